@@ -10,11 +10,16 @@ use std::path::Path;
 use std::process::Command;
 use std::time::Duration;
 
-const LAUNCHER_URL: &str = "http://127.0.0.1:19556";
+const LAUNCHER_PORT: u16 = 19556;
 const PROTOCOL_VERSION: &str = "2024-11-05";
 const SERVER_NAME: &str = "roxlit";
 const SERVER_VERSION: &str = "0.1.0";
 
+/// Launcher HTTP server URL.
+fn launcher_url() -> String {
+    format!("http://127.0.0.1:{LAUNCHER_PORT}")
+}
+
 fn main() {
     let stdin = io::stdin();
     let stdout = io::stdout();
@@ -376,7 +381,7 @@ fn tool_run_code(id: Value, arguments: &Value) -> Value {
     };
 
     // POST to the launcher's MCP relay endpoint
-    let url = format!("{}/mcp/run-code", LAUNCHER_URL);
+    let url = format!("{}/mcp/run-code", launcher_url());
     let body = json!({ "code": code });
 
     let response = client.post(&url).json(&body).send();
@@ -657,7 +662,7 @@ fn tool_backup_create(id: Value, arguments: &Value) -> Value {
     }
 
     // Stage everything (including untracked) so stash create captures it
-    if let Err(e) = run_git(project_path, &["add", "-A"]) {
+    if let Err(e) = stage_all(project_path) {
         return mcp_error_result(id, &format!("Failed to stage files: {e}"));
     }
 
@@ -788,7 +793,7 @@ fn tool_backup_restore(id: Value, arguments: &Value) -> Value {
     // Auto-backup current state before restoring (so user can undo the restore)
     let mut auto_backup_msg = String::new();
     if !is_undo_restore {
-    let _ = run_git(project_path, &["add", "-A"]);
+    let _ = stage_all(project_path);
     if let Ok(sha) = run_git(project_path, &["stash", "create"]) {
         let sha = sha.trim().to_string();
         if !sha.is_empty() {
@@ -925,7 +930,7 @@ fn tool_telemetry_track(id: Value, arguments: &Value) -> Value {
         "group": group,
     });
 
-    let url = format!("{LAUNCHER_URL}/telemetry/track");
+    let url = format!("{}/telemetry/track", launcher_url());
     match http_post_json(&url, &body.to_string()) {
         Ok(_) => mcp_result(id, &format!(
             "Tracking registered: {} [{}] (group: {})\nThe plugin will start sampling when the instance exists (e.g. during playtest).",
@@ -949,7 +954,7 @@ fn tool_telemetry_stop(id: Value, arguments: &Value) -> Value {
         json!({ "group": group.unwrap() })
     };
 
-    let url = format!("{LAUNCHER_URL}/telemetry/untrack");
+    let url = format!("{}/telemetry/untrack", launcher_url());
     match http_post_json(&url, &body.to_string()) {
         Ok(_) => {
             let target = instance_path.unwrap_or_else(|| group.unwrap());
@@ -967,7 +972,7 @@ fn tool_telemetry_toggle(id: Value, arguments: &Value) -> Value {
     let enabled = arguments["enabled"].as_bool().unwrap_or(true);
 
     let body = json!({ "group": group, "enabled": enabled });
-    let url = format!("{LAUNCHER_URL}/telemetry/toggle");
+    let url = format!("{}/telemetry/toggle", launcher_url());
     match http_post_json(&url, &body.to_string()) {
         Ok(_) => {
             let state = if enabled { "enabled" } else { "disabled" };
@@ -1064,7 +1069,11 @@ fn tool_telemetry_clear(id: Value, arguments: &Value) -> Value {
 
 /// Run a git command in the given directory. Returns stdout on success, stderr on error.
 fn run_git(path: &str, args: &[&str]) -> Result<String, String> {
-    let output = Command::new("git")
+    let mut cmd = Command::new("git");
+    // Deeply nested projects have paths past Windows' 260-character limit
+    #[cfg(target_os = "windows")]
+    cmd.args(["-c", "core.longpaths=true"]);
+    let output = cmd
         .args(args)
         .current_dir(path)
         .output()
@@ -1084,6 +1093,46 @@ fn run_git(path: &str, args: &[&str]) -> Result<String, String> {
     }
 }
 
+/// Stage everything for a backup. Untracked files matched by `.roxlitignore`
+/// (gitignore syntax, on top of .gitignore) are left out, so imported asset
+/// trees and vendored packages don't bloat every stash. They're excluded by
+/// pathspec, keeping the user's own global excludes file in effect.
+fn stage_all(path: &str) -> Result<String, String> {
+    let excludes = std::fs::read_to_string(Path::new(path).join(".roxlitignore"))
+        .map(|content| ignore_pathspecs(&content))
+        .unwrap_or_default();
+    let mut args = vec!["add", "-A", "--", "."];
+    args.extend(excludes.iter().map(String::as_str));
+    run_git(path, &args)
+}
+
+/// `.roxlitignore` patterns as `git add` pathspecs excluding them. Negated
+/// (`!`) patterns aren't supported and are skipped.
+fn ignore_pathspecs(content: &str) -> Vec<String> {
+    let mut specs = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        let dir_only = line.ends_with('/');
+        let pattern = line.trim_end_matches('/');
+        // A slash other than a trailing one anchors the pattern to the root
+        let base = if pattern.contains('/') {
+            pattern.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{pattern}")
+        };
+        if base.is_empty() || base == "**/" {
+            continue;
+        }
+        if !dir_only {
+            specs.push(format!(":(exclude,glob){base}"));
+        }
+        specs.push(format!(":(exclude,glob){base}/**"));
+    }
+    specs
+}
+
 /// Ensure the project directory is a git repository. If not, initialize one.
 fn ensure_git_repo(path: &str) -> Result<(), String> {
     let git_dir = Path::new(path).join(".git");
@@ -1097,7 +1146,7 @@ fn ensure_git_repo(path: &str) -> Result<(), String> {
     }
 
     run_git(path, &["init"]).map_err(|e| format!("Failed to init git repo: {e}"))?;
-    run_git(path, &["add", "-A"]).map_err(|e| format!("Failed to stage files: {e}"))?;
+    stage_all(path).map_err(|e| format!("Failed to stage files: {e}"))?;
     run_git(path, &["commit", "-m", "roxlit: initial commit for backups"])
         .map_err(|e| format!("Failed to create initial commit: {e}"))?;
 
@@ -0,0 +1,209 @@
+//! Adopting a Rojo project that wasn't created by Roxlit: its existing setup
+//! (Wally, Selene, toolchain manager, source layout, AI context) is detected
+//! and kept, and only the Roxlit files it doesn't have yet are added.
+
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::commands::backup::now_timestamp;
+use crate::commands::config::{self, ProjectEntry};
+use crate::commands::{context, manifest, project};
+use crate::error::{InstallerError, Result};
+use crate::templates;
+use crate::util::expand_tilde;
+
+/// Tooling files worth reporting, with what they mean.
+const KNOWN_TOOLING: &[(&str, &str)] = &[
+    ("wally.toml", "Wally packages"),
+    ("selene.toml", "Selene linter"),
+    ("stylua.toml", "StyLua formatter"),
+    (".stylua.toml", "StyLua formatter"),
+    ("aftman.toml", "Aftman toolchain"),
+    ("foreman.toml", "Foreman toolchain"),
+    ("rokit.toml", "Rokit toolchain"),
+    (".luaurc", "Luau config"),
+    (".git", "Git repository"),
+];
+
+/// Toolchain manifests; Roxlit only adds aftman.toml when none exists.
+const TOOLCHAIN_FILES: &[&str] = &["aftman.toml", "foreman.toml", "rokit.toml"];
+
+/// What `adopt_project` found and did, relative to the project root.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdoptReport {
+    pub project: Option<ProjectEntry>,
+    /// Existing setup that was recognized, e.g. "Wally packages (wally.toml)".
+    pub detected: Vec<String>,
+    /// Files and folders Roxlit created.
+    pub added: Vec<String>,
+    /// Roxlit files the project already had, left as they were.
+    pub kept: Vec<String>,
+}
+
+impl AdoptReport {
+    /// Writes `content` to `rel` unless the file exists.
+    fn write_if_missing(&mut self, root: &Path, rel: &str, content: &str) -> Result<()> {
+        let path = root.join(rel);
+        if path.exists() {
+            self.kept.push(rel.into());
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+        self.added.push(rel.into());
+        Ok(())
+    }
+}
+
+/// Source folders the project file maps (`$path` values), e.g. `src/server`.
+fn source_paths(project_json: &serde_json::Value, out: &mut Vec<String>) {
+    let Some(node) = project_json.as_object() else { return };
+    for (key, value) in node {
+        if key == "$path" {
+            if let Some(path) = value.as_str() {
+                out.push(path.to_string());
+            }
+        } else if !key.starts_with('$') {
+            source_paths(value, out);
+        }
+    }
+}
+
+/// Registers an existing Rojo project with Roxlit without overwriting any of
+/// its files: its project file, source layout, toolchain and AI context are
+/// kept, and only the missing Roxlit pieces (roxlit.toml, MCP config, editor
+/// workspace, AI memory, context file) are added. `ai_tool` defaults to the
+/// tool whose context file the project already has. Its first sync waits for
+/// the user to review it (see `first_sync`).
+#[tauri::command]
+pub async fn adopt_project(project_path: String, ai_tool: Option<String>) -> Result<AdoptReport> {
+    let project_path = expand_tilde(&project_path);
+    let root = Path::new(&project_path);
+    let project_file = root.join("default.project.json");
+    let content = fs::read_to_string(&project_file).map_err(|_| {
+        InstallerError::Custom(format!("{project_path} is not a Rojo project (no default.project.json)"))
+    })?;
+    let project_json: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| InstallerError::Custom(format!("Invalid default.project.json: {e}")))?;
+    crate::commands::analytics::track_feature("adopt_project");
+
+    let name = project_json["name"]
+        .as_str()
+        .map(String::from)
+        .or_else(|| root.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "project".into());
+    let ai_tool = ai_tool.unwrap_or_else(|| config::detect_ai_tool(root));
+
+    let mut report = AdoptReport::default();
+    for (file, what) in KNOWN_TOOLING {
+        if root.join(file).exists() {
+            report.detected.push(format!("{what} ({file})"));
+        }
+    }
+    let mut sources = Vec::new();
+    source_paths(&project_json, &mut sources);
+    if !sources.is_empty() {
+        report.detected.push(format!("Source folders: {}", sources.join(", ")));
+    }
+
+    if !TOOLCHAIN_FILES.iter().any(|f| root.join(f).exists()) {
+        report.write_if_missing(root, "aftman.toml", &templates::aftman_toml())?;
+    }
+    report.write_if_missing(root, "roxlit-mcp.json", &templates::roxlit_mcp_json(&name))?;
+    report.write_if_missing(root, ".roxlit-mcp-ignore", templates::roxlit_mcp_ignore())?;
+    report.write_if_missing(root, ".roxlit/memory/MEMORY.md", templates::memory_md())?;
+
+    let workspace = project::workspace_path(root);
+    let workspace_name = workspace.file_name().unwrap_or_default().to_string_lossy().to_string();
+    if workspace.exists() {
+        report.kept.push(workspace_name);
+    } else {
+        project::generate_workspace(project_path.clone()).await?;
+        report.added.push(workspace_name);
+    }
+
+    if manifest::read_manifest(&project_path)?.is_some() {
+        report.kept.push(manifest::MANIFEST_FILE.into());
+    } else {
+        let roxlit_manifest = manifest::RoxlitManifest {
+            project: manifest::ManifestProject {
+                name: Some(name.clone()),
+                template: None,
+                ai_tool: Some(ai_tool.clone()),
+            },
+            settings: Default::default(),
+        };
+        manifest::write_manifest(&project_path, &roxlit_manifest)?;
+        report.added.push(manifest::MANIFEST_FILE.into());
+    }
+
+    // A hand-written context file is kept and linked to a generated ROXLIT.md
+    let relative = |path: &Path| path.strip_prefix(root).unwrap_or(path).to_string_lossy().to_string();
+    let tool_file = context::context_file_path(root, &ai_tool);
+    let context_file = context::generated_context_path(root, &ai_tool);
+    if context_file.exists() {
+        report.kept.push(relative(&context_file));
+    } else {
+        // generate_context also writes the MCP config, which may list the user's own servers
+        let mcp_config = context::mcp_config_path(root, &ai_tool)
+            .and_then(|path| Some((fs::read(&path).ok()?, path)));
+        context::generate_context(&project_path, &ai_tool, &name)?;
+        report.added.push(relative(&context_file));
+        if context_file != tool_file {
+            report.kept.push(format!("{} (now links to {})", relative(&tool_file), context::ROXLIT_CONTEXT_FILE));
+        }
+        if let Some((original, path)) = mcp_config {
+            fs::write(&path, original)?;
+            report.kept.push(relative(&path));
+        }
+    }
+
+    // Projects adopted before keep their created_at; new ones get a first-sync review
+    let existing = config::find_project(&project_path);
+    let first_sync_pending = existing.is_none();
+    let created_at = existing.map_or_else(now_timestamp, |p| p.created_at);
+    let entry = ProjectEntry {
+        name,
+        path: project_path,
+        ai_tool,
+        created_at,
+        place_id: None,
+        universe_id: None,
+        settings: Default::default(),
+        places: Vec::new(),
+        active_place: None,
+        first_sync_pending,
+        missing: false,
+    };
+    config::save_project(entry.clone()).await?;
+    report.project = Some(entry);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_paths() {
+        let project_json = serde_json::json!({
+            "name": "game",
+            "tree": {
+                "$className": "DataModel",
+                "ReplicatedStorage": {
+                    "Shared": { "$path": "src/shared" },
+                    "Packages": { "$path": "Packages" }
+                },
+                "ServerScriptService": { "$path": "src/server" }
+            }
+        });
+        let mut sources = Vec::new();
+        source_paths(&project_json, &mut sources);
+        sources.sort();
+        assert_eq!(sources, ["Packages", "src/server", "src/shared"]);
+    }
+}
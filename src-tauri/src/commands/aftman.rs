@@ -0,0 +1,360 @@
+//! Aftman toolchain helpers: trust management and editing the project's
+//! aftman.toml. Aftman refuses to install a tool it hasn't been told to
+//! trust, and asks interactively, which stalls or fails when it runs without a
+//! terminal. We pre-trust the tools Roxlit manages and turn trust failures
+//! into errors that name the tools.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::commands::config;
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Tools Roxlit installs or offers in aftman.toml.
+pub const MANAGED_TOOLS: &[&str] = &[
+    "rojo-rbx/rojo",
+    "UpliftGames/wally",
+    "Kampfkarren/selene",
+    "JohnnyMorganz/StyLua",
+];
+
+/// Aftman's trust list: one `owner/repo` per line.
+pub(crate) fn trusted_file() -> Option<PathBuf> {
+    config::aftman_home().map(|h| h.join("trusted.txt"))
+}
+
+fn trusted_tools() -> Vec<String> {
+    trusted_file()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .map(|s| s.lines().map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect())
+        .unwrap_or_default()
+}
+
+/// Adds `tools` to aftman's trust list. Returns the ones that weren't trusted yet.
+pub fn ensure_trusted<S: AsRef<str>>(tools: &[S]) -> Result<Vec<String>> {
+    let path = trusted_file().ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
+    let mut trusted = trusted_tools();
+    let added: Vec<String> = tools
+        .iter()
+        .map(|t| t.as_ref().to_string())
+        .filter(|t| !trusted.iter().any(|existing| existing.eq_ignore_ascii_case(t)))
+        .collect();
+    if added.is_empty() {
+        return Ok(added);
+    }
+    trusted.extend(added.iter().cloned());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, format!("{}\n", trusted.join("\n")))?;
+    Ok(added)
+}
+
+/// `owner/repo` of every tool in the project's aftman.toml.
+pub fn project_tools(project_path: &Path) -> Vec<String> {
+    read_tools(project_path).into_iter().map(|t| t.repo).collect()
+}
+
+/// Turns a failed `aftman install` into an error. Trust failures name the
+/// untrusted tools and how to trust them.
+pub fn install_error(project_path: &Path, stderr: &str) -> InstallerError {
+    if !is_trust_error(stderr) {
+        return InstallerError::Custom(format!("aftman install failed: {}", stderr.trim()));
+    }
+    let trusted = trusted_tools();
+    let untrusted: Vec<String> = project_tools(project_path)
+        .into_iter()
+        .filter(|t| !trusted.iter().any(|existing| existing.eq_ignore_ascii_case(t)))
+        .collect();
+    if untrusted.is_empty() {
+        return InstallerError::Custom(format!(
+            "aftman install failed because a tool is not trusted. Run `aftman trust <owner/repo>` for it and retry.\n{}",
+            stderr.trim()
+        ));
+    }
+    InstallerError::Custom(format!(
+        "aftman does not trust {} yet. Trust {} from the launcher, or run `aftman trust {}`, then retry.",
+        untrusted.join(", "),
+        if untrusted.len() == 1 { "it" } else { "them" },
+        untrusted[0]
+    ))
+}
+
+/// Aftman's refusal to install an untrusted tool without a terminal:
+/// "Tool owner/repo has never been installed. Run `aftman add owner/repo`
+/// in your terminal to install it and trust this tool."
+fn is_trust_error(stderr: &str) -> bool {
+    stderr.contains("has never been installed") && stderr.contains("trust this tool")
+}
+
+/// Adds tools (`owner/repo`) to aftman's trust list, e.g. after an install
+/// failed on a trust check. Returns the ones that were added.
+#[tauri::command]
+pub async fn trust_aftman_tools(tools: Vec<String>) -> Result<Vec<String>> {
+    if let Some(bad) = tools.iter().find(|t| t.split('/').count() != 2 || t.contains('@')) {
+        return Err(InstallerError::Custom(format!("Not an owner/repo tool name: {bad}")));
+    }
+    ensure_trusted(&tools)
+}
+
+/// A tool entry of aftman.toml: `name = "owner/repo@version"`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AftmanTool {
+    pub name: String,
+    pub repo: String,
+    pub version: String,
+}
+
+/// Splits and validates an `owner/repo@version` spec.
+fn parse_tool_spec(spec: &str) -> Result<(String, String)> {
+    let invalid = || InstallerError::Custom(format!("Invalid tool \"{spec}\" (expected owner/repo@version, e.g. rojo-rbx/rojo@7.4.4)"));
+    let (repo, version) = spec.trim().split_once('@').ok_or_else(invalid)?;
+    let (owner, name) = repo.split_once('/').ok_or_else(invalid)?;
+    let valid_part = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    let version_core = version.trim_start_matches('v').split(['-', '+']).next().unwrap_or_default();
+    let valid_version = !version_core.is_empty()
+        && version_core.split('.').all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+    if !valid_part(owner) || !valid_part(name) || !valid_version || !valid_part(version) {
+        return Err(invalid());
+    }
+    Ok((repo.to_string(), version.to_string()))
+}
+
+/// Tools declared in the project's aftman.toml.
+fn read_tools(project_path: &Path) -> Vec<AftmanTool> {
+    let Ok(content) = std::fs::read_to_string(project_path.join("aftman.toml")) else {
+        return Vec::new();
+    };
+    let Ok(value) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    let Some(tools) = value.get("tools").and_then(|t| t.as_table()) else {
+        return Vec::new();
+    };
+    tools
+        .iter()
+        .filter_map(|(name, spec)| {
+            let (repo, version) = spec.as_str()?.split_once('@')?;
+            Some(AftmanTool {
+                name: name.clone(),
+                repo: repo.to_string(),
+                version: version.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Sets `name = "spec"` under `[tools]`, editing the line in place so
+/// comments and formatting are kept.
+fn set_tool_line(content: &str, name: &str, spec: &str) -> String {
+    let entry = format!("{name} = \"{spec}\"");
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let mut in_tools = false;
+    let mut tools_header = None;
+    for i in 0..lines.len() {
+        let trimmed = lines[i].trim();
+        if trimmed.starts_with('[') {
+            in_tools = trimmed == "[tools]";
+            if in_tools {
+                tools_header = Some(i);
+            }
+            continue;
+        }
+        if in_tools && trimmed.split_once('=').is_some_and(|(key, _)| key.trim() == name) {
+            lines[i] = format!("{entry}{}", inline_comment(&lines[i]));
+            return lines.join("\n") + "\n";
+        }
+    }
+    match tools_header {
+        Some(i) => lines.insert(i + 1, entry),
+        None => {
+            if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push("[tools]".into());
+            lines.push(entry);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// The trailing `# comment` of a TOML line, with the spaces before it, or "".
+fn inline_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[line[..i].trim_end().len()..],
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// Runs `aftman install` in the project.
+async fn run_install(project_path: &Path) -> Result<()> {
+    let aftman = config::aftman_bin_dir()
+        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?
+        .join(if cfg!(target_os = "windows") { "aftman.exe" } else { "aftman" });
+    let mut cmd = tokio::process::Command::new(&aftman);
+    cmd.arg("install")
+        .current_dir(project_path)
+        .stdin(std::process::Stdio::null());
+    if let Some(project) = config::find_project(&project_path.to_string_lossy()) {
+        project.settings.apply_env(&mut cmd);
+    }
+    crate::commands::install::set_aftman_home(&mut cmd);
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    let output = cmd
+        .output()
+        .await
+        .map_err(|e| InstallerError::Custom(format!("Failed to run aftman: {e}")))?;
+    if !output.status.success() {
+        return Err(install_error(project_path, &String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(())
+}
+
+/// Writes a tool to aftman.toml, trusts it and installs it. aftman.toml is
+/// put back as it was if the install fails.
+async fn write_tool(project_path: &Path, name: &str, repo: &str, version: &str) -> Result<Vec<AftmanTool>> {
+    let path = project_path.join("aftman.toml");
+    let original = std::fs::read_to_string(&path).ok();
+    let content = original.clone().unwrap_or_default();
+    std::fs::write(&path, set_tool_line(&content, name, &format!("{repo}@{version}")))?;
+    // The user chose this tool, so trusting it is what they asked for
+    let installed = match ensure_trusted(&[repo]) {
+        Ok(_) => run_install(project_path).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = installed {
+        let _ = match original {
+            Some(original) => std::fs::write(&path, original),
+            None => std::fs::remove_file(&path),
+        };
+        return Err(e);
+    }
+    Ok(read_tools(project_path))
+}
+
+/// The project's Rojo entry in aftman.toml.
+fn rojo_tool(project_path: &Path) -> Option<AftmanTool> {
+    read_tools(project_path)
+        .into_iter()
+        .find(|t| t.repo.eq_ignore_ascii_case("rojo-rbx/rojo"))
+}
+
+/// Rojo version the project pins in aftman.toml.
+pub(crate) fn pinned_rojo(project_path: &Path) -> Option<String> {
+    rojo_tool(project_path).map(|t| t.version)
+}
+
+/// What `pin_rojo` did.
+pub(crate) enum RojoPin {
+    /// Raised from this version ("none" if Rojo wasn't pinned).
+    Raised(String),
+    AlreadyCurrent,
+    /// Pinned to this newer version, left as it is.
+    Newer(String),
+}
+
+/// Raises the project's Rojo pin in aftman.toml to the version Roxlit ships
+/// (`templates::ROJO_VERSION`) and installs it. A project pinned to a newer
+/// Rojo on purpose is left alone.
+pub(crate) async fn pin_rojo(project_path: &Path) -> Result<RojoPin> {
+    let target = crate::templates::ROJO_VERSION;
+    let (name, previous) = match rojo_tool(project_path) {
+        Some(tool) if tool.version.trim_start_matches('v') == target => return Ok(RojoPin::AlreadyCurrent),
+        Some(tool) if crate::commands::update::is_newer_version(target, &tool.version) => {
+            return Ok(RojoPin::Newer(tool.version));
+        }
+        Some(tool) => (tool.name, tool.version),
+        None => ("rojo".to_string(), "none".to_string()),
+    };
+    write_tool(project_path, &name, "rojo-rbx/rojo", target).await?;
+    Ok(RojoPin::Raised(previous))
+}
+
+/// Tools in the project's aftman.toml.
+#[tauri::command]
+pub async fn list_aftman_tools(project_path: String) -> Vec<AftmanTool> {
+    read_tools(Path::new(&expand_tilde(&project_path)))
+}
+
+/// Adds a tool (`name`, `owner/repo@version`) to aftman.toml and installs it.
+#[tauri::command]
+pub async fn add_aftman_tool(project_path: String, name: String, spec: String) -> Result<Vec<AftmanTool>> {
+    let project_path = PathBuf::from(expand_tilde(&project_path));
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(InstallerError::Custom(format!("Invalid tool name: {name}")));
+    }
+    if read_tools(&project_path).iter().any(|t| t.name == name) {
+        return Err(InstallerError::Custom(format!("{name} is already in aftman.toml")));
+    }
+    let (repo, version) = parse_tool_spec(&spec)?;
+    write_tool(&project_path, name, &repo, &version).await
+}
+
+/// Changes the version of a tool in aftman.toml and installs it.
+#[tauri::command]
+pub async fn bump_aftman_tool(project_path: String, name: String, version: String) -> Result<Vec<AftmanTool>> {
+    let project_path = PathBuf::from(expand_tilde(&project_path));
+    let tool = read_tools(&project_path)
+        .into_iter()
+        .find(|t| t.name == name)
+        .ok_or_else(|| InstallerError::Custom(format!("{name} is not in aftman.toml")))?;
+    let (repo, version) = parse_tool_spec(&format!("{}@{}", tool.repo, version.trim()))?;
+    write_tool(&project_path, &name, &repo, &version).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tool_spec() {
+        assert_eq!(
+            parse_tool_spec("rojo-rbx/rojo@7.4.4").unwrap(),
+            ("rojo-rbx/rojo".into(), "7.4.4".into())
+        );
+        assert!(parse_tool_spec("UpliftGames/wally@0.3.2-beta.1").is_ok());
+        assert!(parse_tool_spec("rojo@7.4.4").is_err());
+        assert!(parse_tool_spec("rojo-rbx/rojo").is_err());
+        assert!(parse_tool_spec("rojo-rbx/rojo@latest").is_err());
+        assert!(parse_tool_spec("a/b@1.0\"\nx = \"").is_err());
+    }
+
+    #[test]
+    fn test_set_tool_line() {
+        let toml = "# toolchain\n[tools]\nrojo = \"rojo-rbx/rojo@7.4.4\" # pinned\n";
+        assert_eq!(
+            set_tool_line(toml, "rojo", "rojo-rbx/rojo@7.5.1"),
+            "# toolchain\n[tools]\nrojo = \"rojo-rbx/rojo@7.5.1\" # pinned\n"
+        );
+        assert_eq!(
+            set_tool_line("[tools]\nwally = \"UpliftGames/wally@0.3.1\"\n", "wally", "UpliftGames/wally@0.3.2"),
+            "[tools]\nwally = \"UpliftGames/wally@0.3.2\"\n"
+        );
+        assert_eq!(
+            set_tool_line(toml, "wally", "UpliftGames/wally@0.3.2"),
+            "# toolchain\n[tools]\nwally = \"UpliftGames/wally@0.3.2\"\nrojo = \"rojo-rbx/rojo@7.4.4\" # pinned\n"
+        );
+        assert_eq!(set_tool_line("", "selene", "Kampfkarren/selene@0.27.1"), "[tools]\nselene = \"Kampfkarren/selene@0.27.1\"\n");
+    }
+
+    #[test]
+    fn test_is_trust_error() {
+        assert!(is_trust_error(
+            "error: Tool UpliftGames/wally has never been installed. Run `aftman add UpliftGames/wally` in your terminal to install it and trust this tool."
+        ));
+        assert!(!is_trust_error("os error 32: file is being used by another process"));
+        assert!(!is_trust_error("Could not download trusted-tools.json: 404"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Opt-in anonymous usage analytics (install step outcomes, OS, feature
+//! usage), used to decide which platforms and features need work. Nothing is
+//! sent unless the user enabled `analyticsEnabled` in settings. Every event
+//! sent is also appended to `~/.roxlit/analytics-sent.jsonl`, so users can see
+//! exactly what left their machine.
+//!
+//! Not to be confused with the Studio telemetry trackers in logs.rs, which
+//! never leave the project.
+
+use std::hash::{BuildHasher, Hasher};
+use std::path::PathBuf;
+
+use crate::commands::config;
+
+/// Collection endpoint. Events carry no paths, names or log content.
+const ENDPOINT: &str = "https://api.roxlit.dev/v1/events";
+
+/// Entries returned by `get_analytics_log` when no limit is given.
+const DEFAULT_LOG_LIMIT: usize = 100;
+
+fn sent_log_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("analytics-sent.jsonl"))
+}
+
+/// Random install id, not derived from anything on the machine.
+pub(crate) fn new_install_id() -> String {
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(crate::commands::logs::unix_millis() as u128);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Sends an event in the background if analytics are enabled. `props` must
+/// not contain anything identifying (paths, project or player names).
+pub fn track(event: &str, props: serde_json::Value) {
+    let Some(cfg) = config::read_config() else {
+        return;
+    };
+    if cfg.analytics_enabled != Some(true) {
+        return;
+    }
+    let Some(install_id) = cfg.analytics_id else {
+        return;
+    };
+
+    let payload = serde_json::json!({
+        "installId": install_id,
+        "event": event,
+        "version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "timestamp": crate::commands::logs::unix_millis() / 1000,
+        "props": props,
+    });
+
+    if let Some(path) = sent_log_path() {
+        use std::io::Write;
+        if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) {
+            let _ = writeln!(file, "{payload}");
+        }
+    }
+
+    // Best-effort: analytics must never affect the app
+    tokio::spawn(async move {
+        let _ = reqwest::Client::new()
+            .post(ENDPOINT)
+            .json(&payload)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await;
+    });
+}
+
+/// Outcome of one installation step.
+pub fn track_install_step(step: &str, success: bool) {
+    track("install_step", serde_json::json!({ "step": step, "success": success }));
+}
+
+/// One use of a feature (counted server-side).
+pub fn track_feature(feature: &str) {
+    track("feature_used", serde_json::json!({ "feature": feature }));
+}
+
+/// The most recent events sent, newest last.
+#[tauri::command]
+pub fn get_analytics_log(limit: Option<usize>) -> Vec<serde_json::Value> {
+    let content = sent_log_path()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .unwrap_or_default();
+    let events: Vec<serde_json::Value> = content
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+    let skip = events.len().saturating_sub(limit.unwrap_or(DEFAULT_LOG_LIMIT));
+    events.into_iter().skip(skip).collect()
+}
@@ -9,6 +9,9 @@ use std::process::Command;
 /// Run a git command in the given directory.
 pub fn run_git(path: &str, args: &[&str]) -> Result<String, String> {
     let mut cmd = Command::new("git");
+    // Deeply nested projects have paths past Windows' 260-character limit
+    #[cfg(target_os = "windows")]
+    cmd.args(["-c", "core.longpaths=true"]);
     cmd.args(args).current_dir(path);
 
     // Prevent console windows from flashing on Windows
@@ -34,6 +37,46 @@ pub fn run_git(path: &str, args: &[&str]) -> Result<String, String> {
     }
 }
 
+/// Stage everything for a backup. Untracked files matched by `.roxlitignore`
+/// (gitignore syntax, on top of .gitignore) are left out, so imported asset
+/// trees and vendored packages don't bloat every stash. They're excluded by
+/// pathspec, keeping the user's own global excludes file in effect.
+pub fn stage_all(path: &str) -> Result<String, String> {
+    let excludes = std::fs::read_to_string(Path::new(path).join(".roxlitignore"))
+        .map(|content| ignore_pathspecs(&content))
+        .unwrap_or_default();
+    let mut args = vec!["add", "-A", "--", "."];
+    args.extend(excludes.iter().map(String::as_str));
+    run_git(path, &args)
+}
+
+/// `.roxlitignore` patterns as `git add` pathspecs excluding them. Negated
+/// (`!`) patterns aren't supported and are skipped.
+fn ignore_pathspecs(content: &str) -> Vec<String> {
+    let mut specs = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        let dir_only = line.ends_with('/');
+        let pattern = line.trim_end_matches('/');
+        // A slash other than a trailing one anchors the pattern to the root
+        let base = if pattern.contains('/') {
+            pattern.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{pattern}")
+        };
+        if base.is_empty() || base == "**/" {
+            continue;
+        }
+        if !dir_only {
+            specs.push(format!(":(exclude,glob){base}"));
+        }
+        specs.push(format!(":(exclude,glob){base}/**"));
+    }
+    specs
+}
+
 /// Ensure the project directory is a git repository.
 pub fn ensure_git_repo(path: &str) -> Result<(), String> {
     let git_dir = Path::new(path).join(".git");
@@ -56,7 +99,7 @@ pub fn ensure_git_repo(path: &str) -> Result<(), String> {
     }
 
     run_git(path, &["init"]).map_err(|e| format!("Failed to init git repo: {e}"))?;
-    run_git(path, &["add", "-A"]).map_err(|e| format!("Failed to stage files: {e}"))?;
+    stage_all(path).map_err(|e| format!("Failed to stage files: {e}"))?;
     run_git(path, &["commit", "-m", "roxlit: initial commit for backups"])
         .map_err(|e| format!("Failed to create initial commit: {e}"))?;
 
@@ -162,6 +205,81 @@ pub fn find_stash_index(path: &str, backup_id: &str) -> Option<usize> {
     None
 }
 
+/// Records roxlit stashes that have no manifest entry, marked `recovered`.
+/// They are left when a backup is interrupted between `stash store` and the
+/// manifest write, or when the manifest loses entries; recording them keeps
+/// their IDs from being handed out again without discarding a snapshot.
+/// Returns the recovered backup IDs.
+pub fn recover_unrecorded_backups(path: &str) -> Vec<String> {
+    let Ok(stash_list) = run_git(path, &["stash", "list"]) else {
+        return Vec::new();
+    };
+    let manifest_path = Path::new(path).join(".roxlit").join("backups.jsonl");
+    let manifest = std::fs::read_to_string(&manifest_path).unwrap_or_default();
+    let mut recorded: std::collections::HashSet<String> = manifest
+        .lines()
+        .filter_map(|l| serde_json::from_str::<Value>(l).ok())
+        .filter_map(|e| e["id"].as_str().map(String::from))
+        .collect();
+
+    let mut entries = Vec::new();
+    for line in stash_list.lines() {
+        let Some((index, id)) = parse_stash_line(line) else {
+            continue;
+        };
+        if !recorded.insert(id.clone()) {
+            continue;
+        }
+        let Ok(sha) = run_git(path, &["rev-parse", &format!("stash@{{{index}}}")]) else {
+            continue;
+        };
+        let sha = sha.trim();
+        let label = line.split_once(&format!("roxlit:{id}:")).map_or(id.as_str(), |(_, label)| label);
+        let timestamp = run_git(path, &["log", "-1", "--format=%ct", sha])
+            .ok()
+            .and_then(|secs| secs.trim().parse().ok())
+            .map(crate::commands::logs::format_timestamp);
+        let files_changed: Vec<String> = run_git(path, &["stash", "show", "--name-only", sha])
+            .unwrap_or_default()
+            .lines()
+            .map(String::from)
+            .collect();
+        entries.push(json!({
+            "id": id,
+            "name": if label == id { None } else { Some(label) },
+            "timestamp": timestamp,
+            "stash_sha": sha,
+            "auto": label.starts_with("auto-"),
+            "files_changed": files_changed,
+            "recovered": true,
+        }));
+    }
+    if entries.is_empty() {
+        return Vec::new();
+    }
+
+    let _ = std::fs::create_dir_all(Path::new(path).join(".roxlit"));
+    let Ok(mut f) = std::fs::OpenOptions::new().create(true).append(true).open(&manifest_path) else {
+        return Vec::new();
+    };
+    let mut recovered = Vec::new();
+    for entry in entries {
+        if writeln!(f, "{}", serde_json::to_string(&entry).unwrap_or_default()).is_ok() {
+            recovered.push(entry["id"].as_str().unwrap_or_default().to_string());
+        }
+    }
+    recovered
+}
+
+/// Stash index and backup ID from a `git stash list` line made by a backup,
+/// e.g. `stash@{2}: On main: roxlit:bk-004:auto-...`.
+fn parse_stash_line(line: &str) -> Option<(usize, String)> {
+    let index = line.strip_prefix("stash@{")?.split('}').next()?.parse().ok()?;
+    let rest = &line[line.find("roxlit:")? + "roxlit:".len()..];
+    let id = rest.split(':').next()?;
+    id.starts_with("bk-").then(|| (index, id.to_string()))
+}
+
 /// Check if a backup ID is a pre-restore backup.
 pub fn is_pre_restore_backup(path: &str, backup_id: &str) -> bool {
     let manifest_path = Path::new(path).join(".roxlit").join("backups.jsonl");
@@ -184,7 +302,7 @@ pub fn create_backup(path: &str, name: &str) -> Result<(String, String), String>
     ensure_git_repo(path)?;
 
     // Stage everything so stash captures untracked files
-    run_git(path, &["add", "-A"]).map_err(|e| format!("Failed to stage files: {e}"))?;
+    stage_all(path).map_err(|e| format!("Failed to stage files: {e}"))?;
 
     let sha = run_git(path, &["stash", "create"])
         .map_err(|e| format!("Failed to create stash: {e}"))?
@@ -352,9 +470,149 @@ pub fn cleanup_by_size(path: &str, max_bytes: u64) {
         .map(|(_, e)| e)
         .collect();
 
-    if let Ok(mut f) = std::fs::File::create(&manifest_path) {
-        for entry in remaining {
-            let _ = writeln!(f, "{}", serde_json::to_string(entry).unwrap_or_default());
+    let _ = rewrite_manifest(&manifest_path, remaining);
+}
+
+/// Replaces backups.jsonl with `entries`. Written to a temporary file and
+/// renamed over the manifest, so a crash or full disk mid-write leaves the
+/// previous manifest intact instead of a truncated one.
+fn rewrite_manifest<'a>(manifest_path: &Path, entries: impl IntoIterator<Item = &'a Value>) -> std::io::Result<()> {
+    let mut content = String::new();
+    for entry in entries {
+        content.push_str(&serde_json::to_string(entry).unwrap_or_default());
+        content.push('\n');
+    }
+    let temp = manifest_path.with_extension("jsonl.tmp");
+    let mut f = std::fs::File::create(&temp)?;
+    f.write_all(content.as_bytes())?;
+    f.sync_all()?;
+    drop(f);
+    std::fs::rename(&temp, manifest_path)
+}
+
+/// Result of checking one backup in `verify_backups`.
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupCheck {
+    pub id: String,
+    pub name: Option<String>,
+    pub timestamp: Option<String>,
+    /// Why the backup can't be restored correctly, None if it's intact.
+    pub problem: Option<String>,
+    /// Removed from the manifest (and the stash list) by this check.
+    pub pruned: bool,
+}
+
+/// Checks every backup in the manifest against git: the stash is still
+/// listed, points at the recorded snapshot, has all its objects, and still
+/// contains every file the manifest recorded. With `prune`, unusable backups
+/// are dropped so a restore never silently produces a partial tree.
+#[tauri::command]
+pub async fn verify_backups(project_path: String, prune: Option<bool>) -> crate::error::Result<Vec<BackupCheck>> {
+    let path = crate::util::expand_tilde(&project_path);
+    let prune = prune.unwrap_or(false);
+    tokio::task::spawn_blocking(move || verify_all(&path, prune))
+        .await
+        .map_err(|e| crate::error::InstallerError::Custom(format!("Backup check failed: {e}")))
+}
+
+fn verify_all(path: &str, prune: bool) -> Vec<BackupCheck> {
+    let manifest_path = Path::new(path).join(".roxlit").join("backups.jsonl");
+    let entries: Vec<Value> = std::fs::read_to_string(&manifest_path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+
+    let mut checks: Vec<BackupCheck> = entries
+        .iter()
+        .map(|entry| BackupCheck {
+            id: entry["id"].as_str().unwrap_or_default().to_string(),
+            name: entry["name"].as_str().map(String::from),
+            timestamp: entry["timestamp"].as_str().map(String::from),
+            problem: backup_problem(path, entry),
+            pruned: false,
+        })
+        .collect();
+
+    if !prune || checks.iter().all(|c| c.problem.is_none()) {
+        return checks;
+    }
+
+    // Drop highest stash index first so the remaining indices stay valid
+    let mut stashes: Vec<usize> = checks
+        .iter()
+        .filter(|c| c.problem.is_some())
+        .filter_map(|c| find_stash_index(path, &c.id))
+        .collect();
+    stashes.sort_by_key(|index| std::cmp::Reverse(*index));
+    for index in stashes {
+        let _ = run_git(path, &["stash", "drop", &format!("stash@{{{index}}}")]);
+    }
+
+    let kept = entries.iter().zip(&checks).filter(|(_, check)| check.problem.is_none()).map(|(entry, _)| entry);
+    if rewrite_manifest(&manifest_path, kept).is_ok() {
+        for check in checks.iter_mut().filter(|check| check.problem.is_some()) {
+            check.pruned = true;
         }
     }
+    checks
+}
+
+/// What makes a manifest entry unrestorable, if anything.
+fn backup_problem(path: &str, entry: &Value) -> Option<String> {
+    let id = entry["id"].as_str().unwrap_or_default();
+    let Some(sha) = entry["stash_sha"].as_str() else {
+        return Some("No snapshot was recorded".into());
+    };
+    let Some(index) = find_stash_index(path, id) else {
+        return Some("Snapshot is missing from the stash list".into());
+    };
+    let stash_ref = format!("stash@{{{index}}}");
+    let listed = run_git(path, &["rev-parse", &stash_ref]).unwrap_or_default();
+    if listed.trim() != sha {
+        return Some("Stash entry points at a different snapshot than recorded".into());
+    }
+    // -l reads every blob's size, so a missing or damaged object fails here
+    if let Err(e) = run_git(path, &["ls-tree", "-r", "-l", sha]) {
+        return Some(format!("Snapshot is damaged: {}", e.trim()));
+    }
+    let stored = run_git(path, &["stash", "show", "--name-only", sha]).unwrap_or_default();
+    let stored: std::collections::HashSet<&str> = stored.lines().collect();
+    let missing = entry["files_changed"]
+        .as_array()
+        .map(|files| files.iter().filter_map(|f| f.as_str()).filter(|f| !stored.contains(f)).count())
+        .unwrap_or(0);
+    if missing > 0 {
+        return Some(format!("Snapshot is truncated: {missing} recorded file(s) missing"));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stash_line() {
+        assert_eq!(
+            parse_stash_line("stash@{2}: On main: roxlit:bk-004:auto-2026"),
+            Some((2, "bk-004".to_string()))
+        );
+        assert_eq!(parse_stash_line("stash@{0}: WIP on main: 1234abc fix"), None);
+    }
+
+    #[test]
+    fn test_ignore_pathspecs() {
+        assert_eq!(
+            ignore_pathspecs("# assets\nPackages/\n*.fbx\n/build/out\n!keep.fbx\n\n"),
+            vec![
+                ":(exclude,glob)**/Packages/**",
+                ":(exclude,glob)**/*.fbx",
+                ":(exclude,glob)**/*.fbx/**",
+                ":(exclude,glob)build/out",
+                ":(exclude,glob)build/out/**",
+            ]
+        );
+    }
 }
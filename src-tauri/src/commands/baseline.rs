@@ -0,0 +1,238 @@
+//! Named "known good" points to go back to in one step. A baseline is a
+//! backup of the project files (kept from size cleanup and tagged in git so
+//! it outlives the stash list) plus a build of the place at that moment;
+//! reverting puts both back.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::commands::backup::{self, run_git, stage_all};
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// A marked baseline, as recorded in `.roxlit/baselines.json`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Baseline {
+    pub label: String,
+    pub created_at: String,
+    /// Backup holding the files, None when nothing had changed since the last commit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_id: Option<String>,
+    /// Commit whose tree is the project's files at this baseline.
+    pub commit: String,
+    /// Git tag pointing at `commit`, None if tagging failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Place built at this baseline, relative to the project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place: Option<String>,
+}
+
+/// What `revert_to_baseline` did.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineRevert {
+    pub label: String,
+    /// Files put back to their baseline content.
+    pub restored_files: usize,
+    /// Files created after the baseline, deleted.
+    pub removed_files: Vec<String>,
+    /// Backup of the state before reverting, to undo it.
+    pub pre_revert_backup: Option<String>,
+    /// Baseline place file, restored over the project's build.
+    pub place: Option<String>,
+}
+
+fn baselines_path(root: &Path) -> PathBuf {
+    root.join(".roxlit").join("baselines.json")
+}
+
+fn read_baselines(root: &Path) -> Vec<Baseline> {
+    std::fs::read_to_string(baselines_path(root))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_baselines(root: &Path, baselines: &[Baseline]) -> Result<()> {
+    std::fs::create_dir_all(root.join(".roxlit"))?;
+    let json = serde_json::to_string_pretty(baselines).map_err(|e| InstallerError::Custom(e.to_string()))?;
+    std::fs::write(baselines_path(root), json)?;
+    Ok(())
+}
+
+/// Label reduced to what's safe in a git tag and a file name.
+pub(crate) fn slug(label: &str) -> String {
+    let mut slug = String::new();
+    for c in label.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Backs up the current files, returning the backup ID and the commit holding
+/// them. With nothing changed since the last commit, that commit is used.
+fn snapshot_files(path: &str, name: &str) -> std::result::Result<(Option<String>, String), String> {
+    match backup::create_backup(path, name) {
+        Ok((backup_id, _)) => {
+            // `stash store` puts the new backup on top
+            let commit = run_git(path, &["rev-parse", "stash@{0}"])?;
+            Ok((Some(backup_id), commit.trim().to_string()))
+        }
+        Err(e) if e.starts_with("Nothing to backup") => {
+            let commit = run_git(path, &["rev-parse", "HEAD"])?;
+            Ok((None, commit.trim().to_string()))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Records the project as it is now under `label`: files, git tag and place
+/// build. Marking an existing label again replaces it.
+#[tauri::command]
+pub async fn mark_baseline(project_path: String, label: String) -> Result<Baseline> {
+    let path = expand_tilde(&project_path);
+    let root = PathBuf::from(&path);
+    let name = slug(&label);
+    if name.is_empty() {
+        return Err(InstallerError::Custom("Give the baseline a name".into()));
+    }
+    crate::commands::analytics::track_feature("mark_baseline");
+
+    let backup_path = path.clone();
+    let backup_name = format!("baseline-{name}");
+    let (backup_id, commit) = tokio::task::spawn_blocking(move || snapshot_files(&backup_path, &backup_name))
+        .await
+        .map_err(|e| InstallerError::Custom(format!("Baseline failed: {e}")))?
+        .map_err(InstallerError::Custom)?;
+
+    let tag_name = format!("roxlit-baseline/{name}");
+    let tag = run_git(&path, &["tag", "-f", &tag_name, &commit]).ok().map(|_| tag_name);
+
+    // Without Rojo or disk space the files alone still make a baseline
+    let dest = root.join(".roxlit").join("baselines").join(format!("{name}.rbxl"));
+    let place = match crate::commands::studio::build_place_to(&path, &dest).await {
+        Ok(()) => Some(dest.strip_prefix(&root).unwrap_or(&dest).to_string_lossy().replace('\\', "/")),
+        Err(_) => None,
+    };
+
+    let baseline = Baseline {
+        label: label.trim().to_string(),
+        created_at: backup::now_timestamp(),
+        backup_id,
+        commit,
+        tag,
+        place,
+    };
+    let mut baselines = read_baselines(&root);
+    baselines.retain(|b| slug(&b.label) != name);
+    baselines.push(baseline.clone());
+    write_baselines(&root, &baselines)?;
+    Ok(baseline)
+}
+
+/// Baselines of a project, newest first.
+#[tauri::command]
+pub async fn list_baselines(project_path: String) -> Result<Vec<Baseline>> {
+    let mut baselines = read_baselines(Path::new(&expand_tilde(&project_path)));
+    baselines.reverse();
+    Ok(baselines)
+}
+
+/// Puts the project back to a baseline: its files (deleting files created
+/// since, except under `.roxlit`) and its place build. The current state is
+/// backed up first. With `open_place`, the baseline place opens in Studio.
+#[tauri::command]
+pub async fn revert_to_baseline(
+    project_path: String,
+    label: String,
+    open_place: Option<bool>,
+) -> Result<BaselineRevert> {
+    let path = expand_tilde(&project_path);
+    let root = PathBuf::from(&path);
+    let wanted = slug(&label);
+    let baseline = read_baselines(&root)
+        .into_iter()
+        .find(|b| slug(&b.label) == wanted)
+        .ok_or_else(|| InstallerError::Custom(format!("No baseline named \"{label}\"")))?;
+    crate::commands::analytics::track_feature("revert_to_baseline");
+
+    let revert_path = path.clone();
+    let revert = baseline.clone();
+    let (pre_revert_backup, restored_files, removed_files) =
+        tokio::task::spawn_blocking(move || restore_files(&revert_path, &revert))
+            .await
+            .map_err(|e| InstallerError::Custom(format!("Revert failed: {e}")))?
+            .map_err(InstallerError::Custom)?;
+
+    let mut place = None;
+    if let Some(saved) = baseline.place.as_ref().map(|p| root.join(p)).filter(|p| p.is_file()) {
+        let build = crate::commands::studio::place_file(&path);
+        std::fs::create_dir_all(build.parent().unwrap_or(&root))?;
+        std::fs::copy(&saved, &build)?;
+        if open_place.unwrap_or(false) {
+            crate::commands::studio::open_place_file(&build).await?;
+        }
+        place = Some(build.to_string_lossy().to_string());
+    }
+
+    Ok(BaselineRevert {
+        label: baseline.label,
+        restored_files,
+        removed_files,
+        pre_revert_backup,
+        place,
+    })
+}
+
+/// Checks out the baseline's files over the working tree. Returns the
+/// pre-revert backup, how many files were restored and which were removed.
+fn restore_files(path: &str, baseline: &Baseline) -> std::result::Result<(Option<String>, usize, Vec<String>), String> {
+    // The tag survives the stash being dropped; the recorded commit is the fallback
+    let target = baseline
+        .tag
+        .as_deref()
+        .filter(|tag| run_git(path, &["rev-parse", "--verify", "--quiet", tag]).is_ok_and(|sha| !sha.trim().is_empty()))
+        .unwrap_or(&baseline.commit)
+        .to_string();
+    run_git(path, &["cat-file", "-e", &format!("{target}^{{tree}}")])
+        .map_err(|_| format!("Baseline \"{}\" is no longer in git", baseline.label))?;
+
+    let (pre_revert_backup, _) = snapshot_files(path, &format!("pre-revert-{}", slug(&baseline.label)))?;
+
+    // Files in the working tree that the baseline doesn't have
+    stage_all(path).map_err(|e| format!("Failed to stage files: {e}"))?;
+    let added = run_git(path, &["diff", "--cached", "--name-only", "--diff-filter=A", &target, "--", ".", ":(exclude).roxlit"]);
+    let changed = run_git(path, &["diff", "--cached", "--name-only", &target, "--", ".", ":(exclude).roxlit"]);
+    let _ = run_git(path, &["reset"]);
+    let added: Vec<String> = added?.lines().map(String::from).collect();
+    let restored_files = changed?.lines().count() - added.len();
+
+    let mut removed_files = Vec::new();
+    for file in added {
+        if std::fs::remove_file(Path::new(path).join(&file)).is_ok() {
+            removed_files.push(file);
+        }
+    }
+    run_git(path, &["checkout", &target, "--", ".", ":(exclude).roxlit"])
+        .map_err(|e| format!("Failed to restore files: {e}"))?;
+    let _ = run_git(path, &["reset"]);
+    Ok((pre_revert_backup, restored_files, removed_files))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("  Before Combat Rework! "), "before-combat-rework");
+        assert_eq!(slug("v1.2 -- stable"), "v1-2-stable");
+        assert_eq!(slug("???"), "");
+    }
+}
@@ -0,0 +1,167 @@
+//! Actions applied to every registered project at once, for after a Roxlit
+//! update when each project needs the same refresh (new context version, new
+//! MCP binary path, new Rojo pin).
+
+use serde::Serialize;
+use std::path::Path;
+
+use crate::commands::config::{self, ProjectEntry};
+use crate::commands::{aftman, context, project_json};
+use crate::error::{InstallerError, Result};
+use crate::templates;
+
+/// Outcome of a bulk action on one project.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkResult {
+    pub name: String,
+    pub path: String,
+    /// "ok", "skipped" or "failed".
+    pub status: String,
+    pub detail: String,
+}
+
+/// Applies `action` to every registered project, one after another, and
+/// reports each one. A failure doesn't stop the others.
+///
+/// - `regenerate-context`: rewrites the AI context (hand edits are saved first)
+/// - `refresh-mcp`: rewrites the AI tool's MCP config
+/// - `doctor`: checks each project without changing its files
+/// - `update-aftman`: raises older Rojo pins to the version Roxlit ships and installs it
+#[tauri::command]
+pub async fn bulk_project_action(action: String) -> Result<Vec<BulkResult>> {
+    if !["regenerate-context", "refresh-mcp", "doctor", "update-aftman"].contains(&action.as_str()) {
+        return Err(InstallerError::Custom(format!(
+            "Unknown action \"{action}\" (expected regenerate-context, refresh-mcp, doctor or update-aftman)"
+        )));
+    }
+    crate::commands::analytics::track_feature("bulk_project_action");
+    let projects = config::load_config().await.map(|c| c.projects).unwrap_or_default();
+
+    let mut results = Vec::new();
+    for project in projects {
+        let outcome = if Path::new(&project.path).is_dir() {
+            run(&action, &project).await
+        } else {
+            Ok((false, "Folder not found".to_string()))
+        };
+        let (status, detail) = match outcome {
+            Ok((true, detail)) => ("ok", detail),
+            Ok((false, detail)) => ("skipped", detail),
+            Err(e) => ("failed", e.to_string()),
+        };
+        results.push(BulkResult {
+            name: project.name,
+            path: project.path,
+            status: status.into(),
+            detail,
+        });
+    }
+    Ok(results)
+}
+
+/// Runs one action on one project: `(false, reason)` when it didn't apply.
+async fn run(action: &str, project: &ProjectEntry) -> Result<(bool, String)> {
+    let root = Path::new(&project.path);
+    match action {
+        "regenerate-context" => {
+            let context_file = context::generated_context_path(root, &project.ai_tool);
+            let saved = context::save_user_edits(root, &context_file)?;
+            context::generate_context(&project.path, &project.ai_tool, &project.name)?;
+            let mut detail = format!("Regenerated {}", context_file.strip_prefix(root).unwrap_or(&context_file).display());
+            if let Some(saved) = saved {
+                detail.push_str(&format!(" (hand edits saved to {})", saved.strip_prefix(root).unwrap_or(&saved).display()));
+            }
+            Ok((true, detail))
+        }
+        "refresh-mcp" => {
+            if !context::mcp_enabled(&project.path) {
+                return Ok((false, "MCP is disabled or not installed".into()));
+            }
+            context::configure_mcp(root, &project.ai_tool)?;
+            Ok((true, format!("Updated the {} MCP config", context::tool_display_name(&project.ai_tool))))
+        }
+        "doctor" => {
+            let problems = doctor(root, project);
+            if problems.is_empty() {
+                Ok((true, "No problems found".into()))
+            } else {
+                Err(InstallerError::Custom(problems.join("; ")))
+            }
+        }
+        _ => {
+            if !root.join("aftman.toml").exists() {
+                return Ok((false, "No aftman.toml (uses another toolchain manager)".into()));
+            }
+            match aftman::pin_rojo(root).await? {
+                aftman::RojoPin::Raised(previous) => Ok((true, format!("Rojo {previous} → {}", templates::ROJO_VERSION))),
+                aftman::RojoPin::AlreadyCurrent => Ok((false, format!("Already on Rojo {}", templates::ROJO_VERSION))),
+                aftman::RojoPin::Newer(version) => Ok((false, format!("Pinned to newer Rojo {version}, left as is"))),
+            }
+        }
+    }
+}
+
+/// Problems that would get in the way of "Start Development".
+fn doctor(root: &Path, project: &ProjectEntry) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Err(e) = project_json::validate(root) {
+        problems.push(format!("default.project.json: {e}"));
+    }
+    if root.join("aftman.toml").exists()
+        && aftman::pinned_rojo(root)
+            .is_none_or(|version| crate::commands::update::is_newer_version(&version, templates::ROJO_VERSION))
+    {
+        problems.push(format!("aftman.toml doesn't pin Rojo {} or newer", templates::ROJO_VERSION));
+    }
+    let context_file = context::generated_context_path(root, &project.ai_tool);
+    match std::fs::read_to_string(&context_file) {
+        Ok(content) if !content.contains(&format!("roxlit-context-version: {}", templates::CONTEXT_VERSION)) => {
+            problems.push("AI context is out of date".into());
+        }
+        Ok(_) => {}
+        Err(_) => problems.push(format!(
+            "{} is missing",
+            context_file.strip_prefix(root).unwrap_or(&context_file).display()
+        )),
+    }
+    if context::mcp_enabled(&project.path) {
+        if let Some(mcp_config) = context::mcp_config_path(root, &project.ai_tool).filter(|p| !p.exists()) {
+            problems.push(format!(
+                "{} is missing",
+                mcp_config.strip_prefix(root).unwrap_or(&mcp_config).display()
+            ));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doctor() {
+        let root = std::env::temp_dir().join(format!("roxlit-doctor-test-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("aftman.toml"), "[tools]\nrojo = \"rojo-rbx/rojo@7.0.0\"\n").unwrap();
+        let project = ProjectEntry {
+            name: "game".into(),
+            path: root.to_string_lossy().to_string(),
+            ai_tool: "claude".into(),
+            created_at: String::new(),
+            place_id: None,
+            universe_id: None,
+            settings: Default::default(),
+            places: Vec::new(),
+            active_place: None,
+            first_sync_pending: false,
+            missing: false,
+        };
+        let problems = doctor(&root, &project);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(problems[0].starts_with("default.project.json:"));
+        assert_eq!(problems[1], format!("aftman.toml doesn't pin Rojo {} or newer", templates::ROJO_VERSION));
+        assert_eq!(problems[2], "CLAUDE.md is missing");
+    }
+}
@@ -0,0 +1,160 @@
+//! Classroom provisioning — creates one project per student in a single step.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::commands::backup::now_timestamp;
+use crate::commands::config::{self, ProjectEntry};
+use crate::commands::{context, project};
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// One student from the roster, optionally with a pre-created place to link.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub name: String,
+    #[serde(default)]
+    pub place_id: Option<u64>,
+    #[serde(default)]
+    pub universe_id: Option<u64>,
+}
+
+/// Outcome of a provisioning run.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvisionReport {
+    pub created: Vec<ProjectEntry>,
+    /// Students whose project folder already existed (left untouched).
+    pub skipped: Vec<String>,
+    /// `(student, error)` for projects that could not be created.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Creates a project per roster entry under `parent_dir` and registers each in
+/// the config. `base_template` is an existing project to copy; without one,
+/// the standard starter project is used.
+#[tauri::command]
+pub async fn provision_classroom(
+    roster: Vec<RosterEntry>,
+    base_template: Option<String>,
+    parent_dir: String,
+    ai_tool: String,
+) -> Result<ProvisionReport> {
+    let parent = expand_tilde(&parent_dir);
+    let parent = Path::new(&parent);
+    fs::create_dir_all(parent)?;
+
+    let template = base_template.map(|t| expand_tilde(&t));
+    if let Some(ref t) = template {
+        if !Path::new(t).join("default.project.json").exists() {
+            return Err(InstallerError::Custom(format!(
+                "Template {t} is not a Rojo project (no default.project.json)"
+            )));
+        }
+    }
+
+    let mut report = ProvisionReport::default();
+    for student in roster {
+        let name = student.name.trim().to_string();
+        let folder = folder_name(&name);
+        if folder.is_empty() {
+            report.failed.push((name, "Name has no usable characters".into()));
+            continue;
+        }
+        let project_dir = parent.join(&folder);
+        if project_dir.exists() {
+            report.skipped.push(name);
+            continue;
+        }
+        let project_path = project_dir.to_string_lossy().to_string();
+
+        let created = match template {
+            Some(ref t) => copy_template(Path::new(t), &project_dir, &name),
+            None => project::create_project(&project_path, &name, &Default::default()),
+        }
+        .and_then(|_| context::generate_context(&project_path, &ai_tool, &name));
+        if let Err(e) = created {
+            let _ = fs::remove_dir_all(&project_dir);
+            report.failed.push((name, e.to_string()));
+            continue;
+        }
+
+        let entry = ProjectEntry {
+            name,
+            path: project_path,
+            ai_tool: ai_tool.clone(),
+            created_at: now_timestamp(),
+            place_id: student.place_id,
+            universe_id: student.universe_id,
+            settings: Default::default(),
+            places: Vec::new(),
+            active_place: None,
+            first_sync_pending: false,
+            missing: false,
+        };
+        config::save_project(entry.clone()).await?;
+        report.created.push(entry);
+    }
+
+    Ok(report)
+}
+
+/// Turns a student name into a folder name: alphanumerics kept, everything
+/// else collapsed to single dashes.
+fn folder_name(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
+
+/// Copies a template project, skipping VCS and Roxlit session state, and
+/// renames the Rojo project.
+fn copy_template(src: &Path, dest: &Path, project_name: &str) -> Result<()> {
+    copy_dir(src, dest)?;
+
+    let project_json = dest.join("default.project.json");
+    let content = fs::read_to_string(&project_json)?;
+    if let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&content) {
+        value["name"] = serde_json::Value::String(project_name.to_string());
+        let json = serde_json::to_string_pretty(&value)
+            .map_err(|e| InstallerError::Custom(e.to_string()))?;
+        fs::write(&project_json, json)?;
+    }
+    Ok(())
+}
+
+fn copy_dir(src: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)?.flatten() {
+        let name = entry.file_name();
+        if name == ".git" || name == "node_modules" {
+            continue;
+        }
+        let from = entry.path();
+        let to = dest.join(&name);
+        if from.is_dir() {
+            // Keep memory/context, drop logs and backups from the template's sessions
+            if name == ".roxlit" {
+                fs::create_dir_all(&to)?;
+                for sub in ["memory", "context"] {
+                    if from.join(sub).is_dir() {
+                        copy_dir(&from.join(sub), &to.join(sub))?;
+                    }
+                }
+                continue;
+            }
+            copy_dir(&from, &to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
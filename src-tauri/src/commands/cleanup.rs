@@ -0,0 +1,172 @@
+//! Startup cleanup: removes what crashed sessions leave behind (a Rojo server
+//! whose launcher died, temp download directories) once at launch, instead of
+//! sweeping processes by name before every command, records backups whose
+//! manifest entry was lost, and flags registered projects whose folder is
+//! gone. What was done goes to the in-memory log. Git locks are only
+//! reported: another git process may still hold one.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::commands::backup;
+use crate::commands::logs::LoggerState;
+
+/// A lock or partial download younger than this may still be in use.
+const STALE_AFTER: Duration = Duration::from_secs(10 * 60);
+
+/// Holds the PID of the `rojo serve` this launcher started, while it runs.
+fn rojo_pid_file() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("rojo.pid"))
+}
+
+/// Records the running Rojo server's PID (None clears it).
+pub(crate) fn record_rojo_pid(pid: Option<u32>) {
+    let Some(path) = rojo_pid_file() else { return };
+    match pid {
+        Some(pid) => {
+            let _ = std::fs::write(path, pid.to_string());
+        }
+        None => {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Runs every cleanup step and logs what each removed.
+pub async fn run_startup_cleanup(logger_state: LoggerState) {
+    let mut removed = Vec::new();
+
+    if let Some(pid) = stop_orphaned_rojo().await {
+        removed.push(format!("Stopped Rojo left running by a previous session (pid {pid})"));
+    }
+    if kill_legacy_mcp().await {
+        removed.push("Stopped leftover rbxsync servers from an older version".into());
+    }
+
+    removed.extend(crate::commands::config::mark_missing_projects());
+
+    let projects = crate::commands::config::read_config()
+        .map(|c| c.projects)
+        .unwrap_or_default();
+    for project in projects.iter().filter(|p| Path::new(&p.path).join(".git").exists()) {
+        let lock = Path::new(&project.path).join(".git").join("index.lock");
+        if is_stale(&lock) {
+            removed.push(format!(
+                "Found an old git lock in {} (.git/index.lock). If no git program is running, delete it so backups can run.",
+                project.path
+            ));
+        }
+        let path = project.path.clone();
+        let recovered = tokio::task::spawn_blocking(move || backup::recover_unrecorded_backups(&path))
+            .await
+            .unwrap_or_default();
+        if !recovered.is_empty() {
+            removed.push(format!(
+                "Recorded backups missing from the manifest in {}: {}",
+                project.path,
+                recovered.join(", ")
+            ));
+        }
+    }
+
+    let temp = std::env::temp_dir();
+    for dir in [temp.join("roxlit-installer"), temp.join("roxlit-preview")] {
+        if dir.exists() && std::fs::remove_dir_all(&dir).is_ok() {
+            removed.push(format!("Removed temp directory {}", dir.display()));
+        }
+    }
+    if let Some(installers) = crate::commands::update::installers_dir() {
+        for entry in std::fs::read_dir(installers).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "part") && is_stale(&path) && std::fs::remove_file(&path).is_ok() {
+                removed.push(format!("Removed partial download {}", path.display()));
+            }
+        }
+    }
+
+    logger_state.log_system("cleanup", &removed).await;
+}
+
+/// Modified longer than `STALE_AFTER` ago.
+fn is_stale(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.elapsed().ok())
+        .is_some_and(|age| age > STALE_AFTER)
+}
+
+/// Stops the Rojo server recorded in the PID file, if it's still running
+/// (the launcher crashed or was killed mid-session). Returns its PID.
+async fn stop_orphaned_rojo() -> Option<u32> {
+    let path = rojo_pid_file()?;
+    let pid = std::fs::read_to_string(&path).ok()?.trim().parse::<u32>().ok();
+    let _ = std::fs::remove_file(&path);
+    let pid = pid?;
+    if !is_rojo_process(pid).await {
+        return None;
+    }
+
+    #[cfg(target_os = "windows")]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("taskkill");
+        // /T: also the processes it started
+        cmd.args(["/F", "/T", "/PID", &pid.to_string()]).creation_flags(0x08000000); // CREATE_NO_WINDOW
+        cmd
+    };
+    #[cfg(not(target_os = "windows"))]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("kill");
+        cmd.arg(pid.to_string());
+        cmd
+    };
+    let killed = cmd.output().await.is_ok_and(|o| o.status.success());
+    killed.then_some(pid)
+}
+
+/// The PID still belongs to Rojo (PIDs get reused after a reboot).
+async fn is_rojo_process(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    let name = tokio::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/FO", "CSV", "/NH"])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .output()
+        .await
+        .ok()
+        .and_then(|o| crate::util::tasklist_image(&String::from_utf8_lossy(&o.stdout), pid));
+    #[cfg(not(target_os = "windows"))]
+    let name = tokio::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "comm="])
+        .output()
+        .await
+        .ok()
+        .map(|o| String::from_utf8_lossy(&o.stdout).to_string());
+
+    name.is_some_and(|n| n.to_lowercase().contains("rojo"))
+}
+
+/// Kills rbxsync and rbxsync-mcp servers left by versions that shipped them.
+/// The current roxlit-mcp is left alone, since AI tools may have it running.
+/// Returns true if any were found.
+async fn kill_legacy_mcp() -> bool {
+    #[cfg(target_os = "windows")]
+    let commands: [(&str, [&str; 3]); 2] = [
+        ("taskkill", ["/F", "/IM", "rbxsync.exe"]),
+        ("taskkill", ["/F", "/IM", "rbxsync-mcp.exe"]),
+    ];
+    #[cfg(not(target_os = "windows"))]
+    let commands: [(&str, [&str; 2]); 2] = [
+        ("pkill", ["-f", "rbxsync serve"]),
+        ("pkill", ["-x", "rbxsync-mcp"]),
+    ];
+
+    let mut killed = false;
+    for (program, args) in commands {
+        let mut cmd = tokio::process::Command::new(program);
+        cmd.args(args);
+        #[cfg(target_os = "windows")]
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        killed |= cmd.output().await.is_ok_and(|o| o.status.success());
+    }
+    killed
+}
@@ -15,6 +15,167 @@ pub struct ProjectEntry {
     pub place_id: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub universe_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "ProjectSettings::is_default")]
+    pub settings: ProjectSettings,
+    /// Places of a multi-place experience (e.g. lobby and game). Empty for
+    /// single-place projects, which only use `place_id`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub places: Vec<ProjectPlace>,
+    /// Name of the place in `places` that sessions open and serve. Its ids
+    /// are mirrored in `place_id` / `universe_id`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_place: Option<String>,
+    /// Adopted by `adopt_project` and never synced: start_rojo holds Rojo back
+    /// until the user confirms what the first sync changes in Studio.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub first_sync_pending: bool,
+    /// The folder or its default.project.json wasn't there at the last
+    /// startup check. Kept until `prune_missing_projects` archives it, in case
+    /// the folder is on a drive that isn't mounted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub missing: bool,
+}
+
+/// A project removed by `prune_missing_projects`, kept so its linked places
+/// can be found again if the folder turns up.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedProject {
+    pub name: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub universe_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub places: Vec<ProjectPlace>,
+    pub archived_at: String,
+}
+
+/// One place of a multi-place project.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPlace {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub place_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub universe_id: Option<u64>,
+    /// Rojo project file served for this place, relative to the project
+    /// (default `default.project.json`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_file: Option<String>,
+}
+
+impl ProjectEntry {
+    /// The selected entry of `places`, if any.
+    pub fn active_place(&self) -> Option<&ProjectPlace> {
+        let name = self.active_place.as_deref()?;
+        self.places.iter().find(|p| p.name == name)
+    }
+
+    /// Rojo project file to serve and build, relative to the project.
+    pub fn project_file(&self) -> &str {
+        self.active_place()
+            .and_then(|p| p.project_file.as_deref())
+            .unwrap_or("default.project.json")
+    }
+}
+
+/// Rojo project file of the project at `project_path` (its active place's,
+/// or `default.project.json`).
+pub fn project_file(project_path: &str) -> String {
+    find_project(project_path)
+        .map(|p| p.project_file().to_string())
+        .unwrap_or_else(|| "default.project.json".into())
+}
+
+/// Per-project preferences, edited from the launcher's project settings.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSettings {
+    /// Generate the trimmed AI context (for tools that inject it into every
+    /// message) instead of the full one.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compact_context: bool,
+    /// Team rules added to every generated context file, above the user notes:
+    /// a path to a markdown file (e.g. `docs/ai-rules.md`) or inline text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_extra: Option<String>,
+    /// Open Studio on "Start Development" when a place is linked (default on).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_open_studio: Option<bool>,
+    /// Capture Studio output into output.log (default on).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_logs: Option<bool>,
+    /// Write the AI tool's MCP config and the MCP section of the context
+    /// (default on, when the MCP server is installed).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_config: Option<bool>,
+    /// When no place is linked, auto-open a local `rojo build` of the project
+    /// in Studio instead of asking the user to open one (default off). The
+    /// build runs once; later starts reopen the file Studio saved into.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_local_place: Option<bool>,
+    /// Stop the dev session when Studio closes or crashes mid-session,
+    /// instead of only warning (default off).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_on_studio_lost: Option<bool>,
+    /// A file every collaborator's launcher also appends Studio output to,
+    /// tagged with their name, for Team Create debugging: relative to the
+    /// project or absolute (e.g. on a shared network folder). Machine-local,
+    /// like `env`: each collaborator sets it, roxlit.toml never does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_log: Option<String>,
+    /// Minutes between auto-backups during a session (default 10).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_interval_mins: Option<u32>,
+    /// Auto-backups are pruned, oldest first, above this total size in MB
+    /// (default 100).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_limit_mb: Option<u64>,
+    /// Keep `.roxlit/logs/latest-errors.log` with only this session's Studio
+    /// errors, for AI tools that watch a file instead of reading the full log
+    /// (default off).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_log: Option<bool>,
+    /// How Roxlit generates default.project.json when it (re)creates it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_json: Option<crate::templates::ProjectJsonOptions>,
+    /// Environment variables for the tools Roxlit runs for this project
+    /// (`rojo serve`, `rojo build`, `aftman install`), e.g. `RUST_LOG=debug`
+    /// to debug a Rojo issue without running it by hand. Machine-local: never
+    /// read from or written to roxlit.toml.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<std::collections::BTreeMap<String, String>>,
+    /// Automated playtests while a session runs (see playtest.rs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playtest_schedule: Option<crate::commands::playtest::PlaytestSchedule>,
+}
+
+impl ProjectSettings {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn auto_open_studio(&self) -> bool {
+        self.auto_open_studio.unwrap_or(true)
+    }
+
+    pub fn capture_logs(&self) -> bool {
+        self.capture_logs.unwrap_or(true)
+    }
+
+    pub fn mcp_config(&self) -> bool {
+        self.mcp_config.unwrap_or(true)
+    }
+
+    /// Sets the project's `env` variables on a child process.
+    pub fn apply_env(&self, cmd: &mut tokio::process::Command) {
+        if let Some(env) = &self.env {
+            cmd.envs(env);
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -29,60 +190,338 @@ pub struct RoxlitConfig {
     pub dismissed_version: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_delay_days: Option<u32>,
+    /// Optional GitHub personal access token for API requests (raises the
+    /// unauthenticated 60 req/h limit that shared office IPs exhaust quickly).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_token: Option<String>,
+    /// Unix timestamp after which a rate-limited update check should be retried,
+    /// bypassing the usual 24h check interval.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_retry_at: Option<i64>,
+    /// Overrides the home directory as the base for tool binaries
+    /// (`<toolDir>/.aftman/bin`, `<toolDir>/.roxlit/bin`) for users whose home
+    /// is on a roaming profile or quota-limited network share.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_dir: Option<String>,
+    /// Studio plugins folder to install and update plugins in, instead of
+    /// Studio's default, for custom Studio installs (e.g. under Wine) or
+    /// plugins kept in a synced folder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugins_dir: Option<String>,
+    /// Terminal for `open_terminal` and Claude Code, e.g. `wt`, `powershell`,
+    /// `iTerm` or `konsole`. Unset uses the OS's usual terminal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal: Option<String>,
+    /// Editor (`vscode`, `cursor`, `windsurf`) that opens projects whose AI
+    /// tool's own editor isn't installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_editor: Option<String>,
+    /// Mask player names and UserIds in captured Studio output, so logs can be
+    /// pasted into AI chats and bug reports without leaking player data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redact_logs: Option<bool>,
+    /// Minimum level per log prefix (`studio`, `rojo-plugin`, `rojo`, `rojo-err`,
+    /// `roxlit`, `mcp`, `telemetry`): `info`, `warn`, `error`, or `off`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_levels: Option<std::collections::BTreeMap<String, String>>,
+    /// Seconds after "Start Development" before warning that the Studio plugin
+    /// hasn't connected (default 90, 0 disables the warning).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_grace_secs: Option<u64>,
+    /// Versions of the tools Roxlit installed itself (`aftman`, `rojo`,
+    /// `rojo-plugin`, `roxlit-plugin`, `roxlit-mcp`), recorded at install and
+    /// update time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installed_tools: Option<std::collections::BTreeMap<String, String>>,
+    /// Download a pending update's installer in the background so applying it
+    /// doesn't wait for the download.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefetch_updates: Option<bool>,
+    /// Opt-in anonymous usage analytics (see analytics.rs). Off unless set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analytics_enabled: Option<bool>,
+    /// Random id generated when analytics are first enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analytics_id: Option<String>,
+    /// App version that last started with this config; a different one at
+    /// startup triggers a snapshot (see config_backup.rs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_version: Option<String>,
+    /// Hash of the generated part of each AI context file (keyed by path)
+    /// as last written, to notice hand edits before regenerating it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_hashes: Option<std::collections::BTreeMap<String, String>>,
+    /// Capture settings served to the Studio plugin (see `PluginConfig`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_config: Option<PluginConfig>,
+    /// Studio output `[ScriptName]` prefixes muted or soloed (see `ScriptFilter`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script_filter: Option<ScriptFilter>,
+    /// Projects pruned because their folder was gone (see `ArchivedProject`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_projects: Option<Vec<ArchivedProject>>,
+    /// Weekly background maintenance (see maintenance.rs). On unless set to false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maintenance: Option<bool>,
+    /// Unix timestamp of the last maintenance run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_maintenance: Option<u64>,
+    /// Desktop notifications per category (`studioDisconnected`,
+    /// `playtestErrors`, `updateReady`); a category left out is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<std::collections::BTreeMap<String, bool>>,
+}
+
+/// Per-script filtering of Studio output by its leading `[ScriptName]`
+/// prefix (compared case-insensitively). Applies to output.log and what the
+/// frontend streams; errors and playtest markers are always kept.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptFilter {
+    /// Scripts whose lines are dropped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub muted: Vec<String>,
+    /// When not empty, only these scripts' lines are kept.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub solo: Vec<String>,
+}
+
+/// Capture settings the Studio plugin polls from `GET /plugin-config`, so they
+/// apply to a running Studio without rebuilding the plugin. Unset fields keep
+/// the plugin's built-in defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginConfig {
+    /// Milliseconds between log batches sent to the launcher.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_interval_ms: Option<u64>,
+    /// Studio message types captured: `output`, `info`, `warning`, `error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub levels: Option<Vec<String>>,
+    /// Stop capturing Studio output until unpaused.
+    #[serde(default)]
+    pub paused: bool,
+    /// Report plugin-side metrics (batch sizes, send timings) with each batch.
+    #[serde(default)]
+    pub metrics: bool,
+}
+
+impl Default for RoxlitConfig {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            projects: vec![],
+            last_active_project: None,
+            last_update_check: None,
+            dismissed_version: None,
+            update_delay_days: None,
+            github_token: None,
+            update_retry_at: None,
+            tool_dir: None,
+            plugins_dir: None,
+            terminal: None,
+            default_editor: None,
+            redact_logs: None,
+            log_levels: None,
+            plugin_grace_secs: None,
+            installed_tools: None,
+            prefetch_updates: None,
+            analytics_enabled: None,
+            analytics_id: None,
+            app_version: None,
+            context_hashes: None,
+            plugin_config: None,
+            script_filter: None,
+            archived_projects: None,
+            maintenance: None,
+            last_maintenance: None,
+            notifications: None,
+        }
+    }
 }
 
-fn config_path() -> Option<PathBuf> {
+pub(crate) fn config_path() -> Option<PathBuf> {
     dirs::home_dir().map(|h| h.join(".roxlit").join("config.json"))
 }
 
+/// Writes `~/.roxlit/config.json` through a temporary file and a rename, so
+/// a crash mid-write can't leave a truncated config behind.
+pub(crate) fn write_config(config: &RoxlitConfig) -> Result<()> {
+    let path = config_path()
+        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    let temp = path.with_extension("json.tmp");
+    std::fs::write(&temp, json)?;
+    std::fs::rename(&temp, &path)?;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn load_config() -> Option<RoxlitConfig> {
+    read_config()
+}
+
+/// Synchronous config read for callers outside async contexts.
+pub fn read_config() -> Option<RoxlitConfig> {
     let path = config_path()?;
     let content = std::fs::read_to_string(&path).ok()?;
     serde_json::from_str(&content).ok()
 }
 
+/// Base directory for tool binaries: the `toolDir` setting, or the home directory.
+/// Accounts without tools of their own fall back to a machine-wide install.
+pub fn tools_home() -> Option<PathBuf> {
+    if let Some(dir) = read_config().and_then(|c| c.tool_dir) {
+        return Some(PathBuf::from(expand_tilde(&dir)));
+    }
+    let home = dirs::home_dir();
+    let home_has_tools = home.as_ref().is_some_and(|h| h.join(".aftman").exists());
+    if !home_has_tools {
+        if let Some(system) = system_tools_dir().filter(|d| d.join(".aftman").exists()) {
+            return Some(system);
+        }
+    }
+    home
+}
+
+/// Machine-wide install location used by the "all users" install mode:
+/// `%ProgramData%\Roxlit` on Windows, `/Library/Application Support/Roxlit` on macOS.
+pub fn system_tools_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        let program_data = std::env::var_os("ProgramData")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"));
+        Some(program_data.join("Roxlit"))
+    } else if cfg!(target_os = "macos") {
+        Some(PathBuf::from("/Library/Application Support/Roxlit"))
+    } else {
+        Some(PathBuf::from("/opt/roxlit"))
+    }
+}
+
+/// Machine-wide copy of the Studio plugins. Studio only loads plugins from the
+/// per-user folder, so the launcher seeds each account from here on start.
+pub fn system_plugins_dir() -> Option<PathBuf> {
+    system_tools_dir().map(|d| d.join("plugins"))
+}
+
+/// Studio's plugins folder for this account (`None` on Linux, which has no
+/// native Studio).
+pub fn default_plugins_dir() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        dirs::data_local_dir().map(|d| d.join("Roblox").join("Plugins"))
+    } else if cfg!(target_os = "macos") {
+        dirs::home_dir().map(|d| d.join("Library").join("Roblox").join("Plugins"))
+    } else {
+        None
+    }
+}
+
+/// The Studio plugins folder every plugin install and refresh writes to: the
+/// `pluginsDir` setting, or Studio's default. Plugins are per machine, so
+/// there is no per-project folder.
+pub fn plugins_dir() -> Option<PathBuf> {
+    read_config()
+        .and_then(|c| c.plugins_dir)
+        .map(|dir| PathBuf::from(expand_tilde(&dir)))
+        .or_else(default_plugins_dir)
+}
+
+/// Aftman's home directory (`AFTMAN_HOME`), where it keeps its tool shims.
+pub fn aftman_home() -> Option<PathBuf> {
+    tools_home().map(|h| h.join(".aftman"))
+}
+
+/// Directory containing aftman and the tools it installs (rojo).
+pub fn aftman_bin_dir() -> Option<PathBuf> {
+    aftman_home().map(|h| h.join("bin"))
+}
+
+/// Directory containing Roxlit-managed binaries (roxlit-mcp).
+pub fn roxlit_bin_dir() -> Option<PathBuf> {
+    tools_home().map(|h| h.join(".roxlit").join("bin"))
+}
+
+/// Full path of the roxlit-mcp binary (which may not exist yet).
+pub fn mcp_binary_path() -> Option<PathBuf> {
+    let name = if cfg!(target_os = "windows") { "roxlit-mcp.exe" } else { "roxlit-mcp" };
+    roxlit_bin_dir().map(|d| d.join(name))
+}
+
+/// Persist the tool directory override chosen during installation.
+pub fn save_tool_dir(tool_dir: &str) -> Result<()> {
+    let mut config = read_config().unwrap_or_default();
+    config.tool_dir = Some(expand_tilde(tool_dir));
+
+    write_config(&config)?;
+
+    Ok(())
+}
+
+impl RoxlitConfig {
+    /// Version of a tool Roxlit installed, if it recorded one.
+    pub fn tool_version(&self, tool: &str) -> Option<&str> {
+        self.installed_tools.as_ref()?.get(tool).map(String::as_str)
+    }
+}
+
+/// Record the version of a tool Roxlit just installed or updated.
+pub fn save_tool_version(tool: &str, version: &str) -> Result<()> {
+    let mut config = read_config().unwrap_or_default();
+    config
+        .installed_tools
+        .get_or_insert_with(Default::default)
+        .insert(tool.to_string(), version.to_string());
+
+    write_config(&config)?;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn save_project(project: ProjectEntry) -> Result<RoxlitConfig> {
-    let path = config_path()
-        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
-
-    let mut config = load_config().await.unwrap_or(RoxlitConfig {
-        version: 1,
-        projects: vec![],
-        last_active_project: None,
-        last_update_check: None,
-        dismissed_version: None,
-        update_delay_days: None,
-    });
+    let mut config = load_config().await.unwrap_or_default();
 
     // Expand tilde so paths are always absolute
     let mut project = project;
     project.path = expand_tilde(&project.path);
+    project.missing = !project_exists(&project.path);
 
-    // Upsert by path — preserve place_id/universe_id from existing entry
+    // Upsert by path — preserve place_id/universe_id/settings from existing entry
     if let Some(existing) = config.projects.iter_mut().find(|p| p.path == project.path) {
         let preserved_place_id = existing.place_id;
         let preserved_universe_id = existing.universe_id;
+        let preserved_settings = existing.settings.clone();
+        let preserved_places = std::mem::take(&mut existing.places);
+        let preserved_active_place = existing.active_place.take();
+        let preserved_first_sync_pending = existing.first_sync_pending;
         *existing = project.clone();
+        // Only confirm_first_sync clears it
+        existing.first_sync_pending |= preserved_first_sync_pending;
+        if existing.places.is_empty() {
+            existing.places = preserved_places;
+            existing.active_place = preserved_active_place;
+        }
         if existing.place_id.is_none() {
             existing.place_id = preserved_place_id;
         }
         if existing.universe_id.is_none() {
             existing.universe_id = preserved_universe_id;
         }
+        if existing.settings.is_default() {
+            existing.settings = preserved_settings;
+        }
     } else {
         config.projects.push(project.clone());
     }
     config.last_active_project = Some(project.path);
 
     // Write
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
-    std::fs::write(&path, json)?;
+    write_config(&config)?;
 
     Ok(config)
 }
@@ -92,17 +531,7 @@ pub async fn save_update_state(
     last_update_check: Option<String>,
     dismissed_version: Option<String>,
 ) -> Result<()> {
-    let path = config_path()
-        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
-
-    let mut config = load_config().await.unwrap_or(RoxlitConfig {
-        version: 1,
-        projects: vec![],
-        last_active_project: None,
-        last_update_check: None,
-        dismissed_version: None,
-        update_delay_days: None,
-    });
+    let mut config = load_config().await.unwrap_or_default();
 
     if last_update_check.is_some() {
         config.last_update_check = last_update_check;
@@ -111,40 +540,193 @@ pub async fn save_update_state(
         config.dismissed_version = dismissed_version;
     }
 
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
+    write_config(&config)?;
+
+    Ok(())
+}
+
+/// Settings changed from the launcher. Unset fields keep their saved value.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsPatch {
+    #[serde(default)]
+    pub update_delay_days: Option<u32>,
+    /// Empty string clears the token.
+    #[serde(default)]
+    pub github_token: Option<String>,
+    /// Empty string resets to the home directory.
+    #[serde(default)]
+    pub tool_dir: Option<String>,
+    /// Empty string resets to Studio's default folder.
+    #[serde(default)]
+    pub plugins_dir: Option<String>,
+    /// Empty string goes back to the OS's usual terminal.
+    #[serde(default)]
+    pub terminal: Option<String>,
+    /// Empty string clears the default editor.
+    #[serde(default)]
+    pub default_editor: Option<String>,
+    #[serde(default)]
+    pub redact_logs: Option<bool>,
+    /// Empty map clears all filters.
+    #[serde(default)]
+    pub log_levels: Option<std::collections::BTreeMap<String, String>>,
+    #[serde(default)]
+    pub plugin_grace_secs: Option<u64>,
+    #[serde(default)]
+    pub prefetch_updates: Option<bool>,
+    #[serde(default)]
+    pub analytics_enabled: Option<bool>,
+    #[serde(default)]
+    pub maintenance: Option<bool>,
+    /// Empty map turns every category back on.
+    #[serde(default)]
+    pub notifications: Option<std::collections::BTreeMap<String, bool>>,
+}
+
+#[tauri::command]
+pub async fn save_settings(patch: SettingsPatch) -> Result<()> {
+    let SettingsPatch {
+        update_delay_days,
+        github_token,
+        tool_dir,
+        plugins_dir,
+        terminal,
+        default_editor,
+        redact_logs,
+        log_levels,
+        plugin_grace_secs,
+        prefetch_updates,
+        analytics_enabled,
+        maintenance,
+        notifications,
+    } = patch;
+    let mut config = load_config().await.unwrap_or_default();
+
+    if update_delay_days.is_some() {
+        config.update_delay_days = update_delay_days;
     }
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
-    std::fs::write(&path, json)?;
+    // Empty string clears the token
+    if let Some(token) = github_token {
+        let token = token.trim().to_string();
+        config.github_token = if token.is_empty() { None } else { Some(token) };
+    }
+    // Empty string resets to the home directory
+    if let Some(dir) = tool_dir {
+        let dir = dir.trim();
+        config.tool_dir = if dir.is_empty() { None } else { Some(expand_tilde(dir)) };
+    }
+    // Empty string resets to Studio's default folder
+    if let Some(dir) = plugins_dir {
+        let dir = dir.trim();
+        config.plugins_dir = if dir.is_empty() { None } else { Some(expand_tilde(dir)) };
+    }
+    // Empty string goes back to the OS's usual terminal
+    if let Some(terminal) = terminal {
+        let terminal = terminal.trim();
+        config.terminal = if terminal.is_empty() { None } else { Some(terminal.to_string()) };
+    }
+    // Empty string clears the default editor
+    if let Some(editor) = default_editor {
+        let editor = editor.trim();
+        config.default_editor = if editor.is_empty() { None } else { Some(editor.to_string()) };
+    }
+    if redact_logs.is_some() {
+        config.redact_logs = redact_logs;
+    }
+    // Empty map clears all filters
+    if let Some(levels) = log_levels {
+        config.log_levels = if levels.is_empty() { None } else { Some(levels) };
+    }
+    if plugin_grace_secs.is_some() {
+        config.plugin_grace_secs = plugin_grace_secs;
+    }
+    if prefetch_updates.is_some() {
+        config.prefetch_updates = prefetch_updates;
+    }
+    if maintenance.is_some() {
+        config.maintenance = maintenance;
+    }
+    // Empty map turns every category back on
+    if let Some(categories) = notifications {
+        config.notifications = if categories.is_empty() { None } else { Some(categories) };
+    }
+    if let Some(enabled) = analytics_enabled {
+        config.analytics_enabled = Some(enabled);
+        if enabled && config.analytics_id.is_none() {
+            config.analytics_id = Some(crate::commands::analytics::new_install_id());
+        }
+    }
+
+    write_config(&config)?;
+
+    // Apply new log filters to the running session
+    crate::commands::logs::reload_log_levels();
 
     Ok(())
 }
 
+/// Replaces the Studio plugin's capture settings. A connected plugin picks
+/// them up on its next `/plugin-config` poll.
 #[tauri::command]
-pub async fn save_settings(update_delay_days: u32) -> Result<()> {
-    let path = config_path()
-        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
+pub async fn set_plugin_config(
+    plugin_config: PluginConfig,
+    status: tauri::State<'_, crate::commands::logs::LauncherStatus>,
+) -> Result<()> {
+    let mut config = load_config().await.unwrap_or_default();
+    config.plugin_config = (plugin_config != PluginConfig::default()).then(|| plugin_config.clone());
+    write_config(&config)?;
 
-    let mut config = load_config().await.unwrap_or(RoxlitConfig {
-        version: 1,
-        projects: vec![],
-        last_active_project: None,
-        last_update_check: None,
-        dismissed_version: None,
-        update_delay_days: None,
-    });
+    status.set_plugin_config(plugin_config).await;
+    Ok(())
+}
 
-    config.update_delay_days = Some(update_delay_days);
+/// The muted and soloed Studio scripts.
+#[tauri::command]
+pub async fn get_script_filter() -> ScriptFilter {
+    load_config().await.and_then(|c| c.script_filter).unwrap_or_default()
+}
 
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
+/// Mutes or unmutes a script's `[ScriptName]` output.
+#[tauri::command]
+pub async fn mute_script(name: String, muted: bool) -> Result<ScriptFilter> {
+    update_script_filter(|filter| toggle_name(&mut filter.muted, &name, muted)).await
+}
+
+/// Adds or removes a script from the solo list; while any script is soloed,
+/// only soloed scripts' output is kept.
+#[tauri::command]
+pub async fn solo_script(name: String, solo: bool) -> Result<ScriptFilter> {
+    update_script_filter(|filter| toggle_name(&mut filter.solo, &name, solo)).await
+}
+
+fn toggle_name(names: &mut Vec<String>, name: &str, on: bool) {
+    let name = name.trim().trim_start_matches('[').trim_end_matches(']');
+    names.retain(|n| !n.eq_ignore_ascii_case(name));
+    if on && !name.is_empty() {
+        names.push(name.to_string());
     }
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
-    std::fs::write(&path, json)?;
+}
 
-    Ok(())
+/// Saves a change to the script filter and applies it to the running session.
+async fn update_script_filter(change: impl FnOnce(&mut ScriptFilter)) -> Result<ScriptFilter> {
+    let mut config = load_config().await.unwrap_or_default();
+    let mut filter = config.script_filter.take().unwrap_or_default();
+    change(&mut filter);
+    config.script_filter = (filter != ScriptFilter::default()).then(|| filter.clone());
+    write_config(&config)?;
+
+    crate::commands::logs::reload_log_levels();
+    Ok(filter)
+}
+
+/// Versions of the tools Roxlit installed (see `RoxlitConfig::installed_tools`).
+#[tauri::command]
+pub async fn get_tool_versions() -> std::collections::BTreeMap<String, String> {
+    load_config()
+        .await
+        .and_then(|c| c.installed_tools)
+        .unwrap_or_default()
 }
 
 #[derive(Clone, Serialize)]
@@ -201,7 +783,7 @@ pub async fn scan_for_projects(parent_dir: String) -> Vec<DiscoveredProject> {
 }
 
 /// Detects which AI tool a project uses by checking for context files.
-fn detect_ai_tool(project_path: &Path) -> String {
+pub(crate) fn detect_ai_tool(project_path: &Path) -> String {
     if project_path.join("CLAUDE.md").exists() {
         return "claude".to_string();
     }
@@ -225,35 +807,238 @@ fn detect_ai_tool(project_path: &Path) -> String {
 /// Checks if a project path still exists on disk (directory + default.project.json).
 #[tauri::command]
 pub async fn check_project_exists(path: String) -> bool {
-    let expanded = expand_tilde(&path);
+    project_exists(&path)
+}
+
+pub(crate) fn project_exists(path: &str) -> bool {
+    let expanded = expand_tilde(path);
     let path = Path::new(&expanded);
     path.exists() && path.join("default.project.json").exists()
 }
 
+/// Flags registered projects whose folder is gone as `missing` (and clears
+/// the flag on ones that are back), so they aren't picked as the project to
+/// resume. Run at startup; returns what changed, for the log.
+pub fn mark_missing_projects() -> Vec<String> {
+    let Some(mut config) = read_config() else { return Vec::new() };
+    let mut changes = Vec::new();
+    for project in &mut config.projects {
+        let missing = !project_exists(&project.path);
+        if missing != project.missing {
+            project.missing = missing;
+            changes.push(if missing {
+                format!("Project {} is missing ({} not found)", project.name, project.path)
+            } else {
+                format!("Project {} was found again at {}", project.name, project.path)
+            });
+        }
+    }
+    if !changes.is_empty() {
+        let _ = write_config(&config);
+    }
+    changes
+}
+
+/// Removes projects whose folder is gone from the project list, moving them
+/// to `archivedProjects` with their linked place ids. Checks the disk again
+/// rather than trusting the startup flag. Returns the archived entries.
+#[tauri::command]
+pub async fn prune_missing_projects() -> Result<Vec<ArchivedProject>> {
+    let mut config = load_config().await.unwrap_or_default();
+
+    let (missing, kept): (Vec<ProjectEntry>, Vec<ProjectEntry>) =
+        config.projects.drain(..).partition(|p| !project_exists(&p.path));
+    config.projects = kept;
+    if missing.is_empty() {
+        return Ok(Vec::new());
+    }
+    if config.last_active_project.as_ref().is_some_and(|active| missing.iter().any(|p| &p.path == active)) {
+        config.last_active_project = None;
+    }
+
+    let archived_at = crate::commands::backup::now_timestamp();
+    let pruned: Vec<ArchivedProject> = missing
+        .into_iter()
+        .map(|p| ArchivedProject {
+            name: p.name,
+            path: p.path,
+            place_id: p.place_id,
+            universe_id: p.universe_id,
+            places: p.places,
+            archived_at: archived_at.clone(),
+        })
+        .collect();
+    let archive = config.archived_projects.get_or_insert_with(Vec::new);
+    // A project pruned again replaces its older archive entry
+    archive.retain(|a| !pruned.iter().any(|p| p.path == a.path));
+    archive.extend(pruned.iter().cloned());
+
+    write_config(&config)?;
+    Ok(pruned)
+}
+
+/// Returns the config entry for a project path, if registered.
+pub fn find_project(project_path: &str) -> Option<ProjectEntry> {
+    let path = expand_tilde(project_path);
+    read_config()?.projects.into_iter().find(|p| p.path == path)
+}
+
+/// Replaces a registered project's settings. Context changes take effect on
+/// the next "Start Development".
+#[tauri::command]
+pub async fn save_project_settings(path: String, settings: ProjectSettings) -> Result<()> {
+    let mut config = load_config().await.unwrap_or_default();
+    let path = expand_tilde(&path);
+    let project = config
+        .projects
+        .iter_mut()
+        .find(|p| p.path == path)
+        .ok_or_else(|| InstallerError::Custom(format!("Project not found: {path}")))?;
+    project.settings = settings;
+    crate::commands::manifest::sync_from_config(&path, &project.settings)?;
+
+    write_config(&config)?;
+
+    Ok(())
+}
+
+/// Loads the config, applies `update` to the project registered at `path`,
+/// saves it, and returns the updated project.
+pub(crate) async fn update_project(path: &str, update: impl FnOnce(&mut ProjectEntry) -> Result<()>) -> Result<ProjectEntry> {
+    let mut config = load_config().await.unwrap_or_default();
+    let path = expand_tilde(path);
+    let project = config
+        .projects
+        .iter_mut()
+        .find(|p| p.path == path)
+        .ok_or_else(|| InstallerError::Custom(format!("Project not found: {path}")))?;
+    update(project)?;
+    let updated = project.clone();
+
+    write_config(&config)?;
+
+    Ok(updated)
+}
+
+/// Adds a place to a project, or replaces the one with the same name. The
+/// first place added becomes the active one.
+#[tauri::command]
+pub async fn save_place(project_path: String, place: ProjectPlace) -> Result<ProjectEntry> {
+    if place.name.trim().is_empty() {
+        return Err(InstallerError::Custom("The place needs a name".into()));
+    }
+    if let Some(file) = &place.project_file {
+        let file = Path::new(&expand_tilde(&project_path)).join(file);
+        if !file.exists() {
+            return Err(InstallerError::Custom(format!("{} does not exist", file.display())));
+        }
+    }
+    update_project(&project_path, |project| {
+        match project.places.iter_mut().find(|p| p.name == place.name) {
+            Some(existing) => *existing = place,
+            None => project.places.push(place),
+        }
+        if project.active_place().is_none() {
+            project.active_place = project.places.first().map(|p| p.name.clone());
+        }
+        mirror_active_place(project);
+        Ok(())
+    })
+    .await
+}
+
+/// Removes a place from a project. Removing the active place selects the
+/// first remaining one.
+#[tauri::command]
+pub async fn remove_place(project_path: String, name: String) -> Result<ProjectEntry> {
+    update_project(&project_path, |project| {
+        project.places.retain(|p| p.name != name);
+        if project.active_place().is_none() {
+            project.active_place = project.places.first().map(|p| p.name.clone());
+        }
+        mirror_active_place(project);
+        Ok(())
+    })
+    .await
+}
+
+/// Selects the place the next session opens in Studio and serves with Rojo.
+/// Takes effect on the next "Start Development".
+#[tauri::command]
+pub async fn set_active_place(project_path: String, name: String) -> Result<ProjectEntry> {
+    update_project(&project_path, |project| {
+        if !project.places.iter().any(|p| p.name == name) {
+            return Err(InstallerError::Custom(format!("No place named {name} in this project")));
+        }
+        project.active_place = Some(name);
+        mirror_active_place(project);
+        Ok(())
+    })
+    .await
+}
+
+/// Copies the active place's ids to the project's own, which is where
+/// auto-open, the plugin handshake and the AI context read them from.
+fn mirror_active_place(project: &mut ProjectEntry) {
+    if let Some(place) = project.active_place().cloned() {
+        project.place_id = place.place_id;
+        project.universe_id = place.universe_id;
+    }
+}
+
 /// Persists the active project path in config so it's remembered on next launch.
 #[tauri::command]
 pub async fn set_active_project(path: String) -> Result<()> {
-    let config_path = config_path()
-        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
-
-    let mut config = load_config().await.unwrap_or(RoxlitConfig {
-        version: 1,
-        projects: vec![],
-        last_active_project: None,
-        last_update_check: None,
-        dismissed_version: None,
-        update_delay_days: None,
-    });
+    let mut config = load_config().await.unwrap_or_default();
 
     config.last_active_project = Some(expand_tilde(&path));
 
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
-    std::fs::write(&config_path, json)?;
+    write_config(&config)?;
 
     Ok(())
 }
 
+/// Record when a rate-limited update check should be retried.
+/// Best-effort — silently ignores errors like `save_place_id`.
+pub fn save_update_retry_at(retry_at: Option<i64>) {
+    let path = match config_path() {
+        Some(p) => p,
+        None => return,
+    };
+
+    let content = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let mut config: RoxlitConfig = match serde_json::from_str(&content) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    config.update_retry_at = retry_at;
+    let _ = write_config(&config);
+}
+
+/// Record when maintenance last ran.
+pub fn save_last_maintenance(timestamp: u64) -> Result<()> {
+    let mut config = read_config().unwrap_or_default();
+    config.last_maintenance = Some(timestamp);
+    write_config(&config)?;
+    Ok(())
+}
+
+/// Record the hash of a context file's generated part.
+/// Best-effort — silently ignores errors like `save_place_id`.
+pub fn save_context_hash(context_file: &Path, hash: &str) {
+    let mut config = read_config().unwrap_or_default();
+    config
+        .context_hashes
+        .get_or_insert_with(Default::default)
+        .insert(context_file.to_string_lossy().to_string(), hash.to_string());
+    let _ = write_config(&config);
+}
+
 /// Persist a placeId and universeId for the given project path in the config file.
 /// Called when stop_rojo flushes the linked IDs from LauncherStatus.
 pub fn save_place_id(project_path: &str, place_id: u64, universe_id: Option<u64>) {
@@ -277,8 +1062,14 @@ pub fn save_place_id(project_path: &str, place_id: u64, universe_id: Option<u64>
         if let Some(uid) = universe_id {
             project.universe_id = Some(uid);
         }
-        if let Ok(json) = serde_json::to_string_pretty(&config) {
-            let _ = std::fs::write(&path, json);
+        // Studio linked the selected place of a multi-place project
+        let active = project.active_place.clone();
+        if let Some(place) = project.places.iter_mut().find(|p| Some(&p.name) == active.as_ref()) {
+            place.place_id = Some(place_id);
+            if let Some(uid) = universe_id {
+                place.universe_id = Some(uid);
+            }
         }
+        let _ = write_config(&config);
     }
 }
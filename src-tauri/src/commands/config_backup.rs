@@ -0,0 +1,145 @@
+//! Copies of `~/.roxlit/config.json` taken at startup whenever the app
+//! version changes, before the new version reads or rewrites it. If an
+//! upgrade loses projects or settings, the copy written by the previous
+//! version can be restored from `~/.roxlit/config-backups/<version>.json`.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::commands::config::{self, RoxlitConfig};
+use crate::error::{InstallerError, Result};
+
+/// Snapshots kept; older ones are deleted.
+const KEEP_BACKUPS: usize = 10;
+
+/// A config snapshot that can be restored.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigBackup {
+    /// Version that wrote the config ("unknown" for versions that didn't
+    /// record it), or "pre-restore" for the config replaced by a restore.
+    pub version: String,
+    pub path: String,
+    /// Projects in the snapshot, to tell the right one apart at a glance.
+    pub projects: usize,
+}
+
+fn backups_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("config-backups"))
+}
+
+/// Called once at startup: if config.json was last written by another app
+/// version, copies it to `config-backups/<that version>.json` and then
+/// records the current version. Best-effort — a failure never blocks startup.
+pub fn snapshot_on_upgrade() {
+    let (Some(path), Some(dir)) = (config::config_path(), backups_dir()) else {
+        return;
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return; // First run
+    };
+    let current = env!("CARGO_PKG_VERSION");
+    // Parsed loosely: a config the current version can't read still gets its snapshot
+    let previous = serde_json::from_str::<serde_json::Value>(&content)
+        .ok()
+        .and_then(|v| v["appVersion"].as_str().map(String::from));
+    if previous.as_deref() == Some(current) {
+        return;
+    }
+
+    let name = previous.as_deref().filter(|v| is_valid_name(v)).unwrap_or("unknown");
+    if std::fs::create_dir_all(&dir).is_err() || std::fs::write(dir.join(format!("{name}.json")), &content).is_err() {
+        return;
+    }
+    prune(&dir);
+
+    if let Ok(mut parsed) = serde_json::from_str::<RoxlitConfig>(&content) {
+        parsed.app_version = Some(current.to_string());
+        let _ = config::write_config(&parsed);
+    }
+}
+
+/// Version names are used as file names.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+}
+
+fn backup_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect()
+}
+
+/// Deletes all but the newest `KEEP_BACKUPS` snapshots.
+fn prune(dir: &Path) {
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = backup_files(dir)
+        .into_iter()
+        .filter_map(|p| Some((std::fs::metadata(&p).ok()?.modified().ok()?, p)))
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    for (_, path) in files.into_iter().skip(KEEP_BACKUPS) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Config snapshots, newest version first.
+#[tauri::command]
+pub fn list_config_backups() -> Vec<ConfigBackup> {
+    let Some(dir) = backups_dir() else {
+        return Vec::new();
+    };
+    let mut backups: Vec<ConfigBackup> = backup_files(&dir)
+        .into_iter()
+        .filter_map(|path| {
+            let version = path.file_stem()?.to_string_lossy().to_string();
+            let projects = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+                .and_then(|v| v["projects"].as_array().map(Vec::len))
+                .unwrap_or(0);
+            Some(ConfigBackup { version, path: path.to_string_lossy().to_string(), projects })
+        })
+        .collect();
+    // Names that aren't versions ("unknown", "pre-restore") sort last
+    backups.sort_by(|a, b| {
+        if crate::commands::update::is_newer_version(&a.version, &b.version) {
+            std::cmp::Ordering::Greater
+        } else if crate::commands::update::is_newer_version(&b.version, &a.version) {
+            std::cmp::Ordering::Less
+        } else {
+            a.version.cmp(&b.version)
+        }
+    });
+    backups
+}
+
+/// Replaces config.json with a snapshot. The config being replaced is kept
+/// as the `pre-restore` snapshot, so a restore can itself be undone.
+/// Returns the restored config.
+#[tauri::command]
+pub async fn restore_config_backup(version: String) -> Result<RoxlitConfig> {
+    if !is_valid_name(&version) {
+        return Err(InstallerError::Custom(format!("Invalid backup name: {version}")));
+    }
+    let (path, dir) = config::config_path()
+        .zip(backups_dir())
+        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
+    let backup = dir.join(format!("{version}.json"));
+    let content = std::fs::read_to_string(&backup)
+        .map_err(|e| InstallerError::Custom(format!("Cannot read config backup {version}: {e}")))?;
+    let mut restored: RoxlitConfig = serde_json::from_str(&content)
+        .map_err(|e| InstallerError::Custom(format!("Config backup {version} can't be read by this version: {e}")))?;
+
+    if let Ok(current) = std::fs::read_to_string(&path) {
+        std::fs::write(dir.join("pre-restore.json"), current)?;
+    }
+    // Marked as this version's, so the next startup doesn't snapshot it again
+    restored.app_version = Some(env!("CARGO_PKG_VERSION").to_string());
+    config::write_config(&restored)?;
+    Ok(restored)
+}
@@ -1,42 +1,45 @@
 use crate::error::Result;
 use crate::templates;
+use crate::templates::context_packs::IndexEntry;
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Generates AI context files tailored to the selected tool.
 pub fn generate_context(project_path: &str, ai_tool: &str, project_name: &str) -> Result<()> {
     let root = Path::new(project_path);
 
-    // Check if MCP binary exists to include Roxlit MCP info
-    let mcp_bin_name = if cfg!(target_os = "windows") { "roxlit-mcp.exe" } else { "roxlit-mcp" };
-    let mcp_available = dirs::home_dir()
-        .map(|h| h.join(".roxlit").join("bin").join(mcp_bin_name).exists())
+    let mcp_available = mcp_enabled(project_path);
+
+    let compact = crate::commands::config::find_project(project_path)
+        .map(|p| p.settings.compact_context)
         .unwrap_or(false);
+    let full_content = templates::ai_context(project_name, mcp_available);
+    let context_content = if compact {
+        templates::ai_context_compact(project_name, mcp_available)
+    } else {
+        full_content.clone()
+    };
 
-    let context_content = templates::ai_context(project_name, mcp_available);
+    let tool_file = context_file_path(root, ai_tool);
+    let context_file = generated_context_path(root, ai_tool);
+    if let Some(parent) = context_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&context_file, &context_content)?;
+    if context_file != tool_file {
+        link_roxlit_context(&tool_file, ai_tool)?;
+    }
+    update_project_state(root, &context_file)?;
+    crate::commands::config::save_context_hash(&context_file, &generated_hash(&context_content));
 
-    match ai_tool {
-        "claude" => {
-            fs::write(root.join("CLAUDE.md"), &context_content)?;
-        }
-        "cursor" => {
-            fs::write(root.join(".cursorrules"), &context_content)?;
-        }
-        "windsurf" => {
-            fs::write(root.join(".windsurfrules"), &context_content)?;
-        }
-        "vscode" => {
-            // Copilot reads instructions from .github/copilot-instructions.md
-            fs::create_dir_all(root.join(".github"))?;
-            fs::write(
-                root.join(".github").join("copilot-instructions.md"),
-                &context_content,
-            )?;
-        }
-        _ => {
-            // Generic fallback for unknown tools
-            fs::write(root.join("AI-CONTEXT.md"), &context_content)?;
-        }
+    // Compact context links to the full guide instead of inlining it
+    let full_guide = root.join(".roxlit").join("context").join("full-guide.md");
+    if compact {
+        fs::create_dir_all(root.join(".roxlit").join("context"))?;
+        fs::write(&full_guide, &full_content)?;
+    } else if full_guide.exists() {
+        fs::remove_file(&full_guide)?;
     }
 
     // Write context packs to .roxlit/context/
@@ -50,19 +53,299 @@ pub fn generate_context(project_path: &str, ai_tool: &str, project_name: &str) -
     Ok(())
 }
 
+/// Where the generated context goes next to a context file the user wrote by
+/// hand (e.g. an adopted project's own CLAUDE.md), which is never overwritten.
+pub const ROXLIT_CONTEXT_FILE: &str = "ROXLIT.md";
+
+/// Markers around the lines `link_roxlit_context` adds to a hand-written file.
+const ROXLIT_LINK_START: &str = "<!-- roxlit-link:start -->";
+const ROXLIT_LINK_END: &str = "<!-- roxlit-link:end -->";
+
+/// Whether a context file was generated by Roxlit (any version, including
+/// files from before the version marker and the RbxSync-era ones).
+pub fn is_generated_context(content: &str) -> bool {
+    content.contains("roxlit-context-version:")
+        || content.contains(templates::USER_NOTES_MARKER)
+        || content.contains("RbxSync MCP server")
+}
+
+/// The file the generated context is written to: the AI tool's context file,
+/// or `ROXLIT.md` when the tool's file exists and was written by hand.
+pub fn generated_context_path(project_root: &Path, ai_tool: &str) -> PathBuf {
+    let tool_file = context_file_path(project_root, ai_tool);
+    match fs::read_to_string(&tool_file) {
+        Ok(content) if !is_generated_context(&content) => project_root.join(ROXLIT_CONTEXT_FILE),
+        _ => tool_file,
+    }
+}
+
+/// Appends a short, delimited pointer to `ROXLIT.md` to a hand-written
+/// context file (once), leaving the rest of the file as the user wrote it.
+fn link_roxlit_context(tool_file: &Path, ai_tool: &str) -> Result<()> {
+    let content = fs::read_to_string(tool_file)?;
+    if content.contains(ROXLIT_LINK_START) {
+        return Ok(());
+    }
+    // Claude Code inlines files imported with `@path`; other tools get told to read it
+    let pointer = if ai_tool == "claude" {
+        format!("Roblox/Rojo instructions for this project, maintained by Roxlit:\n@{ROXLIT_CONTEXT_FILE}")
+    } else {
+        format!("Read and follow {ROXLIT_CONTEXT_FILE} (Roblox/Rojo instructions for this project, maintained by Roxlit).")
+    };
+    let separator = if content.is_empty() || content.ends_with("\n\n") {
+        ""
+    } else if content.ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    fs::write(
+        tool_file,
+        format!("{content}{separator}{ROXLIT_LINK_START}\n{pointer}\n{ROXLIT_LINK_END}\n"),
+    )?;
+    Ok(())
+}
+
+/// Whether the MCP server is installed and the project hasn't turned off its
+/// MCP config. Decides the context variant and whether MCP config is written.
+pub fn mcp_enabled(project_path: &str) -> bool {
+    let installed = crate::commands::config::mcp_binary_path()
+        .map(|p| p.exists())
+        .unwrap_or(false);
+    installed
+        && crate::commands::config::find_project(project_path)
+            .is_none_or(|p| p.settings.mcp_config())
+}
+
+/// Returns the context file the given AI tool reads.
+pub fn context_file_path(project_root: &Path, ai_tool: &str) -> std::path::PathBuf {
+    match ai_tool {
+        "claude" => project_root.join("CLAUDE.md"),
+        "cursor" => project_root.join(".cursorrules"),
+        "windsurf" => project_root.join(".windsurfrules"),
+        // Copilot reads instructions from .github/copilot-instructions.md
+        "vscode" => project_root.join(".github").join("copilot-instructions.md"),
+        // Generic fallback for unknown tools
+        _ => project_root.join("AI-CONTEXT.md"),
+    }
+}
+
+const CONTEXT_EXTRA_START: &str = "<!-- roxlit-context-extra:start -->";
+const CONTEXT_EXTRA_END: &str = "<!-- roxlit-context-extra:end -->";
+const PROJECT_STATE_START: &str = "<!-- roxlit-project-state:start -->";
+const PROJECT_STATE_END: &str = "<!-- roxlit-project-state:end -->";
+
+/// Refreshes the managed sections of a context file, above the user notes
+/// (which are never touched): the project's `contextExtra` rules, then the
+/// generated "Project State" (linked place, Wally dependencies, instance tree).
+pub fn update_project_state(project_root: &Path, context_file: &Path) -> Result<()> {
+    let content = fs::read_to_string(context_file)?;
+
+    let extra = context_extra(project_root);
+    let updated = replace_managed_block(&content, CONTEXT_EXTRA_START, CONTEXT_EXTRA_END, extra.as_deref());
+    let updated = replace_managed_block(
+        &updated,
+        PROJECT_STATE_START,
+        PROJECT_STATE_END,
+        Some(&project_state(project_root)),
+    );
+
+    if updated != content {
+        fs::write(context_file, updated)?;
+    }
+    Ok(())
+}
+
+/// Replaces the block between `start` and `end` markers with `body`, inserting
+/// it above the user notes if missing. `None` removes the block.
+fn replace_managed_block(content: &str, start: &str, end: &str, body: Option<&str>) -> String {
+    let section = match body {
+        Some(body) => format!("{start}\n{}\n{end}\n\n", body.trim_end()),
+        None => String::new(),
+    };
+
+    match (content.find(start), content.find(end)) {
+        (Some(s), Some(e)) if e > s => {
+            let mut after = e + end.len();
+            // Swallow the blank line written after the end marker
+            while content[after..].starts_with('\n') {
+                after += 1;
+            }
+            format!("{}{section}{}", &content[..s], &content[after..])
+        }
+        _ if section.is_empty() => content.to_string(),
+        _ => match content.find(templates::USER_NOTES_MARKER) {
+            Some(pos) => format!("{}{section}{}", &content[..pos], &content[pos..]),
+            None => format!("{content}\n{section}"),
+        },
+    }
+}
+
+/// Hash of the generated part of a context file: everything above the user
+/// notes except the blocks `update_project_state` refreshes on every start.
+fn generated_hash(content: &str) -> String {
+    let generated = content
+        .find(templates::USER_NOTES_MARKER)
+        .map_or(content, |pos| &content[..pos]);
+    let generated = replace_managed_block(generated, CONTEXT_EXTRA_START, CONTEXT_EXTRA_END, None);
+    let generated = replace_managed_block(&generated, PROJECT_STATE_START, PROJECT_STATE_END, None);
+    Sha256::digest(generated.trim_end().as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Where hand edits to the generated part of a context file are saved
+/// before it is regenerated: `CLAUDE.local.md` (which Claude Code also
+/// reads) for CLAUDE.md, `.roxlit/context-edits.md` for other tools.
+fn local_edits_path(project_root: &Path, context_file: &Path) -> PathBuf {
+    if context_file.file_name().is_some_and(|n| n == "CLAUDE.md") {
+        project_root.join("CLAUDE.local.md")
+    } else {
+        project_root.join(".roxlit").join("context-edits.md")
+    }
+}
+
+/// If the generated part of `context_file` was edited by hand since Roxlit
+/// last wrote it, appends that part to the local edits file so regenerating
+/// doesn't lose it. Returns the file it was saved to.
+pub fn save_user_edits(project_root: &Path, context_file: &Path) -> Result<Option<PathBuf>> {
+    let Ok(content) = fs::read_to_string(context_file) else {
+        return Ok(None);
+    };
+    let recorded = crate::commands::config::read_config()
+        .and_then(|c| c.context_hashes?.remove(context_file.to_string_lossy().as_ref()));
+    // Files generated before hashes were recorded can't be compared
+    if recorded.is_none_or(|hash| hash == generated_hash(&content)) {
+        return Ok(None);
+    }
+
+    let generated = content
+        .find(templates::USER_NOTES_MARKER)
+        .map_or(content.as_str(), |pos| &content[..pos]);
+    let file_name = context_file.file_name().unwrap_or_default().to_string_lossy();
+    let target = local_edits_path(project_root, context_file);
+    let mut saved = fs::read_to_string(&target).unwrap_or_default();
+    if !saved.is_empty() {
+        saved.push_str("\n\n");
+    }
+    saved.push_str(&format!(
+        "<!-- Saved by Roxlit on {}: your edited copy of the generated part of {file_name}, \
+         which was replaced by an update. Move the rules you still need into the \
+         \"{}\" section of {file_name} (kept on updates) and delete the rest. -->\n\n{}\n",
+        crate::commands::backup::now_timestamp(),
+        templates::USER_NOTES_MARKER.trim_start_matches("## "),
+        generated.trim_end(),
+    ));
+    if let Some(dir) = target.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&target, saved)?;
+    Ok(Some(target))
+}
+
+/// Resolves the project's `contextExtra` setting: a markdown file path
+/// (relative to the project root or absolute) if one exists, else inline text.
+fn context_extra(project_root: &Path) -> Option<String> {
+    let project = crate::commands::config::find_project(&project_root.to_string_lossy())?;
+    let extra = project.settings.context_extra?;
+    let extra = extra.trim();
+    if extra.is_empty() {
+        return None;
+    }
+    let path = project_root.join(crate::util::expand_tilde(extra));
+    if path.is_file() {
+        return fs::read_to_string(path).ok();
+    }
+    Some(extra.to_string())
+}
+
+/// Renders the current project state as markdown.
+fn project_state(project_root: &Path) -> String {
+    let mut out = String::from("## Project State (auto-generated)\n\n");
+
+    let project = crate::commands::config::find_project(&project_root.to_string_lossy());
+    match project.as_ref().and_then(|p| p.place_id) {
+        Some(place_id) => {
+            out.push_str(&format!("- **Place ID**: {place_id}\n"));
+            if let Some(universe_id) = project.as_ref().and_then(|p| p.universe_id) {
+                out.push_str(&format!("- **Universe ID**: {universe_id}\n"));
+            }
+        }
+        None => out.push_str("- **Place**: not linked yet (open the place in Studio with the Roxlit plugin)\n"),
+    }
+
+    let deps = wally_dependencies(project_root);
+    if !deps.is_empty() {
+        out.push_str("\n### Wally Dependencies\n\n");
+        for (alias, package) in deps {
+            out.push_str(&format!("- `{alias}` = `{package}`\n"));
+        }
+    }
+
+    let tree = instance_tree(project_root);
+    if !tree.is_empty() {
+        out.push_str("\n### Instance Tree (top level)\n\n```\n");
+        out.push_str(&tree);
+        out.push_str("```\n");
+    }
+    out
+}
+
+/// Reads `(alias, package)` pairs from wally.toml's dependency tables.
+fn wally_dependencies(project_root: &Path) -> Vec<(String, String)> {
+    let Ok(content) = fs::read_to_string(project_root.join("wally.toml")) else {
+        return Vec::new();
+    };
+    let Ok(value) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
+    value
+        .iter()
+        .filter(|(section, _)| section.ends_with("dependencies"))
+        .filter_map(|(_, table)| table.as_table())
+        .flatten()
+        .filter_map(|(alias, package)| Some((alias.clone(), package.as_str()?.to_string())))
+        .collect()
+}
+
+/// Most children listed per service in the instance tree.
+const TREE_CHILD_LIMIT: usize = 15;
+
+/// Lists each service mapped in default.project.json with its immediate
+/// children, as `instances::project_tree` maps them.
+fn instance_tree(project_root: &Path) -> String {
+    let Ok(root) = crate::commands::instances::project_tree(project_root) else {
+        return String::new();
+    };
+
+    let mut out = String::new();
+    for service in &root.children {
+        out.push_str(&format!("{}\n", service.name));
+        let mut children: Vec<&str> = service.children.iter().map(|c| c.name.as_str()).collect();
+        children.sort();
+        children.dedup();
+        for child in children.iter().take(TREE_CHILD_LIMIT) {
+            out.push_str(&format!("  {child}\n"));
+        }
+        if children.len() > TREE_CHILD_LIMIT {
+            out.push_str(&format!("  ... {} more\n", children.len() - TREE_CHILD_LIMIT));
+        }
+    }
+    out
+}
+
 /// Writes curated Roblox documentation packs to `.roxlit/context/`.
-fn write_context_packs(project_root: &Path) -> Result<()> {
+pub fn write_context_packs(project_root: &Path) -> Result<()> {
     let context_dir = project_root.join(".roxlit").join("context");
     fs::create_dir_all(&context_dir)?;
 
-    fs::write(context_dir.join("index.md"), templates::context_packs::index())?;
-    fs::write(context_dir.join("datastore.md"), templates::context_packs::datastore())?;
-    fs::write(context_dir.join("remote-events.md"), templates::context_packs::remote_events())?;
-    fs::write(context_dir.join("player-lifecycle.md"), templates::context_packs::player_lifecycle())?;
-    fs::write(context_dir.join("workspace-physics.md"), templates::context_packs::workspace_physics())?;
-    fs::write(context_dir.join("replication.md"), templates::context_packs::replication())?;
-    fs::write(context_dir.join("services-reference.md"), templates::context_packs::services_reference())?;
-    fs::write(context_dir.join("studio-ui.md"), templates::context_packs::studio_ui())?;
+    for pack in templates::context_packs::PACKS {
+        fs::write(context_dir.join(pack.file), (pack.content)())?;
+    }
+
+    // User packs, and the index generated from everything now on disk
+    sync_custom_packs(project_root)?;
 
     // Version file for mid-session context refresh detection
     fs::write(context_dir.join("version.txt"), "1")?;
@@ -70,11 +353,113 @@ fn write_context_packs(project_root: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Generated files in `.roxlit/context/` besides the built-in packs.
+const GENERATED_PACKS: &[&str] = &["index.md", "lesson.md", "full-guide.md"];
+
+/// Whether a custom pack with this file name would replace a generated one.
+fn is_reserved_pack(name: &str) -> bool {
+    GENERATED_PACKS.contains(&name)
+        || templates::context_packs::PACKS.iter().any(|p| p.file == name)
+}
+
+/// Lists the custom packs copied by the last sync, so removed ones can be cleaned up.
+const CUSTOM_PACKS_MANIFEST: &str = ".custom-packs";
+
+/// Collects user markdown packs from `~/.roxlit/context-packs/` and
+/// `<project>/.roxlit/context-custom/`. Project packs win over global ones
+/// with the same name. Returns `(file name, content)` sorted by name.
+fn custom_packs(project_root: &Path) -> Vec<(String, String)> {
+    let mut dirs = Vec::new();
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".roxlit").join("context-packs"));
+    }
+    dirs.push(project_root.join(".roxlit").join("context-custom"));
+
+    let mut packs = std::collections::BTreeMap::new();
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.ends_with(".md") || is_reserved_pack(&name) {
+                continue;
+            }
+            if let Ok(content) = fs::read_to_string(entry.path()) {
+                packs.insert(name, content);
+            }
+        }
+    }
+    packs.into_iter().collect()
+}
+
+/// One-line summary of a pack: its first heading, or first non-empty line.
+fn pack_summary(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+    line.trim_start_matches('#').trim().chars().take(100).collect()
+}
+
+/// Copies custom packs into `.roxlit/context/`, removes ones whose source is
+/// gone, and regenerates index.md from the packs present. Cheap enough to run on every launch.
+pub fn sync_custom_packs(project_root: &Path) -> Result<()> {
+    let context_dir = project_root.join(".roxlit").join("context");
+    fs::create_dir_all(&context_dir)?;
+
+    let packs = custom_packs(project_root);
+    let manifest_path = context_dir.join(CUSTOM_PACKS_MANIFEST);
+    let previous = fs::read_to_string(&manifest_path).unwrap_or_default();
+    for name in previous.lines() {
+        if !packs.iter().any(|(n, _)| n == name) && !is_reserved_pack(name) {
+            let _ = fs::remove_file(context_dir.join(name));
+        }
+    }
+
+    for (name, content) in &packs {
+        fs::write(context_dir.join(name), content)?;
+    }
+    let names: Vec<&str> = packs.iter().map(|(n, _)| n.as_str()).collect();
+    fs::write(&manifest_path, names.join("\n"))?;
+
+    let mut entries: Vec<IndexEntry> = templates::context_packs::PACKS
+        .iter()
+        .filter(|p| context_dir.join(p.file).exists())
+        .map(|p| IndexEntry {
+            file: p.file.into(),
+            summary: p.summary.into(),
+            version: p.version.into(),
+        })
+        .collect();
+    if context_dir.join("full-guide.md").exists() {
+        entries.push(IndexEntry {
+            file: "full-guide.md".into(),
+            summary: "Complete Roxlit guide (the AI context file is the compact version)".into(),
+            version: templates::CONTEXT_VERSION.into(),
+        });
+    }
+    if context_dir.join("lesson.md").exists() {
+        entries.push(IndexEntry {
+            file: "lesson.md".into(),
+            summary: "Classroom lesson: tutoring rules for EXERCISES.md".into(),
+            version: "lesson".into(),
+        });
+    }
+    for (name, content) in &packs {
+        entries.push(IndexEntry {
+            file: name.clone(),
+            summary: pack_summary(content),
+            version: "custom".into(),
+        });
+    }
+    fs::write(context_dir.join("index.md"), templates::context_packs::index(&entries))?;
+
+    Ok(())
+}
+
 /// Writes MCP server configuration for the selected AI tool.
 pub fn configure_mcp(project_root: &Path, ai_tool: &str) -> Result<()> {
-    let mcp_bin_name = if cfg!(target_os = "windows") { "roxlit-mcp.exe" } else { "roxlit-mcp" };
-    let mcp_binary = dirs::home_dir()
-        .map(|h| h.join(".roxlit").join("bin").join(mcp_bin_name))
+    let mcp_binary = crate::commands::config::mcp_binary_path()
         .ok_or_else(|| crate::error::InstallerError::Custom("Cannot find home directory".into()))?;
 
     // Use forward slashes — Windows accepts them in paths, and backslashes
@@ -175,6 +560,18 @@ pub fn configure_mcp(project_root: &Path, ai_tool: &str) -> Result<()> {
     Ok(())
 }
 
+/// Returns where `configure_mcp` writes the MCP config for the given AI tool.
+pub fn mcp_config_path(project_root: &Path, ai_tool: &str) -> Option<std::path::PathBuf> {
+    match ai_tool {
+        "claude" => Some(project_root.join(".mcp.json")),
+        "cursor" => Some(project_root.join(".cursor").join("mcp.json")),
+        "vscode" => Some(project_root.join(".vscode").join("mcp.json")),
+        "windsurf" => dirs::home_dir()
+            .map(|h| h.join(".codeium").join("windsurf").join("mcp_config.json")),
+        _ => Some(project_root.join(".mcp.json")),
+    }
+}
+
 /// Returns a human-readable name for the AI tool ID.
 pub fn tool_display_name(ai_tool: &str) -> &str {
     match ai_tool {
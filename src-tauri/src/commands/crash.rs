@@ -0,0 +1,122 @@
+//! Crash reports. A panic hook writes the panic message, a backtrace and the
+//! most recent log lines to `~/.roxlit/crashes/`, for panics on the main
+//! thread as well as in spawned tasks (which tokio otherwise swallows).
+//! Pending reports are offered for the next session log export.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::commands::logs::{format_timestamp, unix_millis, LogBuffer};
+
+/// Log lines included in a crash report.
+const CRASH_LOG_LINES: usize = 200;
+
+/// `~/.roxlit/crashes/`: reports not yet included in an export.
+pub fn crashes_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("crashes"))
+}
+
+/// Installs the panic hook. The default hook still runs afterwards, so the
+/// panic is printed to stderr as before.
+pub fn install_panic_hook(buffer: Arc<Mutex<LogBuffer>>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "(non-string panic payload)".into());
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+        let thread = std::thread::current().name().unwrap_or("unnamed").to_string();
+        // try_lock: the panic may have happened while the buffer was locked
+        let log_tail = buffer
+            .try_lock()
+            .map(|b| b.tail(CRASH_LOG_LINES))
+            .unwrap_or_default();
+
+        let report = format!(
+            "Roxlit {} crashed at {}\nOS: {} {}\nThread: {thread}\nPanic: {message}\nLocation: {location}\n\n\
+             Backtrace:\n{}\n\nLast {} log lines:\n{}\n",
+            env!("CARGO_PKG_VERSION"),
+            format_timestamp(unix_millis() / 1000),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            std::backtrace::Backtrace::force_capture(),
+            log_tail.len(),
+            log_tail.join("\n"),
+        );
+        if let Some(dir) = crashes_dir() {
+            let _ = std::fs::create_dir_all(&dir);
+            let _ = std::fs::write(dir.join(format!("crash-{}.txt", unix_millis())), report);
+        }
+
+        default_hook(info);
+    }));
+}
+
+/// A crash report waiting to be shared.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReport {
+    pub path: String,
+    /// First line of the report ("Roxlit x.y.z crashed at ...").
+    pub title: String,
+    /// The panic message.
+    pub panic: String,
+}
+
+/// Crash report files not yet included in an export, oldest first.
+pub fn pending_report_files() -> Vec<PathBuf> {
+    let Some(entries) = crashes_dir().and_then(|d| std::fs::read_dir(d).ok()) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Moves reports to `crashes/exported/` once they are part of an export, so
+/// they aren't offered again.
+pub fn mark_exported(files: &[PathBuf]) {
+    let Some(dir) = crashes_dir().map(|d| d.join("exported")) else {
+        return;
+    };
+    let _ = std::fs::create_dir_all(&dir);
+    for file in files {
+        if let Some(name) = file.file_name() {
+            let _ = std::fs::rename(file, dir.join(name));
+        }
+    }
+}
+
+fn read_report(path: &Path) -> Option<CrashReport> {
+    let content = std::fs::read_to_string(path).ok()?;
+    Some(CrashReport {
+        path: path.to_string_lossy().to_string(),
+        title: content.lines().next().unwrap_or_default().to_string(),
+        panic: content
+            .lines()
+            .find_map(|l| l.strip_prefix("Panic: "))
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+/// Crash reports from previous runs that haven't been exported yet, so the UI
+/// can offer to include them in the next session log export.
+#[tauri::command]
+pub fn list_crash_reports() -> Vec<CrashReport> {
+    pending_report_files()
+        .iter()
+        .filter_map(|p| read_report(p))
+        .collect()
+}
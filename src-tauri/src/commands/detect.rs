@@ -15,6 +15,12 @@ pub struct DetectionResult {
     pub aftman_version: Option<String>,
     pub roxlit_mcp_installed: bool,
     pub roxlit_mcp_version: Option<String>,
+    /// The Rojo/Aftman found is the one Roxlit installed (its recorded version
+    /// matches), rather than the user's own copy.
+    pub rojo_managed: bool,
+    pub aftman_managed: bool,
+    /// Versions Roxlit recorded when it installed each tool.
+    pub installed_tools: std::collections::BTreeMap<String, String>,
 }
 
 /// Scans the system for Roblox Studio, Rojo, and Aftman.
@@ -27,6 +33,18 @@ pub async fn detect_environment() -> crate::error::Result<DetectionResult> {
     let (aftman_installed, aftman_version) = detect_cli_tool("aftman").await;
     let (roxlit_mcp_installed, roxlit_mcp_version) = detect_roxlit_mcp(&os).await;
 
+    let installed_tools = crate::commands::config::load_config()
+        .await
+        .and_then(|c| c.installed_tools)
+        .unwrap_or_default();
+    let managed = |tool: &str, detected: &Option<String>| match (installed_tools.get(tool), detected) {
+        // `rojo --version` prints "Rojo 7.4.4"
+        (Some(recorded), Some(detected)) => detected.split_whitespace().any(|w| w.trim_start_matches('v') == recorded),
+        _ => false,
+    };
+    let rojo_managed = managed("rojo", &rojo_version);
+    let aftman_managed = managed("aftman", &aftman_version);
+
     Ok(DetectionResult {
         os,
         studio_installed,
@@ -37,6 +55,9 @@ pub async fn detect_environment() -> crate::error::Result<DetectionResult> {
         aftman_version,
         roxlit_mcp_installed,
         roxlit_mcp_version,
+        rojo_managed,
+        aftman_managed,
+        installed_tools,
     })
 }
 
@@ -79,9 +100,8 @@ fn detect_studio(os: &str) -> (bool, Option<PathBuf>) {
 
 /// Runs `<tool> --version` and parses the output to check availability.
 async fn detect_cli_tool(name: &str) -> (bool, Option<String>) {
-    // Also check the aftman bin directory directly
-    let bin_path = dirs::home_dir()
-        .map(|h| h.join(".aftman").join("bin").join(name));
+    // Also check the aftman bin directory directly (honors the toolDir setting)
+    let bin_path = crate::commands::config::aftman_bin_dir().map(|d| d.join(name));
 
     let mut cmd = Command::new(name);
     cmd.arg("--version");
@@ -0,0 +1,148 @@
+//! Free disk space checks, so backups, downloads and place builds stop with a
+//! clear "disk almost full" message instead of failing halfway through with
+//! an IO error (or filling the disk for everything else).
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Large writes are skipped when they would leave less than this free.
+pub(crate) const MIN_FREE_BYTES: u64 = 500 * 1024 * 1024;
+
+/// Free space on one of the disks Roxlit writes to.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsage {
+    /// What lives there ("Project", "Roxlit tools", "Temp").
+    pub label: String,
+    pub path: String,
+    /// None when the free space couldn't be determined.
+    pub free_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    /// Under `MIN_FREE_BYTES`: backups and downloads are being skipped.
+    pub low: bool,
+}
+
+/// Free and total bytes on the volume holding `path` (or its nearest
+/// existing ancestor, for paths about to be created).
+pub(crate) async fn free_space(path: &Path) -> Option<(u64, u64)> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+
+    #[cfg(target_os = "windows")]
+    {
+        let drive = existing.to_string_lossy().chars().next().filter(|c| c.is_ascii_alphabetic())?;
+        let script = format!("$d = [System.IO.DriveInfo]::new('{drive}'); \"$($d.AvailableFreeSpace) $($d.TotalSize)\"");
+        let output = tokio::process::Command::new("powershell")
+            .args(["-NoProfile", "-Command", &script])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .await
+            .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut numbers = stdout.split_whitespace().map(|n| n.parse::<u64>().ok());
+        Some((numbers.next()??, numbers.next()??))
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let output = tokio::process::Command::new("df")
+            .arg("-Pk")
+            .arg(existing)
+            .output()
+            .await
+            .ok()?;
+        parse_df(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Available and total bytes from `df -Pk` output. Located relative to the
+/// capacity column, since the filesystem name and mount point may contain
+/// spaces.
+#[cfg(any(not(target_os = "windows"), test))]
+fn parse_df(output: &str) -> Option<(u64, u64)> {
+    let fields: Vec<&str> = output.lines().nth(1)?.split_whitespace().collect();
+    let capacity = fields.iter().position(|f| f.ends_with('%'))?;
+    let available = fields.get(capacity.checked_sub(1)?)?.parse::<u64>().ok()?;
+    let total = fields.get(capacity.checked_sub(3)?)?.parse::<u64>().ok()?;
+    Some((available * 1024, total * 1024))
+}
+
+/// "1.2 GB"-style size for messages.
+fn format_size(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    let mb = bytes as f64 / MB;
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{mb:.0} MB")
+    }
+}
+
+/// If writing `needed` bytes under `path` would leave less than
+/// `MIN_FREE_BYTES`, the free bytes and a message saying what was stopped.
+/// Unknown free space never blocks anything.
+pub(crate) async fn low_space(path: &Path, needed: u64, what: &str) -> Option<(u64, String)> {
+    let (free, _) = free_space(path).await?;
+    if free >= needed.saturating_add(MIN_FREE_BYTES) {
+        return None;
+    }
+    let message = format!(
+        "Only {} free on the disk holding {}, so {what} was skipped. Free up at least {} and try again.",
+        format_size(free),
+        path.display(),
+        format_size(needed.saturating_add(MIN_FREE_BYTES) - free),
+    );
+    Some((free, message))
+}
+
+/// `low_space` as an error, for commands that should fail instead.
+pub(crate) async fn ensure_space(path: &Path, needed: u64, what: &str) -> Result<()> {
+    match low_space(path, needed, what).await {
+        Some((_, message)) => Err(InstallerError::Custom(message)),
+        None => Ok(()),
+    }
+}
+
+/// Free space on the project's disk, the tools directory and the temp
+/// directory, for troubleshooting.
+#[tauri::command]
+pub async fn get_disk_usage(project_path: Option<String>) -> Vec<DiskUsage> {
+    let mut locations: Vec<(&str, PathBuf)> = Vec::new();
+    if let Some(path) = project_path {
+        locations.push(("Project", PathBuf::from(expand_tilde(&path))));
+    }
+    if let Some(tools) = crate::commands::config::tools_home() {
+        locations.push(("Roxlit tools", tools));
+    }
+    locations.push(("Temp", std::env::temp_dir()));
+
+    let mut usage = Vec::new();
+    for (label, path) in locations {
+        let space = free_space(&path).await;
+        usage.push(DiskUsage {
+            label: label.into(),
+            path: path.to_string_lossy().to_string(),
+            free_bytes: space.map(|(free, _)| free),
+            total_bytes: space.map(|(_, total)| total),
+            low: space.is_some_and(|(free, _)| free < MIN_FREE_BYTES),
+        });
+    }
+    usage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_df() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/sda1         1000000    400000    600000      40% /\n";
+        assert_eq!(parse_df(output), Some((600000 * 1024, 1000000 * 1024)));
+        let spaced = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                      My Volume 2000 500 1500 25% /Volumes/My Volume\n";
+        assert_eq!(parse_df(spaced), Some((1500 * 1024, 2000 * 1024)));
+        assert_eq!(parse_df("garbage"), None);
+    }
+}
@@ -0,0 +1,352 @@
+//! Every file Roxlit downloads goes through here: GitHub release mirrors,
+//! retries, streaming to a `.part` file, SHA-256 verification and byte-level
+//! progress, so all install steps report progress the same way. A file is
+//! only fetched from a mirror when its checksum is known.
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+use crate::commands::config;
+use crate::commands::update::{github_api_get, GithubResponse};
+use crate::error::{InstallerError, Result};
+
+/// Alternate hosts for GitHub release assets, tried in order when github.com is
+/// unreachable (e.g. blocked in the user's region). The asset path after
+/// `https://github.com/` is appended to each base. Only hosts Roxlit runs:
+/// a third-party proxy could serve anything in place of the binaries.
+const RELEASE_MIRRORS: &[&str] = &["https://dl.roxlit.dev/github/"];
+
+/// Tries per URL before moving on to the next mirror.
+const ATTEMPTS_PER_SOURCE: u32 = 2;
+/// Wait before retrying the same URL.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// The SHA-256 a download is checked against.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Checksum<'a> {
+    /// Known in advance; the download fails unless it matches.
+    Sha256(&'a str),
+    /// The `digest` GitHub lists for the release asset. When GitHub has none
+    /// (or can't be reached) the file is only taken from github.com itself.
+    Release,
+}
+
+/// Bytes received so far for one download.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Progress {
+    pub downloaded: u64,
+    /// From Content-Length; None when the server didn't send one.
+    pub total: Option<u64>,
+}
+
+/// Returns every URL a release asset can be fetched from: the primary URL first,
+/// followed by its mirrors. Non-GitHub URLs have no mirrors.
+pub(crate) fn asset_urls(url: &str) -> Vec<String> {
+    let mut urls = vec![url.to_string()];
+    if let Some(path) = url.strip_prefix("https://github.com/") {
+        urls.extend(RELEASE_MIRRORS.iter().map(|base| format!("{base}{path}")));
+    }
+    urls
+}
+
+/// GETs one URL, retrying connection failures and server errors.
+async fn get_once(
+    client: &reqwest::Client,
+    url: &str,
+    etag: Option<&str>,
+) -> std::result::Result<reqwest::Response, String> {
+    let mut last_err = String::new();
+    for attempt in 1..=ATTEMPTS_PER_SOURCE {
+        let mut request = client.get(url);
+        if let Some(etag) = etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        match request.send().await {
+            Ok(response)
+                if response.status().is_success() || response.status() == reqwest::StatusCode::NOT_MODIFIED =>
+            {
+                return Ok(response)
+            }
+            Ok(response) => {
+                last_err = format!("HTTP {} from {url}", response.status());
+                // Only server errors are worth retrying on the same host
+                if !response.status().is_server_error() {
+                    break;
+                }
+            }
+            Err(e) => last_err = format!("{e} ({url})"),
+        }
+        if attempt < ATTEMPTS_PER_SOURCE {
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+    }
+    Err(last_err)
+}
+
+/// Repository (`owner/repo`), tag (None for `latest`) and file name of a
+/// GitHub release asset URL.
+fn release_asset(url: &str) -> Option<(&str, Option<&str>, &str)> {
+    let (repo, rest) = url.strip_prefix("https://github.com/")?.split_once("/releases/")?;
+    if let Some(name) = rest.strip_prefix("latest/download/") {
+        return Some((repo, None, name));
+    }
+    let (tag, name) = rest.strip_prefix("download/")?.split_once('/')?;
+    Some((repo, Some(tag), name))
+}
+
+/// SHA-256 (hex) GitHub lists for the release asset at `url`.
+async fn release_digest(client: &reqwest::Client, url: &str) -> Option<String> {
+    let (repo, tag, name) = release_asset(url)?;
+    let api = match tag {
+        Some(tag) => format!("https://api.github.com/repos/{repo}/releases/tags/{tag}"),
+        None => format!("https://api.github.com/repos/{repo}/releases/latest"),
+    };
+    let GithubResponse::Ok(response) = github_api_get(client, &api).await.ok()? else {
+        return None;
+    };
+    let release: serde_json::Value = response.error_for_status().ok()?.json().await.ok()?;
+    let asset = release["assets"].as_array()?.iter().find(|a| a["name"].as_str() == Some(name))?;
+    asset["digest"].as_str()?.strip_prefix("sha256:").map(String::from)
+}
+
+/// Returns the mirror host name if `source` is not the primary `url`.
+pub(crate) fn mirror_host(url: &str, source: &str) -> Option<String> {
+    if url == source {
+        return None;
+    }
+    let rest = source.split("://").nth(1).unwrap_or(source);
+    Some(rest.split('/').next().unwrap_or(rest).to_string())
+}
+
+/// Downloads `url` (or one of its mirrors) to `target`, reporting progress as
+/// bytes arrive. The file is written as `<target>.part` and only renamed into
+/// place once complete and verified, so an interrupted or corrupted download
+/// never replaces a working file. A download that breaks off or fails
+/// verification is retried from the next mirror.
+/// Returns the URL the file was downloaded from.
+pub(crate) async fn download(
+    url: &str,
+    target: &Path,
+    checksum: Checksum<'_>,
+    on_progress: impl Fn(Progress) + Sync,
+) -> Result<String> {
+    fetch(url, target, None, checksum, &on_progress)
+        .await
+        .map(|fetched| fetched.map(|(source, _)| source).unwrap_or_else(|| url.to_string()))
+}
+
+/// Downloads a binary from a URL (or one of its mirrors) to the target path.
+/// An existing copy is revalidated by ETag and kept when the asset hasn't
+/// changed, so unchanged binaries aren't downloaded again. A new copy is
+/// checked against the release's listed checksum (see `Checksum::Release`).
+/// Returns the URL the file was actually downloaded from (`url` when kept).
+pub(crate) async fn download_binary(url: &str, target_path: &Path, on_progress: impl Fn(Progress) + Sync) -> Result<String> {
+    let etag = cached_etag(url, target_path);
+    let Some((source, new_etag)) = fetch(url, target_path, etag.as_deref(), Checksum::Release, &on_progress).await? else {
+        return Ok(url.to_string());
+    };
+    let size = std::fs::metadata(target_path).map(|m| m.len()).unwrap_or(0);
+    save_asset_record(
+        target_path,
+        new_etag.map(|etag| AssetRecord {
+            url: url.to_string(),
+            etag,
+            size,
+        }),
+    );
+
+    // Make executable on Unix
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tokio::fs::set_permissions(target_path, std::fs::Permissions::from_mode(0o755)).await?;
+    }
+
+    Ok(source)
+}
+
+/// Shared by `download` and `download_binary`. None when the server answered
+/// `304 Not Modified` to `etag`; otherwise the source URL and new ETag.
+async fn fetch(
+    url: &str,
+    target: &Path,
+    etag: Option<&str>,
+    checksum: Checksum<'_>,
+    on_progress: &(dyn Fn(Progress) + Sync),
+) -> Result<Option<(String, Option<String>)>> {
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let partial = part_path(target);
+
+    let client = reqwest::Client::new();
+    let expected = match checksum {
+        Checksum::Sha256(sha256) => Some(sha256.to_string()),
+        Checksum::Release => release_digest(&client, url).await,
+    };
+    // Nothing to check a mirror's copy against: only github.com itself is trusted
+    let sources = if expected.is_some() { asset_urls(url) } else { vec![url.to_string()] };
+    let mut last_err = String::new();
+    for source in sources {
+        // A truncated or corrupted copy from one host falls through to the next
+        let response = match get_once(&client, &source, etag).await {
+            Ok(response) => response,
+            Err(e) => {
+                last_err = e;
+                continue;
+            }
+        };
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        let new_etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        match stream_to_file(response, &partial, on_progress).await {
+            Ok(digest) if expected.as_ref().is_none_or(|expected| expected.eq_ignore_ascii_case(&digest)) => {
+                tokio::fs::rename(&partial, target).await?;
+                return Ok(Some((source, new_etag)));
+            }
+            Ok(digest) => last_err = format!("checksum mismatch from {source} (got {digest})"),
+            Err(e) => last_err = format!("{e} ({source})"),
+        }
+        let _ = tokio::fs::remove_file(&partial).await;
+    }
+    Err(InstallerError::Custom(format!("Failed to download {url}: {last_err}")))
+}
+
+/// Writes the response body to `path`, returning its SHA-256 as hex.
+async fn stream_to_file(response: reqwest::Response, path: &Path, on_progress: &(dyn Fn(Progress) + Sync)) -> Result<String> {
+    let mut progress = Progress {
+        downloaded: 0,
+        total: response.content_length(),
+    };
+    on_progress(progress);
+
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut hasher = Sha256::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk).await?;
+        hasher.update(&chunk);
+        progress.downloaded += chunk.len() as u64;
+        on_progress(progress);
+    }
+    file.flush().await?;
+
+    if progress.total.is_some_and(|total| total != progress.downloaded) {
+        return Err(InstallerError::Custom("the download was cut off".into()));
+    }
+    Ok(hasher.finalize().iter().map(|b| format!("{b:02x}")).collect())
+}
+
+/// SHA-256 of a file on disk, as hex.
+pub(crate) fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize().iter().map(|b| format!("{b:02x}")).collect())
+}
+
+/// `<target>.part`, where a download is written until it's complete.
+fn part_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    target.with_file_name(name)
+}
+
+/// A downloaded release asset in `~/.roxlit/assets.json`, keyed by target path.
+#[derive(Serialize, Deserialize)]
+struct AssetRecord {
+    url: String,
+    etag: String,
+    size: u64,
+}
+
+fn asset_manifest_path() -> Option<PathBuf> {
+    config::tools_home().map(|h| h.join(".roxlit").join("assets.json"))
+}
+
+fn load_asset_manifest() -> HashMap<String, AssetRecord> {
+    asset_manifest_path()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Records (or forgets, with `None`) the asset downloaded to `target_path`.
+fn save_asset_record(target_path: &Path, record: Option<AssetRecord>) {
+    let Some(path) = asset_manifest_path() else {
+        return;
+    };
+    let mut manifest = load_asset_manifest();
+    let key = target_path.to_string_lossy().to_string();
+    match record {
+        Some(record) => manifest.insert(key, record),
+        None => manifest.remove(&key),
+    };
+    if let Ok(json) = serde_json::to_string_pretty(&manifest) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
+/// ETag to revalidate `target_path` with: only when it was downloaded from
+/// the same URL and still has the recorded size (not replaced or truncated).
+fn cached_etag(url: &str, target_path: &Path) -> Option<String> {
+    let record = load_asset_manifest().remove(target_path.to_string_lossy().as_ref())?;
+    let size = std::fs::metadata(target_path).ok()?.len();
+    (record.url == url && record.size == size).then_some(record.etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_urls_include_mirrors() {
+        let url = "https://github.com/Roxlit/installer/releases/latest/download/Roxlit.rbxm";
+        let urls = asset_urls(url);
+        assert_eq!(urls[0], url);
+        assert_eq!(urls.len(), 1 + RELEASE_MIRRORS.len());
+        assert!(urls[1].ends_with("Roxlit/installer/releases/latest/download/Roxlit.rbxm"));
+
+        assert_eq!(asset_urls("https://example.com/file.zip").len(), 1);
+    }
+
+    #[test]
+    fn test_mirror_host() {
+        let url = "https://github.com/a/b/releases/download/v1/x.zip";
+        assert_eq!(mirror_host(url, url), None);
+        assert_eq!(
+            mirror_host(url, "https://dl.roxlit.dev/github/a/b/releases/download/v1/x.zip"),
+            Some("dl.roxlit.dev".to_string())
+        );
+    }
+
+    #[test]
+    fn test_release_asset() {
+        assert_eq!(
+            release_asset("https://github.com/rojo-rbx/rojo/releases/download/v7.4.4/Rojo.rbxm"),
+            Some(("rojo-rbx/rojo", Some("v7.4.4"), "Rojo.rbxm"))
+        );
+        assert_eq!(
+            release_asset("https://github.com/Roxlit/installer/releases/latest/download/roxlit-mcp.exe"),
+            Some(("Roxlit/installer", None, "roxlit-mcp.exe"))
+        );
+        assert_eq!(release_asset("https://example.com/file.zip"), None);
+    }
+
+    #[test]
+    fn test_part_path() {
+        assert_eq!(part_path(Path::new("/tmp/roxlit-mcp.exe")), PathBuf::from("/tmp/roxlit-mcp.exe.part"));
+    }
+}
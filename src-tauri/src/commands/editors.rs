@@ -0,0 +1,143 @@
+//! Finding the code editors installed on this machine (VS Code, Cursor,
+//! Windsurf), so `open_in_editor` launches one that exists instead of
+//! assuming `code` is on PATH. Editors are detected once at startup and the
+//! user's pick is kept in the `defaultEditor` setting.
+
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// An editor found on this machine.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorInfo {
+    /// Same ids as `aiTool`: `vscode`, `cursor`, `windsurf`.
+    pub id: String,
+    pub name: String,
+    /// Executable that opens a folder or workspace passed as its argument.
+    pub command: String,
+}
+
+/// Why an editor couldn't be opened, with the editors that could be used
+/// instead (empty when none is installed).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorError {
+    pub message: String,
+    pub available: Vec<EditorInfo>,
+}
+
+impl EditorError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into(), available: detected() }
+    }
+}
+
+/// Known editors: id, name and the command each puts on PATH.
+const EDITORS: &[(&str, &str, &str)] = &[
+    ("vscode", "VS Code", "code"),
+    ("cursor", "Cursor", "cursor"),
+    ("windsurf", "Windsurf", "windsurf"),
+];
+
+/// Editors found at startup (see `refresh`).
+static DETECTED: RwLock<Option<Vec<EditorInfo>>> = RwLock::new(None);
+
+/// Install locations outside PATH, per editor id.
+fn known_locations(id: &str) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    #[cfg(target_os = "windows")]
+    if let Some(local) = dirs::data_local_dir() {
+        let programs = local.join("Programs");
+        paths.push(match id {
+            "vscode" => programs.join("Microsoft VS Code").join("bin").join("code.cmd"),
+            "cursor" => programs.join("cursor").join("resources").join("app").join("bin").join("cursor.cmd"),
+            _ => programs.join("Windsurf").join("bin").join("windsurf.cmd"),
+        });
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let (app, bin) = match id {
+            "vscode" => ("Visual Studio Code.app", "code"),
+            "cursor" => ("Cursor.app", "cursor"),
+            _ => ("Windsurf.app", "windsurf"),
+        };
+        let launcher = std::path::Path::new("Contents").join("Resources").join("app").join("bin").join(bin);
+        paths.push(std::path::Path::new("/Applications").join(app).join(&launcher));
+        if let Some(home) = dirs::home_dir() {
+            paths.push(home.join("Applications").join(app).join(&launcher));
+        }
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        paths.push(PathBuf::from("/snap/bin").join(id));
+    }
+    paths
+}
+
+/// Full path of `command` if it's on PATH.
+fn find_on_path(command: &str) -> Option<PathBuf> {
+    let extensions: &[&str] = if cfg!(target_os = "windows") { &[".cmd", ".exe", ""] } else { &[""] };
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .flat_map(|dir| extensions.iter().map(move |ext| dir.join(format!("{command}{ext}"))))
+        .find(|path| path.is_file())
+}
+
+fn detect() -> Vec<EditorInfo> {
+    EDITORS
+        .iter()
+        .filter_map(|(id, name, command)| {
+            let path = find_on_path(command).or_else(|| known_locations(id).into_iter().find(|p| p.is_file()))?;
+            Some(EditorInfo {
+                id: id.to_string(),
+                name: name.to_string(),
+                command: path.to_string_lossy().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Detects the installed editors again. Run at startup, off the main thread.
+pub fn refresh() -> Vec<EditorInfo> {
+    let editors = detect();
+    if let Ok(mut guard) = DETECTED.write() {
+        *guard = Some(editors.clone());
+    }
+    editors
+}
+
+/// The installed editors, detecting them if startup hasn't yet.
+pub fn detected() -> Vec<EditorInfo> {
+    match DETECTED.read().ok().and_then(|guard| guard.clone()) {
+        Some(editors) => editors,
+        None => refresh(),
+    }
+}
+
+/// The editor to open for a project whose AI tool is `ai_tool`: that tool's
+/// own editor if installed, else the `defaultEditor` setting's.
+pub fn resolve(ai_tool: &str) -> std::result::Result<EditorInfo, EditorError> {
+    let available = detected();
+    let preferred = crate::commands::config::read_config().and_then(|c| c.default_editor);
+    let found = [Some(ai_tool.to_string()), preferred]
+        .into_iter()
+        .flatten()
+        .find_map(|id| available.iter().find(|e| e.id == id).cloned());
+    found.ok_or_else(|| {
+        let message = if available.is_empty() {
+            "No code editor found. Install VS Code, Cursor or Windsurf.".to_string()
+        } else {
+            format!(
+                "{} isn't installed. Choose a default editor to open projects with.",
+                crate::commands::context::tool_display_name(ai_tool)
+            )
+        };
+        EditorError { message, available }
+    })
+}
+
+/// Editors installed on this machine.
+#[tauri::command]
+pub async fn list_editors() -> Vec<EditorInfo> {
+    tokio::task::spawn_blocking(refresh).await.unwrap_or_default()
+}
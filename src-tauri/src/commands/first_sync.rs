@@ -0,0 +1,232 @@
+//! Review of an adopted project's first sync. Rojo makes Studio match the
+//! files: instances only in the project are created, and Studio instances
+//! under folders Rojo owns are deleted when the files don't have them. On a
+//! project that wasn't built with Rojo in mind that can wipe real work, so
+//! start_rojo holds Rojo back until the user has seen this comparison.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tauri::State;
+
+use crate::commands::config;
+use crate::commands::instances::{self, InstanceNode};
+use crate::commands::logs::LauncherStatus;
+use crate::commands::studio;
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Paths listed per category; the counts cover everything.
+const PREVIEW_LIMIT: usize = 200;
+
+/// What the first sync would change in the open place.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPreview {
+    /// Topmost instances Rojo would create, e.g. "ServerScriptService/Combat".
+    pub added: Vec<String>,
+    /// Instances created, including descendants of `added`.
+    pub added_count: usize,
+    /// Studio instances Rojo would delete because the project doesn't have them.
+    pub removed: Vec<String>,
+    pub removed_count: usize,
+}
+
+impl SyncPreview {
+    fn add(&mut self, node: &InstanceNode) {
+        if self.added.len() < PREVIEW_LIMIT {
+            self.added.push(node.path.clone());
+        }
+        self.added_count += count(node);
+    }
+
+    fn remove(&mut self, path: String) {
+        if self.removed.len() < PREVIEW_LIMIT {
+            self.removed.push(path);
+        }
+        self.removed_count += 1;
+    }
+}
+
+fn count(node: &InstanceNode) -> usize {
+    1 + node.children.iter().map(count).sum::<usize>()
+}
+
+/// Whether Rojo deletes unknown Studio children of each project.json node,
+/// by path. Rojo's default: yes under `$path`, no elsewhere, unless
+/// `$ignoreUnknownInstances` says otherwise. Instances from files aren't
+/// listed; they always delete unknown children.
+fn removes_unknown(node: &serde_json::Value, path: &str, out: &mut BTreeMap<String, bool>) {
+    let removes = node["$ignoreUnknownInstances"]
+        .as_bool()
+        .map_or(node.get("$path").is_some(), |ignore| !ignore);
+    out.insert(path.to_string(), removes);
+    let Some(obj) = node.as_object() else { return };
+    for (name, child) in obj.iter().filter(|(k, _)| !k.starts_with('$')) {
+        let child_path = if path.is_empty() { name.clone() } else { format!("{path}/{name}") };
+        removes_unknown(child, &child_path, out);
+    }
+}
+
+/// Compares the project's instance under `node` with Studio's children of
+/// the same instances (`studio`: path → child names, for paths that exist).
+fn diff(
+    node: &InstanceNode,
+    rules: &BTreeMap<String, bool>,
+    studio: &HashMap<String, Vec<String>>,
+    preview: &mut SyncPreview,
+) {
+    let Some(studio_children) = studio.get(&node.path) else { return };
+    for child in &node.children {
+        if studio_children.contains(&child.name) {
+            diff(child, rules, studio, preview);
+        } else {
+            preview.add(child);
+        }
+    }
+    if rules.get(&node.path).copied().unwrap_or(true) {
+        for name in studio_children {
+            if !node.children.iter().any(|c| &c.name == name) {
+                let path = if node.path.is_empty() { name.clone() } else { format!("{}/{name}", node.path) };
+                preview.remove(path);
+            }
+        }
+    }
+}
+
+fn collect_paths(node: &InstanceNode, out: &mut Vec<String>) {
+    out.push(node.path.clone());
+    for child in &node.children {
+        collect_paths(child, out);
+    }
+}
+
+/// Child names of each of `paths` in the open place, for those that exist.
+async fn studio_children(paths: &[String]) -> Result<HashMap<String, Vec<String>>> {
+    let paths = serde_json::to_string(paths).map_err(|e| InstallerError::Custom(e.to_string()))?;
+    let code = format!(
+        r#"local HttpService = game:GetService("HttpService")
+local result = {{}}
+for _, path in HttpService:JSONDecode({paths:?}) do
+	local instance = game
+	for name in string.gmatch(path, "[^/]+") do
+		instance = instance and instance:FindFirstChild(name)
+	end
+	if instance then
+		local names = {{}}
+		local ok, children = pcall(instance.GetChildren, instance)
+		for _, child in (ok and children or {{}}) do
+			table.insert(names, child.Name)
+		end
+		result[path] = names
+	end
+end
+return HttpService:JSONEncode(result)"#
+    );
+    let result = studio::run_in_studio(&code).await?;
+    let value: serde_json::Value = serde_json::from_str(&result)
+        .map_err(|e| InstallerError::Custom(format!("Unexpected answer from Studio: {e}")))?;
+    // JSONEncode turns an empty table into `[]`, so no paths found comes back as an array
+    let Some(found) = value.as_object() else {
+        return Ok(HashMap::new());
+    };
+    Ok(found
+        .iter()
+        .map(|(path, names)| {
+            let names = names
+                .as_array()
+                .map(|list| list.iter().filter_map(|n| n.as_str().map(String::from)).collect())
+                .unwrap_or_default();
+            (path.clone(), names)
+        })
+        .collect())
+}
+
+/// Compares the project with the place open in Studio and returns what the
+/// first `rojo serve` sync would add and delete. Needs the Studio plugin
+/// connected to this project's session (started by start_rojo, which holds
+/// Rojo itself back while the review is pending).
+#[tauri::command]
+pub async fn preview_first_sync(project_path: String, status: State<'_, LauncherStatus>) -> Result<SyncPreview> {
+    let project_path = expand_tilde(&project_path);
+    if !instances::plugin_connected(&status, &project_path).await {
+        return Err(InstallerError::Custom(
+            "Open the project's place in Studio (with the Roxlit plugin) to compare it with the project".into(),
+        ));
+    }
+    let root = Path::new(&project_path);
+    let tree = instances::project_tree(root)?;
+    let project_json: serde_json::Value = std::fs::read_to_string(root.join("default.project.json"))
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default();
+    let mut rules = BTreeMap::new();
+    removes_unknown(&project_json["tree"], "", &mut rules);
+
+    let mut paths = Vec::new();
+    collect_paths(&tree, &mut paths);
+    let studio = studio_children(&paths).await?;
+
+    let mut preview = SyncPreview::default();
+    diff(&tree, &rules, &studio, &mut preview);
+    Ok(preview)
+}
+
+/// Lets Rojo sync the project from now on. The session has to be restarted
+/// (stop_rojo, then start_rojo) for Rojo to start.
+#[tauri::command]
+pub async fn confirm_first_sync(project_path: String) -> Result<()> {
+    config::update_project(&project_path, |project| {
+        project.first_sync_pending = false;
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(path: &str, children: Vec<InstanceNode>) -> InstanceNode {
+        InstanceNode {
+            name: path.rsplit('/').next().unwrap_or_default().to_string(),
+            path: path.to_string(),
+            children,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let project_json = serde_json::json!({
+            "$className": "DataModel",
+            "ServerScriptService": { "$path": "src/server" },
+            "Workspace": { "$className": "Workspace", "Map": { "$path": "src/map" } }
+        });
+        let mut rules = BTreeMap::new();
+        removes_unknown(&project_json, "", &mut rules);
+
+        let tree = node("", vec![
+            node("ServerScriptService", vec![node("ServerScriptService/Main", vec![]), node("ServerScriptService/Combat", vec![node("ServerScriptService/Combat/Hitbox", vec![])])]),
+            node("Workspace", vec![node("Workspace/Map", vec![])]),
+        ]);
+        let studio: HashMap<String, Vec<String>> = [
+            ("", vec!["ServerScriptService", "Workspace", "Lighting"]),
+            ("ServerScriptService", vec!["Main", "OldScript"]),
+            ("ServerScriptService/Main", vec![]),
+            ("Workspace", vec!["Baseplate", "Map"]),
+            ("Workspace/Map", vec!["Tree"]),
+        ]
+        .into_iter()
+        .map(|(path, names)| (path.to_string(), names.into_iter().map(String::from).collect()))
+        .collect();
+
+        let mut preview = SyncPreview::default();
+        diff(&tree, &rules, &studio, &mut preview);
+        assert_eq!(preview.added, ["ServerScriptService/Combat"]);
+        assert_eq!(preview.added_count, 2);
+        // Lighting and Baseplate aren't under a $path, so Rojo leaves them alone
+        assert_eq!(preview.removed, ["ServerScriptService/OldScript", "Workspace/Map/Tree"]);
+    }
+}
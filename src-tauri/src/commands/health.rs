@@ -0,0 +1,121 @@
+//! Session health metrics: rolling timings of auto-backups and of the delay
+//! between Studio printing a line and the log server receiving it. There is
+//! no file save → Studio sync timing: Rojo doesn't report when it applies a
+//! change, so the launcher has nothing to measure it against.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+
+/// Samples kept per metric.
+const MAX_SAMPLES: usize = 200;
+
+#[derive(Clone, Copy)]
+pub enum Metric {
+    /// One auto-backup (git stash snapshot).
+    BackupDuration,
+    /// Plugin-side timestamp of a log entry → batch received.
+    BatchLag,
+}
+
+impl Metric {
+    fn name(self) -> &'static str {
+        match self {
+            Metric::BackupDuration => "backupDuration",
+            Metric::BatchLag => "batchLag",
+        }
+    }
+}
+
+static SAMPLES: Mutex<BTreeMap<&'static str, VecDeque<u64>>> = Mutex::new(BTreeMap::new());
+
+/// Aggregates of one metric, in milliseconds.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSummary {
+    pub name: String,
+    pub count: usize,
+    pub last_ms: u64,
+    pub avg_ms: u64,
+    pub p95_ms: u64,
+    pub max_ms: u64,
+}
+
+/// Records one sample.
+pub fn record(metric: Metric, ms: u64) {
+    if let Ok(mut samples) = SAMPLES.lock() {
+        let series = samples.entry(metric.name()).or_default();
+        if series.len() == MAX_SAMPLES {
+            series.pop_front();
+        }
+        series.push_back(ms);
+    }
+}
+
+/// Clears all samples (called when a session starts).
+pub fn reset() {
+    if let Ok(mut samples) = SAMPLES.lock() {
+        samples.clear();
+    }
+}
+
+/// Summaries of every metric with at least one sample.
+pub fn summaries() -> Vec<MetricSummary> {
+    let Ok(samples) = SAMPLES.lock() else {
+        return Vec::new();
+    };
+    samples
+        .iter()
+        .filter(|(_, series)| !series.is_empty())
+        .map(|(name, series)| {
+            let mut sorted: Vec<u64> = series.iter().copied().collect();
+            sorted.sort_unstable();
+            MetricSummary {
+                name: name.to_string(),
+                count: sorted.len(),
+                last_ms: series.back().copied().unwrap_or(0),
+                avg_ms: sorted.iter().sum::<u64>() / sorted.len() as u64,
+                p95_ms: percentile(&sorted, 95),
+                max_ms: sorted.last().copied().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// One-line summary for the session log, or `None` without samples.
+pub fn summary_line() -> Option<String> {
+    let parts: Vec<String> = summaries()
+        .iter()
+        .map(|s| format!("{} avg {}ms p95 {}ms max {}ms (n={})", s.name, s.avg_ms, s.p95_ms, s.max_ms, s.count))
+        .collect();
+    (!parts.is_empty()).then(|| format!("Health: {}", parts.join(", ")))
+}
+
+/// Nearest-rank percentile of an ascending slice.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Current auto-backup and log lag timings (see the module doc for what isn't measured).
+#[tauri::command]
+pub fn get_health_metrics() -> Vec<MetricSummary> {
+    summaries()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile() {
+        let sorted: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&sorted, 95), 95);
+        assert_eq!(percentile(&sorted, 100), 100);
+        assert_eq!(percentile(&[7], 95), 7);
+        assert_eq!(percentile(&[], 95), 0);
+    }
+}
@@ -1,11 +1,17 @@
+use crate::commands::analytics;
+use crate::commands::config;
 use crate::commands::context;
+use crate::commands::detect;
+use crate::commands::disk;
+use crate::commands::downloader::{self, mirror_host, Checksum, Progress};
+use crate::commands::install_report::SetupChannel;
 use crate::commands::project;
+use crate::commands::rbxm;
 use crate::error::{InstallerError, Result};
-use futures_util::StreamExt;
+use crate::templates::ROJO_VERSION;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::ipc::Channel;
-use tokio::io::AsyncWriteExt;
 
 /// Progress events streamed from Rust to the React frontend via Channel.
 #[derive(Clone, Serialize)]
@@ -28,12 +34,26 @@ pub enum SetupEvent {
     StepCompleted { step: String, detail: String },
     #[serde(rename_all = "camelCase")]
     StepWarning { step: String, message: String },
+    /// Step not run because detection found it already satisfied.
+    #[serde(rename_all = "camelCase")]
+    StepSkipped { step: String, reason: String },
     #[serde(rename_all = "camelCase")]
     Error { step: String, message: String },
+    /// A fatal failure was rolled back; lists the paths that were removed and
+    /// the overwritten files that were put back.
+    #[serde(rename_all = "camelCase")]
+    RolledBack { removed: Vec<String>, restored: Vec<String>, reason: String },
+    /// Post-install verification passed; lists the checks that were run.
+    #[serde(rename_all = "camelCase")]
+    Verified { checks: Vec<String> },
+    /// Not enough free disk space to install; nothing was written.
+    #[serde(rename_all = "camelCase")]
+    DiskLow { path: String, message: String, free_bytes: u64 },
     Finished,
 }
 
 /// Configuration received from the frontend to drive the installation.
+/// The `skip_*` flags are recomputed by `run_installation` from a fresh detection.
 #[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InstallConfig {
@@ -44,23 +64,284 @@ pub struct InstallConfig {
     pub skip_rojo: bool,
     pub skip_roxlit_mcp: bool,
     pub plugins_path: Option<String>,
+    /// Install tool binaries under this directory instead of the home directory.
+    /// Persisted to the `toolDir` setting so the launcher finds them later.
+    #[serde(default)]
+    pub tool_dir: Option<String>,
+    /// Project template id from `list_project_templates` (e.g. a lesson).
+    /// `None` creates the blank starter project.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Starter module ids from `list_starter_modules` to add to the project.
+    #[serde(default)]
+    pub starter_modules: Vec<String>,
+    /// How default.project.json is generated (services, ignore flags, extra
+    /// globIgnorePaths). `None` uses the standard layout.
+    #[serde(default)]
+    pub project_json: Option<crate::templates::ProjectJsonOptions>,
+    /// Install binaries and plugins to machine-wide locations (ProgramData /
+    /// /Library) so every account on a lab machine can use them. Requires admin.
+    #[serde(default)]
+    pub system_wide: bool,
+    /// Remove everything the installer created if a fatal step fails.
+    #[serde(default = "default_rollback_on_failure")]
+    pub rollback_on_failure: bool,
+}
+
+fn default_rollback_on_failure() -> bool {
+    true
+}
+
+/// Records every file and directory an installation creates or overwrites,
+/// so a failed install can be undone and leave the machine as it was.
+#[derive(Default)]
+struct InstallJournal {
+    /// Paths that did not exist before the installer created them, in creation order.
+    created: Vec<PathBuf>,
+    /// Existing files the installer is about to replace, with a copy of each.
+    overwritten: Vec<(PathBuf, PathBuf)>,
+}
+
+impl InstallJournal {
+    /// Record `path` if it doesn't exist yet, or copy it aside if it's an
+    /// existing file. Call right before creating or replacing it.
+    fn track(&mut self, path: impl AsRef<std::path::Path>) {
+        let path = path.as_ref();
+        if self.created.iter().any(|p| p == path) || self.overwritten.iter().any(|(p, _)| p == path) {
+            return;
+        }
+        if !path.exists() {
+            self.created.push(path.to_path_buf());
+        } else if path.is_file() {
+            let backup = Self::backup_dir().join(self.overwritten.len().to_string());
+            let copied = std::fs::create_dir_all(Self::backup_dir()).and_then(|_| std::fs::copy(path, &backup));
+            if copied.is_ok() {
+                self.overwritten.push((path.to_path_buf(), backup));
+            }
+        }
+    }
+
+    /// Where copies of overwritten files are kept until the install ends.
+    fn backup_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("roxlit-install-backup-{}", std::process::id()))
+    }
+
+    /// Record entries of `dir` that were not present in `before`.
+    /// Used for pre-existing directories, where only the new contents are ours.
+    fn track_new_entries(&mut self, dir: &std::path::Path, before: &[std::ffi::OsString]) {
+        if self.created.iter().any(|p| p == dir) {
+            return; // Whole directory is ours already
+        }
+        for name in dir_entries(dir) {
+            if !before.contains(&name) {
+                self.created.push(dir.join(name));
+            }
+        }
+    }
+
+    /// Remove every created path, newest first, then put overwritten files
+    /// back. Returns the paths actually removed and restored.
+    fn rollback(&self) -> (Vec<PathBuf>, Vec<PathBuf>) {
+        let mut removed = Vec::new();
+        for path in self.created.iter().rev() {
+            let result = if path.is_dir() {
+                std::fs::remove_dir_all(path)
+            } else {
+                std::fs::remove_file(path)
+            };
+            if result.is_ok() {
+                removed.push(path.clone());
+            }
+        }
+        let mut restored = Vec::new();
+        for (path, backup) in &self.overwritten {
+            if std::fs::copy(backup, path).is_ok() {
+                restored.push(path.clone());
+            }
+        }
+        (removed, restored)
+    }
+
+    /// Delete the copies of overwritten files once they're no longer needed.
+    fn discard_backups(&self) {
+        if !self.overwritten.is_empty() {
+            let _ = std::fs::remove_dir_all(Self::backup_dir());
+        }
+    }
+}
+
+/// Room needed for the tool downloads, their extracted binaries and the
+/// project, with margin.
+const INSTALL_BYTES: u64 = 300 * 1024 * 1024;
+
+/// Names of the direct children of `dir` (empty if it doesn't exist).
+fn dir_entries(dir: &std::path::Path) -> Vec<std::ffi::OsString> {
+    std::fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|e| e.file_name()).collect())
+        .unwrap_or_default()
 }
 
 use crate::util::expand_tilde;
 
 /// Orchestrates the full installation process, reporting progress through a Channel.
+/// Every run is saved as an install report (see `install_report`).
 #[tauri::command]
 pub async fn run_installation(
     config: InstallConfig,
     on_event: Channel<SetupEvent>,
 ) -> Result<()> {
+    let on_event = SetupChannel::new(on_event, &config.ai_tool, &expand_tilde(&config.project_path));
+    let result = install(config, &on_event).await;
+    on_event.finish(&result);
+    result
+}
+
+/// `run_installation`, with its events recorded into the install report.
+async fn install(config: InstallConfig, on_event: &SetupChannel) -> Result<()> {
     // Resolve ~ in the project path before doing anything
     let config = InstallConfig {
         project_path: expand_tilde(&config.project_path),
         ..config
     };
 
-    let total_steps = calculate_total_steps(&config);
+    // All-users mode installs tools under the machine-wide directory
+    let system_dir = if config.system_wide && config.tool_dir.is_none() {
+        Some(
+            config::system_tools_dir()
+                .ok_or_else(|| InstallerError::Custom("No machine-wide install location on this OS".into()))?,
+        )
+    } else {
+        None
+    };
+    let config = match system_dir {
+        Some(ref dir) => InstallConfig {
+            tool_dir: Some(dir.to_string_lossy().to_string()),
+            ..config
+        },
+        None => config,
+    };
+
+    // Stop before writing anything if the tools or project won't fit
+    let mut targets = vec![PathBuf::from(&config.project_path)];
+    match config.tool_dir {
+        Some(ref dir) => targets.push(PathBuf::from(expand_tilde(dir))),
+        None => targets.extend(config::tools_home()),
+    }
+    for target in targets {
+        if let Some((free_bytes, message)) = disk::low_space(&target, INSTALL_BYTES, "the installation").await {
+            on_event
+                .send(SetupEvent::DiskLow {
+                    path: target.to_string_lossy().to_string(),
+                    message: message.clone(),
+                    free_bytes,
+                })
+                .map_err(|e| InstallerError::Custom(e.to_string()))?;
+            return Err(InstallerError::Custom(message));
+        }
+    }
+
+    let mut journal = InstallJournal::default();
+    // The project directory (or, if it already exists, its new entries) is undone on rollback
+    let project_root = PathBuf::from(&config.project_path);
+    journal.track(&project_root);
+    let project_entries_before = dir_entries(&project_root);
+    // config.json gets the tool directory and installed tool versions
+    if let Some(config_file) = config::config_path() {
+        journal.track(config_file);
+    }
+
+    let (ai_tool, rollback_on_failure) = (config.ai_tool.clone(), config.rollback_on_failure);
+    let result = match prepare(config, system_dir, on_event, &mut journal).await {
+        Ok(config) => run_steps(&config, on_event, &mut journal).await,
+        Err(e) => Err(e),
+    };
+    analytics::track(
+        "install_finished",
+        serde_json::json!({ "success": result.is_ok(), "aiTool": ai_tool }),
+    );
+    if let Err(ref e) = result {
+        if rollback_on_failure {
+            journal.track_new_entries(&project_root, &project_entries_before);
+            let (removed, restored) = journal.rollback();
+            journal.discard_backups();
+            on_event
+                .send(SetupEvent::RolledBack {
+                    removed: removed.iter().map(|p| p.to_string_lossy().to_string()).collect(),
+                    restored: restored.iter().map(|p| p.to_string_lossy().to_string()).collect(),
+                    reason: e.to_string(),
+                })
+                .map_err(|e| InstallerError::Custom(e.to_string()))?;
+        }
+    }
+    journal.discard_backups();
+    result
+}
+
+/// Creates the machine-wide tools directory, saves the tool directory and
+/// re-runs detection. Returns the config with up-to-date skip flags.
+async fn prepare(
+    config: InstallConfig,
+    system_dir: Option<PathBuf>,
+    on_event: &SetupChannel,
+    journal: &mut InstallJournal,
+) -> Result<InstallConfig> {
+    if let Some(ref system_dir) = system_dir {
+        journal.track(system_dir);
+        std::fs::create_dir_all(system_dir).map_err(|e| {
+            InstallerError::Custom(format!(
+                "Cannot write to {} — system-wide install requires administrator privileges ({e})",
+                system_dir.display()
+            ))
+        })?;
+    }
+
+    // Persist the tool directory override first — detection and every install
+    // step resolve binary paths from the config
+    if let Some(ref dir) = config.tool_dir {
+        config::save_tool_dir(dir)?;
+    }
+
+    // Re-run detection here rather than trusting the frontend's skip flags,
+    // which can be stale (e.g. the user installed Rojo after the Detect step).
+    let detection = detect::detect_environment().await?;
+    on_event.record_detection(&detection);
+    let config = InstallConfig {
+        skip_aftman: detection.aftman_installed,
+        skip_rojo: detection.rojo_installed,
+        skip_roxlit_mcp: detection.roxlit_mcp_installed || detection.os == "linux",
+        ..config
+    };
+
+    let skipped = [
+        (config.skip_aftman, "aftman", "Aftman", &detection.aftman_version),
+        (config.skip_rojo, "rojo", "Rojo", &detection.rojo_version),
+        (config.skip_roxlit_mcp, "roxlit_mcp", "Roxlit MCP", &detection.roxlit_mcp_version),
+    ];
+    for (skip, step, name, version) in skipped {
+        if !skip {
+            continue;
+        }
+        let reason = match version {
+            Some(v) => format!("{name} already installed ({v})"),
+            None => format!("{name} is not supported on this platform"),
+        };
+        on_event
+            .send(SetupEvent::StepSkipped {
+                step: step.into(),
+                reason,
+            })
+            .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    }
+    Ok(config)
+}
+
+/// Runs the installation steps in order, recording created paths in `journal`.
+async fn run_steps(
+    config: &InstallConfig,
+    on_event: &SetupChannel,
+    journal: &mut InstallJournal,
+) -> Result<()> {
+    let total_steps = calculate_total_steps(config);
     let mut step_index: usize = 0;
 
     // Step 1: Install Aftman (if needed)
@@ -75,8 +356,9 @@ pub async fn run_installation(
             })
             .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
-        match install_aftman(&on_event).await {
+        match install_aftman(on_event, journal).await {
             Ok(()) => {
+                analytics::track_install_step("aftman", true);
                 on_event
                     .send(SetupEvent::StepCompleted {
                         step: "aftman".into(),
@@ -85,6 +367,7 @@ pub async fn run_installation(
                     .map_err(|e| InstallerError::Custom(e.to_string()))?;
             }
             Err(e) => {
+                analytics::track_install_step("aftman", false);
                 on_event
                     .send(SetupEvent::Error {
                         step: "aftman".into(),
@@ -108,8 +391,9 @@ pub async fn run_installation(
             })
             .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
-        match install_rojo(&config, &on_event).await {
+        match install_rojo(config, on_event, journal).await {
             Ok(()) => {
+                analytics::track_install_step("rojo", true);
                 on_event
                     .send(SetupEvent::StepCompleted {
                         step: "rojo".into(),
@@ -118,6 +402,7 @@ pub async fn run_installation(
                     .map_err(|e| InstallerError::Custom(e.to_string()))?;
             }
             Err(e) => {
+                analytics::track_install_step("rojo", false);
                 on_event
                     .send(SetupEvent::Error {
                         step: "rojo".into(),
@@ -140,16 +425,22 @@ pub async fn run_installation(
         })
         .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
-    match install_studio_plugin(&config).await {
-        Ok(()) => {
+    match install_studio_plugin(config, on_event, journal).await {
+        Ok(source) => {
+            analytics::track_install_step("plugin", true);
+            let detail = match mirror_host(&rojo_plugin_url(ROJO_VERSION), &source) {
+                Some(host) => format!("Studio plugin installed (downloaded from mirror {host})"),
+                None => "Studio plugin installed".into(),
+            };
             on_event
                 .send(SetupEvent::StepCompleted {
                     step: "plugin".into(),
-                    detail: "Studio plugin installed".into(),
+                    detail,
                 })
                 .map_err(|e| InstallerError::Custom(e.to_string()))?;
         }
         Err(e) => {
+            analytics::track_install_step("plugin", false);
             // Plugin installation is non-critical — warn but continue
             on_event
                 .send(SetupEvent::StepWarning {
@@ -172,8 +463,9 @@ pub async fn run_installation(
             })
             .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
-        match install_roxlit_plugin(&config, &on_event).await {
+        match install_roxlit_plugin(config, on_event, journal).await {
             Ok(()) => {
+                analytics::track_install_step("roxlit_mcp", true);
                 on_event
                     .send(SetupEvent::StepCompleted {
                         step: "roxlit_mcp".into(),
@@ -182,6 +474,7 @@ pub async fn run_installation(
                     .map_err(|e| InstallerError::Custom(e.to_string()))?;
             }
             Err(e) => {
+                analytics::track_install_step("roxlit_mcp", false);
                 // Roxlit MCP is non-critical — warn but continue
                 on_event
                     .send(SetupEvent::StepWarning {
@@ -204,7 +497,27 @@ pub async fn run_installation(
         })
         .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
-    project::create_project(&config.project_path, &config.project_name)?;
+    let project_json = config.project_json.clone().unwrap_or_default();
+    project::create_project(&config.project_path, &config.project_name, &project_json)?;
+    if let Some(ref template) = config.template {
+        project::apply_template(&config.project_path, &config.project_name, template, &project_json)?;
+    }
+    project::add_starter_modules(&config.project_path, &config.starter_modules)?;
+    if crate::commands::manifest::read_manifest(&config.project_path)?.is_none() {
+        let manifest = crate::commands::manifest::RoxlitManifest {
+            project: crate::commands::manifest::ManifestProject {
+                name: Some(config.project_name.clone()),
+                template: config.template.clone(),
+                ai_tool: Some(config.ai_tool.clone()),
+            },
+            // Kept so a regenerated default.project.json has the same shape
+            settings: crate::commands::config::ProjectSettings {
+                project_json: config.project_json.clone(),
+                ..Default::default()
+            },
+        };
+        crate::commands::manifest::write_manifest(&config.project_path, &manifest)?;
+    }
     on_event
         .send(SetupEvent::StepCompleted {
             step: "project".into(),
@@ -234,6 +547,42 @@ pub async fn run_installation(
         })
         .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
+    // Step 7: Verify the installed tools actually work
+    step_index += 1;
+    on_event
+        .send(SetupEvent::StepStarted {
+            step: "verify".into(),
+            description: "Verifying installation".into(),
+            step_index,
+            total_steps,
+        })
+        .map_err(|e| InstallerError::Custom(e.to_string()))?;
+
+    let (passed, problems) = verify_installation(config).await;
+    for problem in &problems {
+        on_event
+            .send(SetupEvent::StepWarning {
+                step: "verify".into(),
+                message: problem.clone(),
+            })
+            .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    }
+    on_event
+        .send(SetupEvent::StepCompleted {
+            step: "verify".into(),
+            detail: if problems.is_empty() {
+                format!("All {} checks passed", passed.len())
+            } else {
+                format!("{} checks passed, {} warnings", passed.len(), problems.len())
+            },
+        })
+        .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    if problems.is_empty() {
+        on_event
+            .send(SetupEvent::Verified { checks: passed })
+            .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    }
+
     // All done
     on_event
         .send(SetupEvent::Finished)
@@ -243,7 +592,7 @@ pub async fn run_installation(
 }
 
 fn calculate_total_steps(config: &InstallConfig) -> usize {
-    let mut steps = 3; // plugin + project + context are always run
+    let mut steps = 4; // plugin + project + context + verify are always run
     if !config.skip_aftman {
         steps += 1;
     }
@@ -256,8 +605,63 @@ fn calculate_total_steps(config: &InstallConfig) -> usize {
     steps
 }
 
+/// Combines the byte progress of a step's downloads (which may run in
+/// parallel) into `StepProgress` events within `range` of the step's bar.
+/// An event is only sent when the shown percentage changes.
+struct StepDownloads<'a> {
+    on_event: &'a SetupChannel,
+    step: &'a str,
+    label: &'a str,
+    range: (f64, f64),
+    /// Each download's progress, and the last percentage (or MB) reported.
+    state: std::sync::Mutex<(Vec<Progress>, Option<u64>)>,
+}
+
+impl<'a> StepDownloads<'a> {
+    fn new(on_event: &'a SetupChannel, step: &'a str, label: &'a str, range: (f64, f64), count: usize) -> Self {
+        Self {
+            on_event,
+            step,
+            label,
+            range,
+            state: std::sync::Mutex::new((vec![Progress::default(); count], None)),
+        }
+    }
+
+    /// Progress callback for the `index`th download.
+    fn part(&self, index: usize) -> impl Fn(Progress) + Sync + '_ {
+        move |progress| self.update(index, progress)
+    }
+
+    fn update(&self, index: usize, progress: Progress) {
+        let Ok(mut state) = self.state.lock() else { return };
+        state.0[index] = progress;
+        let downloaded: u64 = state.0.iter().map(|p| p.downloaded).sum();
+        let total: Option<u64> = state.0.iter().map(|p| p.total).sum();
+        let fraction = total.filter(|t| *t > 0).map(|t| downloaded as f64 / t as f64);
+
+        let shown = fraction.map_or(downloaded / 1_000_000, |f| (f * 100.0) as u64);
+        if state.1 == Some(shown) {
+            return;
+        }
+        state.1 = Some(shown);
+
+        let mb = |bytes: u64| bytes as f64 / 1_000_000.0;
+        let detail = match total {
+            Some(total) => format!("Downloading {}... {:.1} MB / {:.1} MB", self.label, mb(downloaded), mb(total)),
+            None => format!("Downloading {}... {:.1} MB", self.label, mb(downloaded)),
+        };
+        let (from, to) = self.range;
+        let _ = self.on_event.send(SetupEvent::StepProgress {
+            step: self.step.into(),
+            progress: from + fraction.unwrap_or(0.0).min(1.0) * (to - from),
+            detail,
+        });
+    }
+}
+
 /// Downloads and installs Aftman from its GitHub releases.
-async fn install_aftman(on_event: &Channel<SetupEvent>) -> Result<()> {
+async fn install_aftman(on_event: &SetupChannel, journal: &mut InstallJournal) -> Result<()> {
     // Asset names follow the pattern: aftman-{version}-{platform}-{arch}.zip
     // We use a known stable version to avoid breaking changes in future releases.
     let version = "0.3.0";
@@ -277,56 +681,21 @@ async fn install_aftman(on_event: &Channel<SetupEvent>) -> Result<()> {
         "https://github.com/LPGhatguy/aftman/releases/download/v{version}/aftman-{version}-{target}.zip"
     );
 
-    on_event
-        .send(SetupEvent::StepProgress {
-            step: "aftman".into(),
-            progress: 0.1,
-            detail: "Downloading Aftman...".into(),
-        })
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
-
-    // Download the zip to a temp file
-    let response = reqwest::get(&url).await?;
-
-    if !response.status().is_success() {
-        return Err(InstallerError::Custom(format!(
-            "Failed to download Aftman: HTTP {} from {url}",
-            response.status()
-        )));
-    }
-
-    let total_size = response.content_length().unwrap_or(0);
-    let mut stream = response.bytes_stream();
-
+    // Download the zip to a temp file (falls back to mirrors if GitHub is blocked)
     let temp_dir = std::env::temp_dir().join("roxlit-installer");
-    tokio::fs::create_dir_all(&temp_dir).await?;
+    journal.track(&temp_dir);
     let zip_path = temp_dir.join("aftman.zip");
-
-    let mut file = tokio::fs::File::create(&zip_path).await?;
-    let mut downloaded: u64 = 0;
-
-    while let Some(chunk) = stream.next().await {
-        let chunk = chunk?;
-        file.write_all(&chunk).await?;
-        downloaded += chunk.len() as u64;
-
-        if total_size > 0 {
-            let progress = 0.1 + (downloaded as f64 / total_size as f64) * 0.6;
-            on_event
-                .send(SetupEvent::StepProgress {
-                    step: "aftman".into(),
-                    progress,
-                    detail: format!(
-                        "Downloading... {:.1} MB / {:.1} MB",
-                        downloaded as f64 / 1_000_000.0,
-                        total_size as f64 / 1_000_000.0,
-                    ),
-                })
-                .map_err(|e| InstallerError::Custom(e.to_string()))?;
-        }
+    let downloads = StepDownloads::new(on_event, "aftman", "Aftman", (0.1, 0.7), 1);
+    let source = downloader::download(&url, &zip_path, Checksum::Release, downloads.part(0)).await?;
+    if let Some(host) = mirror_host(&url, &source) {
+        on_event
+            .send(SetupEvent::StepProgress {
+                step: "aftman".into(),
+                progress: 0.7,
+                detail: format!("GitHub unreachable, downloaded Aftman from mirror {host}"),
+            })
+            .map_err(|e| InstallerError::Custom(e.to_string()))?;
     }
-    file.flush().await?;
-    drop(file);
 
     on_event
         .send(SetupEvent::StepProgress {
@@ -338,11 +707,12 @@ async fn install_aftman(on_event: &Channel<SetupEvent>) -> Result<()> {
 
     // Extract the zip — this is sync but fast, so we spawn_blocking
     let zip_path_clone = zip_path.clone();
-    let aftman_bin_dir = dirs::home_dir()
-        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?
-        .join(".aftman")
-        .join("bin");
+    let aftman_bin_dir = config::aftman_bin_dir()
+        .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
 
+    journal.track(aftman_bin_dir.parent().unwrap_or(&aftman_bin_dir));
+    journal.track(&aftman_bin_dir);
+    journal.track(aftman_bin_dir.join(if cfg!(target_os = "windows") { "aftman.exe" } else { "aftman" }));
     let bin_dir = aftman_bin_dir.clone();
     tokio::task::spawn_blocking(move || -> Result<()> {
         std::fs::create_dir_all(&bin_dir)?;
@@ -397,6 +767,7 @@ async fn install_aftman(on_event: &Channel<SetupEvent>) -> Result<()> {
 
     let mut cmd = tokio::process::Command::new(&aftman_bin);
     cmd.arg("self-install");
+    set_aftman_home(&mut cmd);
     #[cfg(target_os = "windows")]
     cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
     let output = cmd.output().await?;
@@ -414,11 +785,17 @@ async fn install_aftman(on_event: &Channel<SetupEvent>) -> Result<()> {
     // Clean up temp files
     let _ = tokio::fs::remove_dir_all(&temp_dir).await;
 
+    let _ = config::save_tool_version("aftman", version);
+
     Ok(())
 }
 
 /// Installs Rojo by writing an aftman.toml and running aftman install.
-async fn install_rojo(config: &InstallConfig, on_event: &Channel<SetupEvent>) -> Result<()> {
+async fn install_rojo(
+    config: &InstallConfig,
+    on_event: &SetupChannel,
+    journal: &mut InstallJournal,
+) -> Result<()> {
     let project_path = PathBuf::from(&config.project_path);
     std::fs::create_dir_all(&project_path)?;
 
@@ -432,16 +809,12 @@ async fn install_rojo(config: &InstallConfig, on_event: &Channel<SetupEvent>) ->
 
     // Write aftman.toml pinning the Rojo version
     let aftman_toml = project_path.join("aftman.toml");
-    std::fs::write(
-        &aftman_toml,
-        "[tools]\nrojo = \"rojo-rbx/rojo@7.4.4\"\n",
-    )?;
+    journal.track(&aftman_toml);
+    std::fs::write(&aftman_toml, crate::templates::aftman_toml())?;
 
     // Use the full path to aftman since it may not be in PATH yet
-    let aftman_bin = dirs::home_dir()
+    let aftman_bin = config::aftman_bin_dir()
         .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?
-        .join(".aftman")
-        .join("bin")
         .join(if cfg!(target_os = "windows") {
             "aftman.exe"
         } else {
@@ -467,14 +840,22 @@ async fn install_rojo(config: &InstallConfig, on_event: &Channel<SetupEvent>) ->
         })
         .map_err(|e| InstallerError::Custom(e.to_string()))?;
 
+    // Pre-trust the tools we manage for later installs; this aftman.toml only
+    // has Roxlit's own pins, so this install skips the trust check outright
+    if let Some(trusted) = crate::commands::aftman::trusted_file() {
+        journal.track(trusted);
+    }
+    crate::commands::aftman::ensure_trusted(crate::commands::aftman::MANAGED_TOOLS)?;
+
     // Try aftman install with retry — file locks on Windows can linger briefly
     let max_attempts = 3;
     let mut last_err = String::new();
     for attempt in 1..=max_attempts {
         let mut cmd = tokio::process::Command::new(&aftman_bin);
-        cmd.arg("install")
-            .arg("--no-trust-check")
-            .current_dir(&project_path);
+        cmd.args(["install", "--no-trust-check"])
+            .current_dir(&project_path)
+            .stdin(std::process::Stdio::null());
+        set_aftman_home(&mut cmd);
         #[cfg(target_os = "windows")]
         cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
         let output = cmd.output().await?;
@@ -504,11 +885,11 @@ async fn install_rojo(config: &InstallConfig, on_event: &Channel<SetupEvent>) ->
     }
 
     if !last_err.is_empty() {
-        return Err(InstallerError::Custom(format!(
-            "Failed to install Rojo: {last_err}"
-        )));
+        return Err(crate::commands::aftman::install_error(&project_path, &last_err));
     }
 
+    let _ = config::save_tool_version("rojo", ROJO_VERSION);
+
     on_event
         .send(SetupEvent::StepProgress {
             step: "rojo".into(),
@@ -520,104 +901,141 @@ async fn install_rojo(config: &InstallConfig, on_event: &Channel<SetupEvent>) ->
     Ok(())
 }
 
-/// Downloads a binary from a URL to the target path with progress reporting.
-async fn download_binary(url: &str, target_path: &PathBuf) -> Result<()> {
-    if let Some(parent) = target_path.parent() {
-        tokio::fs::create_dir_all(parent).await?;
-    }
+/// Release asset published together with this launcher version.
+fn release_asset_url(name: &str) -> String {
+    format!(
+        "https://github.com/Roxlit/installer/releases/download/v{}/{}",
+        env!("CARGO_PKG_VERSION"),
+        name
+    )
+}
 
-    let response = reqwest::get(url).await?;
-    if !response.status().is_success() {
-        return Err(InstallerError::Custom(format!(
-            "Failed to download: HTTP {} from {url}",
-            response.status()
-        )));
-    }
+/// Roxlit Studio plugin released together with this launcher version.
+fn roxlit_plugin_url() -> String {
+    release_asset_url("Roxlit.rbxm")
+}
 
-    let bytes = response.bytes().await?;
-    tokio::fs::write(target_path, &bytes).await?;
+/// Replaces an outdated `Roxlit.rbxm` in the user's global Studio plugins
+/// folder with the one matching this launcher. Studio only loads plugins at
+/// startup, so the new version is active after a Studio restart.
+pub(crate) async fn update_roxlit_plugin() -> Result<PathBuf> {
+    let plugins_dir = config::plugins_dir().ok_or_else(|| {
+        InstallerError::Custom("Roblox Studio plugins are not supported on this OS".into())
+    })?;
+    let plugin_path = plugins_dir.join("Roxlit.rbxm");
+    let download = plugins_dir.join("Roxlit.rbxm.download");
+    downloader::download_binary(&roxlit_plugin_url(), &download, |_| {}).await?;
+    replace_if_valid(&download, &plugin_path).await?;
+    let _ = config::save_tool_version("roxlit-plugin", env!("CARGO_PKG_VERSION"));
+    Ok(plugin_path)
+}
 
-    // Make executable on Unix
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        tokio::fs::set_permissions(target_path, std::fs::Permissions::from_mode(0o755)).await?;
-    }
+/// Moves a downloaded plugin over `plugin_path` once it parses as a model
+/// file. An invalid download is deleted and the installed plugin kept.
+async fn replace_if_valid(download: &std::path::Path, plugin_path: &std::path::Path) -> Result<()> {
+    discard_if_invalid(download).await?;
+    tokio::fs::rename(download, plugin_path).await?;
+    Ok(())
+}
 
+/// Removes a plugin that doesn't parse as a model file, so Studio isn't left
+/// with a broken plugin that silently fails to load.
+pub(crate) async fn discard_if_invalid(plugin_path: &std::path::Path) -> Result<()> {
+    if let Err(e) = rbxm::check_file(plugin_path).await {
+        let _ = tokio::fs::remove_file(plugin_path).await;
+        return Err(e);
+    }
     Ok(())
 }
 
 /// Returns the Roxlit MCP server download URL for the current platform.
-fn roxlit_mcp_download_url() -> Option<String> {
+pub(crate) fn roxlit_mcp_download_url() -> Option<String> {
     if cfg!(target_os = "windows") && cfg!(target_arch = "x86_64") {
-        Some("https://github.com/Roxlit/installer/releases/latest/download/roxlit-mcp.exe".to_string())
+        Some(release_asset_url("roxlit-mcp.exe"))
     } else {
         None
     }
 }
 
 /// Downloads and installs Roxlit Studio plugin and MCP server.
-async fn install_roxlit_plugin(config: &InstallConfig, on_event: &Channel<SetupEvent>) -> Result<()> {
-    let home = dirs::home_dir()
+async fn install_roxlit_plugin(
+    config: &InstallConfig,
+    on_event: &SetupChannel,
+    journal: &mut InstallJournal,
+) -> Result<()> {
+    let bin_dir = config::roxlit_bin_dir()
         .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?;
-    let bin_dir = home.join(".roxlit").join("bin");
 
-    // 1. Download unified Roxlit Studio plugin
-    on_event
-        .send(SetupEvent::StepProgress {
-            step: "plugin".into(),
-            progress: 0.2,
-            detail: "Installing Roxlit Studio plugin...".into(),
-        })
-        .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    let plugin_url = roxlit_plugin_url();
 
-    let plugin_url = "https://github.com/Roxlit/installer/releases/latest/download/Roxlit.rbxm";
-
-    let plugins_path = match &config.plugins_path {
-        Some(path) => PathBuf::from(path),
-        None => {
-            if cfg!(target_os = "windows") {
-                dirs::data_local_dir()
-                    .ok_or_else(|| InstallerError::Custom("Cannot find AppData".into()))?
-                    .join("Roblox")
-                    .join("Plugins")
-            } else if cfg!(target_os = "macos") {
-                home.join("Library").join("Roblox").join("Plugins")
-            } else {
-                return Ok(()); // Linux — no plugins
-            }
-        }
+    let plugins_path = match studio_plugins_dir(config)? {
+        Some(path) => path,
+        None => return Ok(()), // Linux — no plugins
     };
+    journal.track(&plugins_path);
     std::fs::create_dir_all(&plugins_path)?;
     let plugin_path = plugins_path.join("Roxlit.rbxm");
-    download_binary(plugin_url, &plugin_path).await?;
+    journal.track(&plugin_path);
+    let plugin_download = plugins_path.join("Roxlit.rbxm.download");
+    journal.track(&plugin_download);
 
-    // Clean up old plugins that the unified Roxlit plugin replaces
-    let _ = std::fs::remove_file(plugins_path.join("Rojo.rbxm"));
-    let _ = std::fs::remove_file(plugins_path.join("RbxSync.rbxm")); // legacy
-    let _ = std::fs::remove_file(plugins_path.join("rbxsync.rbxm")); // legacy
-    let _ = std::fs::remove_file(plugins_path.join("RoxlitDebug.rbxm"));
-    let _ = std::fs::remove_file(plugins_path.join("RoxlitDebug.rbxmx"));
+    // MCP server (Windows x64 only)
+    let mcp = roxlit_mcp_download_url().map(|url| {
+        let mcp_bin_name = if cfg!(target_os = "windows") {
+            "roxlit-mcp.exe"
+        } else {
+            "roxlit-mcp"
+        };
+        (url, bin_dir.join(mcp_bin_name))
+    });
+    if let Some((_, ref mcp_path)) = mcp {
+        journal.track(&bin_dir);
+        journal.track(mcp_path);
+    }
 
-    // 2. Download MCP server (Windows x64)
-    if let Some(mcp_url) = roxlit_mcp_download_url() {
+    // Download the unified Roxlit Studio plugin and the MCP server in parallel
+    let (label, count) = if mcp.is_some() {
+        ("the Roxlit plugin and MCP server", 2)
+    } else {
+        ("the Roxlit plugin", 1)
+    };
+    let downloads = StepDownloads::new(on_event, "plugin", label, (0.1, 0.9), count);
+    let plugin_fetch = downloader::download_binary(&plugin_url, &plugin_download, downloads.part(0));
+    let mcp_download = async {
+        match mcp {
+            Some((ref url, ref path)) => downloader::download_binary(url, path, downloads.part(1)).await.map(Some),
+            None => Ok(None),
+        }
+    };
+    let (plugin_source, mcp_source) = tokio::try_join!(plugin_fetch, mcp_download)?;
+    replace_if_valid(&plugin_download, &plugin_path).await?;
+
+    let _ = config::save_tool_version("roxlit-plugin", env!("CARGO_PKG_VERSION"));
+    let mut mirrors: Vec<String> = mirror_host(&plugin_url, &plugin_source).into_iter().collect();
+    if let (Some((url, _)), Some(source)) = (&mcp, &mcp_source) {
+        let _ = config::save_tool_version("roxlit-mcp", env!("CARGO_PKG_VERSION"));
+        mirrors.extend(mirror_host(url, source));
+    }
+    mirrors.dedup();
+    if !mirrors.is_empty() {
         on_event
             .send(SetupEvent::StepProgress {
                 step: "plugin".into(),
-                progress: 0.6,
-                detail: "Installing Roxlit MCP server...".into(),
+                progress: 0.9,
+                detail: format!("Downloaded from mirror {}", mirrors.join(", ")),
             })
             .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    }
 
-        let mcp_bin_name = if cfg!(target_os = "windows") {
-            "roxlit-mcp.exe"
-        } else {
-            "roxlit-mcp"
-        };
-        let mcp_path = bin_dir.join(mcp_bin_name);
-        download_binary(&mcp_url, &mcp_path).await?;
+    // Clean up old plugins that the unified Roxlit plugin replaces
+    let _ = std::fs::remove_file(plugins_path.join("Rojo.rbxm"));
+    let _ = std::fs::remove_file(plugins_path.join("RbxSync.rbxm")); // legacy
+    let _ = std::fs::remove_file(plugins_path.join("rbxsync.rbxm")); // legacy
+    let _ = std::fs::remove_file(plugins_path.join("RoxlitDebug.rbxm"));
+    let _ = std::fs::remove_file(plugins_path.join("RoxlitDebug.rbxmx"));
 
-        // Clean up old rbxsync-mcp
+    // Clean up old rbxsync-mcp
+    if mcp.is_some() {
         let old_mcp = bin_dir.join(if cfg!(target_os = "windows") { "rbxsync-mcp.exe" } else { "rbxsync-mcp" });
         let _ = std::fs::remove_file(&old_mcp);
     }
@@ -633,51 +1051,190 @@ async fn install_roxlit_plugin(config: &InstallConfig, on_event: &Channel<SetupE
     Ok(())
 }
 
-/// Downloads and copies the Rojo Studio plugin to the local plugins folder.
-async fn install_studio_plugin(config: &InstallConfig) -> Result<()> {
-    let plugins_path = match &config.plugins_path {
-        Some(path) => PathBuf::from(path),
-        None => {
-            // Use the default plugins path for the current OS
-            let base = if cfg!(target_os = "windows") {
-                dirs::data_local_dir()
-                    .ok_or_else(|| InstallerError::Custom("Cannot find AppData".into()))?
-                    .join("Roblox")
-                    .join("Plugins")
-            } else if cfg!(target_os = "macos") {
-                dirs::home_dir()
-                    .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?
-                    .join("Library")
-                    .join("Roblox")
-                    .join("Plugins")
-            } else {
-                return Err(InstallerError::Custom(
-                    "Roblox Studio plugins are not supported on this OS".into(),
-                ));
-            };
-            base
-        }
-    };
+/// Resolves the Studio plugins folder: the path from the frontend, the
+/// `pluginsDir` setting, the machine-wide staging folder in all-users mode,
+/// or the OS default.
+/// Returns `None` on platforms without Roblox Studio (Linux) unless configured.
+fn studio_plugins_dir(config: &InstallConfig) -> Result<Option<PathBuf>> {
+    if let Some(path) = &config.plugins_path {
+        return Ok(Some(PathBuf::from(path)));
+    }
+    if let Some(dir) = config::read_config().and_then(|c| c.plugins_dir) {
+        return Ok(Some(PathBuf::from(expand_tilde(&dir))));
+    }
+    if config.system_wide && !cfg!(target_os = "linux") {
+        return Ok(config::system_plugins_dir());
+    }
+    if cfg!(target_os = "windows") {
+        Ok(Some(
+            dirs::data_local_dir()
+                .ok_or_else(|| InstallerError::Custom("Cannot find AppData".into()))?
+                .join("Roblox")
+                .join("Plugins"),
+        ))
+    } else if cfg!(target_os = "macos") {
+        Ok(Some(
+            dirs::home_dir()
+                .ok_or_else(|| InstallerError::Custom("Cannot find home directory".into()))?
+                .join("Library")
+                .join("Roblox")
+                .join("Plugins"),
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Rojo Studio plugin asset of the given Rojo release.
+fn rojo_plugin_url(version: &str) -> String {
+    format!("https://github.com/rojo-rbx/rojo/releases/download/v{version}/Rojo.rbxm")
+}
 
+/// Downloads and copies the Rojo Studio plugin matching the pinned CLI version
+/// to the local plugins folder. Returns the URL the plugin was downloaded from.
+async fn install_studio_plugin(
+    config: &InstallConfig,
+    on_event: &SetupChannel,
+    journal: &mut InstallJournal,
+) -> Result<String> {
+    let plugins_path = studio_plugins_dir(config)?.ok_or_else(|| {
+        InstallerError::Custom("Roblox Studio plugins are not supported on this OS".into())
+    })?;
+
+    journal.track(&plugins_path);
     std::fs::create_dir_all(&plugins_path)?;
 
-    // Download the Rojo plugin from the latest release
-    let url = "https://github.com/rojo-rbx/rojo/releases/latest/download/Rojo.rbxm";
-    let response = reqwest::get(url).await?;
+    // Download the plugin from the same release as the CLI: a newer plugin can
+    // speak a sync protocol the pinned CLI doesn't
+    let plugin_file = plugins_path.join("Rojo.rbxm");
+    journal.track(&plugin_file);
+    let downloads = StepDownloads::new(on_event, "plugin", "the Rojo plugin", (0.1, 0.9), 1);
+    let source = downloader::download_binary(&rojo_plugin_url(ROJO_VERSION), &plugin_file, downloads.part(0)).await?;
+    let _ = config::save_tool_version("rojo-plugin", ROJO_VERSION);
+
+    Ok(source)
+}
+
+/// Rojo version a project pins in its aftman.toml (`rojo = "rojo-rbx/rojo@7.4.4"`).
+fn pinned_rojo_version(project_path: &str) -> Option<String> {
+    let content = std::fs::read_to_string(std::path::Path::new(project_path).join("aftman.toml")).ok()?;
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if key.trim() != "rojo" {
+            return None;
+        }
+        let spec = value.trim().trim_matches('"');
+        Some(spec.rsplit_once('@')?.1.to_string())
+    })
+}
+
+/// Re-downloads the Rojo Studio plugin when it doesn't match the Rojo CLI
+/// version (the project's aftman.toml, or the default pin). Only touches an
+/// existing `Rojo.rbxm` in the project's plugins folder; setups using the
+/// unified Roxlit plugin have none.
+/// Returns the new plugin version, or `None` if nothing changed.
+#[tauri::command]
+pub async fn refresh_rojo_plugin(project_path: Option<String>) -> Result<Option<String>> {
+    let version = project_path
+        .as_deref()
+        .and_then(|p| pinned_rojo_version(&expand_tilde(p)))
+        .unwrap_or_else(|| ROJO_VERSION.to_string());
+    let current = config::read_config().and_then(|c| c.tool_version("rojo-plugin").map(String::from));
+    if current.as_deref() == Some(version.as_str()) {
+        return Ok(None);
+    }
+
+    let Some(plugin_file) = config::plugins_dir()
+        .map(|d| d.join("Rojo.rbxm"))
+        .filter(|p| p.exists())
+    else {
+        return Ok(None);
+    };
 
-    if !response.status().is_success() {
+    // Downloaded next to the plugin and checked before replacing it
+    let download = plugin_file.with_file_name("Rojo.rbxm.download");
+    downloader::download(&rojo_plugin_url(&version), &download, Checksum::Release, |_| {}).await?;
+    let bytes = tokio::fs::read(&download).await?;
+    if let Err(problem) = rbxm::check(&bytes) {
+        let _ = tokio::fs::remove_file(&download).await;
         return Err(InstallerError::Custom(format!(
-            "Failed to download Rojo plugin: HTTP {}",
-            response.status()
+            "Rojo {version} plugin download is not a valid .rbxm file: {problem}"
         )));
     }
+    tokio::fs::rename(&download, &plugin_file).await?;
+    config::save_tool_version("rojo-plugin", &version)?;
+    Ok(Some(version))
+}
 
-    let bytes = response.bytes().await?;
+/// Validates the result of an installation: the tools run, the plugin files are
+/// real .rbxm models, and the generated MCP config parses.
+/// Returns `(passed checks, problems)`.
+async fn verify_installation(config: &InstallConfig) -> (Vec<String>, Vec<String>) {
+    let mut passed = Vec::new();
+    let mut problems = Vec::new();
+
+    // Rojo runs from its installed path
+    let rojo = crate::commands::rojo::rojo_bin_path();
+    let mut cmd = tokio::process::Command::new(&rojo);
+    cmd.arg("--version");
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    match cmd.output().await {
+        Ok(output) if output.status.success() => {
+            let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            passed.push(format!("Rojo runs ({version})"));
+        }
+        Ok(output) => problems.push(format!(
+            "`{rojo} --version` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+        Err(e) => problems.push(format!("Could not run Rojo at {rojo}: {e}")),
+    }
 
-    let plugin_file = plugins_path.join("Rojo.rbxm");
-    std::fs::write(&plugin_file, &bytes)?;
+    // Studio plugins are non-empty, valid .rbxm files
+    if let Ok(Some(plugins_path)) = studio_plugins_dir(config) {
+        let mut plugins = vec!["Rojo.rbxm"];
+        if !config.skip_roxlit_mcp {
+            plugins.push("Roxlit.rbxm");
+        }
+        for name in plugins {
+            let path = plugins_path.join(name);
+            match tokio::fs::read(&path).await {
+                Ok(bytes) => match rbxm::check(&bytes) {
+                    Ok(()) => passed.push(format!("{name} is a valid plugin ({} KB)", bytes.len() / 1024)),
+                    Err(problem) => problems.push(format!("{name} is not a valid .rbxm file: {problem} — reinstall the plugin")),
+                },
+                Err(e) => problems.push(format!("{name} missing from {}: {e}", plugins_path.display())),
+            }
+        }
+    }
 
-    Ok(())
+    // MCP config (only written when the MCP binary is available) parses as JSON
+    let mcp_available = config::mcp_binary_path().map(|p| p.exists()).unwrap_or(false);
+    if mcp_available {
+        let project_root = std::path::Path::new(&config.project_path);
+        if let Some(path) = context::mcp_config_path(project_root, &config.ai_tool) {
+            match std::fs::read_to_string(&path) {
+                Ok(content) => match serde_json::from_str::<serde_json::Value>(&content) {
+                    Ok(_) => passed.push("MCP config parses".into()),
+                    Err(e) => problems.push(format!("MCP config {} is invalid JSON: {e}", path.display())),
+                },
+                Err(e) => problems.push(format!("MCP config {} unreadable: {e}", path.display())),
+            }
+        }
+    }
+
+    (passed, problems)
+}
+
+/// Point aftman at the toolDir override, if one is configured.
+/// Without an override aftman uses its default `~/.aftman`.
+pub(crate) fn set_aftman_home(cmd: &mut tokio::process::Command) {
+    if config::read_config().and_then(|c| c.tool_dir).is_some() {
+        if let Some(home) = config::aftman_home() {
+            cmd.env("AFTMAN_HOME", home);
+        }
+    }
 }
 
 /// Attempts to kill all processes matching the given name.
@@ -702,3 +1259,34 @@ async fn kill_process_by_name(name: &str) {
     tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_rollback() {
+        let root = std::env::temp_dir().join(format!("roxlit-journal-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("existing.txt"), "keep").unwrap();
+        let before = dir_entries(&root);
+
+        let mut journal = InstallJournal::default();
+        journal.track(&root); // already exists — not ours
+        journal.track(root.join("existing.txt"));
+        std::fs::write(root.join("existing.txt"), "overwritten").unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("aftman.toml"), "[tools]").unwrap();
+        journal.track_new_entries(&root, &before);
+
+        let (removed, restored) = journal.rollback();
+        journal.discard_backups();
+        assert_eq!(removed.len(), 2);
+        assert_eq!(restored.len(), 1);
+        assert_eq!(std::fs::read_to_string(root.join("existing.txt")).unwrap(), "keep");
+        assert!(!root.join("src").exists());
+        assert!(!root.join("aftman.toml").exists());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
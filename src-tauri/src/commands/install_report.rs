@@ -0,0 +1,237 @@
+//! A record of each installation run: its steps, how long they took, tool
+//! versions found, warnings and errors, saved as JSON to
+//! `~/.roxlit/install-reports/<unix seconds>.json`. The event stream the UI
+//! shows is gone once the window closes; the report is what a "setup failed"
+//! issue can be debugged from, and the latest one goes into session log exports.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::ipc::Channel;
+
+use crate::commands::detect::DetectionResult;
+use crate::commands::install::SetupEvent;
+use crate::commands::logs::{format_timestamp, unix_millis};
+
+/// Reports kept; older ones are deleted.
+const KEEP_REPORTS: usize = 20;
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallReport {
+    pub app_version: String,
+    /// OS and architecture, e.g. "windows x86_64".
+    pub os: String,
+    pub ai_tool: String,
+    pub project_path: String,
+    pub started_at: String,
+    pub finished_at: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    /// The error the installation failed with.
+    pub error: Option<String>,
+    /// Tool versions found before installing, by tool name.
+    pub detected: BTreeMap<String, String>,
+    pub steps: Vec<ReportStep>,
+    pub warnings: Vec<String>,
+    pub errors: Vec<String>,
+    /// Post-install checks that passed.
+    pub verified: Vec<String>,
+    /// Paths removed by a rollback.
+    pub rolled_back: Vec<String>,
+    #[serde(skip)]
+    started: Option<Instant>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportStep {
+    pub step: String,
+    /// "running" (never finished), "completed", "failed" or "skipped".
+    pub status: String,
+    /// Step description, completion detail, error or skip reason.
+    pub detail: String,
+    pub duration_ms: Option<u64>,
+    #[serde(skip)]
+    started: Option<Instant>,
+}
+
+impl InstallReport {
+    fn new() -> Self {
+        Self {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            started_at: format_timestamp(unix_millis() / 1000),
+            started: Some(Instant::now()),
+            ..Default::default()
+        }
+    }
+
+    /// The last step named `step` that hasn't finished yet.
+    fn running_step(&mut self, step: &str) -> Option<&mut ReportStep> {
+        self.steps.iter_mut().rev().find(|s| s.step == step && s.status == "running")
+    }
+
+    /// Finishes a running step (or adds it, for errors outside any step).
+    fn finish_step(&mut self, step: &str, status: &str, detail: &str) {
+        match self.running_step(step) {
+            Some(s) => {
+                s.status = status.into();
+                s.detail = detail.into();
+                s.duration_ms = s.started.map(|t| t.elapsed().as_millis() as u64);
+            }
+            None => self.steps.push(ReportStep {
+                step: step.into(),
+                status: status.into(),
+                detail: detail.into(),
+                duration_ms: None,
+                started: None,
+            }),
+        }
+    }
+
+    fn record(&mut self, event: &SetupEvent) {
+        match event {
+            SetupEvent::StepStarted { step, description, .. } => self.steps.push(ReportStep {
+                step: step.clone(),
+                status: "running".into(),
+                detail: description.clone(),
+                duration_ms: None,
+                started: Some(Instant::now()),
+            }),
+            SetupEvent::StepCompleted { step, detail } => self.finish_step(step, "completed", detail),
+            SetupEvent::StepSkipped { step, reason } => self.finish_step(step, "skipped", reason),
+            SetupEvent::StepWarning { step, message } => self.warnings.push(format!("{step}: {message}")),
+            SetupEvent::Error { step, message } => {
+                self.errors.push(format!("{step}: {message}"));
+                self.finish_step(step, "failed", message);
+            }
+            SetupEvent::DiskLow { message, .. } => self.errors.push(message.clone()),
+            SetupEvent::Verified { checks } => self.verified = checks.clone(),
+            SetupEvent::RolledBack { removed, .. } => self.rolled_back = removed.clone(),
+            SetupEvent::StepProgress { .. } | SetupEvent::Finished => {}
+        }
+    }
+}
+
+/// The installation's event channel, recording every event it forwards into
+/// the run's report.
+pub struct SetupChannel {
+    channel: Channel<SetupEvent>,
+    report: Mutex<InstallReport>,
+}
+
+impl SetupChannel {
+    pub fn new(channel: Channel<SetupEvent>, ai_tool: &str, project_path: &str) -> Self {
+        let report = InstallReport {
+            ai_tool: ai_tool.into(),
+            project_path: project_path.into(),
+            ..InstallReport::new()
+        };
+        Self { channel, report: Mutex::new(report) }
+    }
+
+    /// Same as `Channel::send`, so install steps use it unchanged.
+    pub fn send(&self, event: SetupEvent) -> tauri::Result<()> {
+        if let Ok(mut report) = self.report.lock() {
+            report.record(&event);
+        }
+        self.channel.send(event)
+    }
+
+    /// Records the tool versions detection found.
+    pub fn record_detection(&self, detection: &DetectionResult) {
+        let Ok(mut report) = self.report.lock() else { return };
+        let found = [
+            ("aftman", &detection.aftman_version),
+            ("rojo", &detection.rojo_version),
+            ("roxlit-mcp", &detection.roxlit_mcp_version),
+        ];
+        for (tool, version) in found {
+            if let Some(version) = version {
+                report.detected.insert(tool.into(), version.clone());
+            }
+        }
+        if detection.studio_installed {
+            report.detected.insert("studio".into(), "installed".into());
+        }
+    }
+
+    /// Completes the report with the installation's outcome and saves it.
+    /// Best-effort — a report that can't be written never fails the install.
+    pub fn finish(self, result: &crate::error::Result<()>) {
+        let Ok(mut report) = self.report.into_inner() else { return };
+        report.finished_at = format_timestamp(unix_millis() / 1000);
+        report.duration_ms = report.started.map_or(0, |t| t.elapsed().as_millis() as u64);
+        report.success = result.is_ok();
+        report.error = result.as_ref().err().map(|e| e.to_string());
+        save(&report);
+    }
+}
+
+fn reports_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("install-reports"))
+}
+
+fn save(report: &InstallReport) {
+    let Some(dir) = reports_dir() else { return };
+    let Ok(json) = serde_json::to_string_pretty(report) else { return };
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    let _ = std::fs::write(dir.join(format!("{}.json", unix_millis() / 1000)), json);
+
+    for old in report_files().into_iter().rev().skip(KEEP_REPORTS) {
+        let _ = std::fs::remove_file(old);
+    }
+}
+
+/// Report files, oldest first (names are Unix timestamps).
+fn report_files() -> Vec<PathBuf> {
+    let Some(entries) = reports_dir().and_then(|d| std::fs::read_dir(d).ok()) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(u64, PathBuf)> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|p| Some((p.file_stem()?.to_str()?.parse().ok()?, p)))
+        .collect();
+    files.sort();
+    files.into_iter().map(|(_, p)| p).collect()
+}
+
+/// The most recent install report, for diagnostics exports.
+pub fn latest_report_file() -> Option<PathBuf> {
+    report_files().pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_records_steps() {
+        let mut report = InstallReport::new();
+        let started = |step: &str| SetupEvent::StepStarted {
+            step: step.into(),
+            description: format!("Installing {step}"),
+            step_index: 1,
+            total_steps: 2,
+        };
+        report.record(&SetupEvent::StepSkipped { step: "aftman".into(), reason: "already installed".into() });
+        report.record(&started("rojo"));
+        report.record(&SetupEvent::StepWarning { step: "rojo".into(), message: "slow mirror".into() });
+        report.record(&SetupEvent::StepCompleted { step: "rojo".into(), detail: "Rojo installed".into() });
+        report.record(&started("plugin"));
+        report.record(&SetupEvent::Error { step: "plugin".into(), message: "HTTP 404".into() });
+
+        let statuses: Vec<(&str, &str)> = report.steps.iter().map(|s| (s.step.as_str(), s.status.as_str())).collect();
+        assert_eq!(statuses, [("aftman", "skipped"), ("rojo", "completed"), ("plugin", "failed")]);
+        assert!(report.steps[1].duration_ms.is_some());
+        assert_eq!(report.warnings, ["rojo: slow mirror"]);
+        assert_eq!(report.errors, ["plugin: HTTP 404"]);
+    }
+}
@@ -0,0 +1,433 @@
+//! Explorer-style view of a project's instance hierarchy, so users can browse
+//! the game without digging through src/ folders. Built from the Rojo project
+//! (default.project.json plus Rojo's file naming rules), or read live from
+//! Studio when the plugin is connected to this project.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use tauri::State;
+
+use crate::commands::logs::LauncherStatus;
+use crate::commands::studio;
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Levels returned below the requested instance when no depth is given.
+const DEFAULT_DEPTH: usize = 2;
+/// Deepest tree a single call returns (keeps live Studio queries small).
+const MAX_DEPTH: usize = 10;
+/// The plugin counts as connected if it polled the launcher this recently.
+const LIVE_WINDOW: Duration = Duration::from_secs(10);
+
+/// One instance in the tree.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceNode {
+    pub name: String,
+    pub class_name: String,
+    /// Slash-separated path from the DataModel, e.g. "ReplicatedStorage/Shared".
+    #[serde(default)]
+    pub path: String,
+    /// File or folder behind this instance, relative to the project root.
+    /// None for instances that only exist in project.json or in Studio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Properties set in project.json, `.meta.json` or `.model.json` files.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub children: Vec<InstanceNode>,
+    /// Has children that were cut off by the depth limit.
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Where a returned tree came from.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeSource {
+    Files,
+    Studio,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceTree {
+    pub source: TreeSource,
+    pub root: InstanceNode,
+}
+
+/// Returns the instance at `path` (slash-separated from the DataModel, empty
+/// for the whole game) with `depth` levels of children. Reads Studio's live
+/// hierarchy when the plugin is connected to this project, else the files.
+#[tauri::command]
+pub async fn get_instance_tree(
+    project_path: String,
+    path: Option<String>,
+    depth: Option<usize>,
+    status: State<'_, LauncherStatus>,
+) -> Result<InstanceTree> {
+    let project_path = expand_tilde(&project_path);
+    let path = path.unwrap_or_default();
+    let depth = depth.unwrap_or(DEFAULT_DEPTH).min(MAX_DEPTH);
+
+    if plugin_connected(&status, &project_path).await {
+        if let Ok(root) = studio_tree(&path, depth).await {
+            return Ok(InstanceTree { source: TreeSource::Studio, root });
+        }
+    }
+
+    let tree = project_tree(Path::new(&project_path))?;
+    let mut root = find_instance(&tree, &path)
+        .ok_or_else(|| InstallerError::Custom(format!("No instance at \"{path}\" in this project")))?
+        .clone();
+    truncate(&mut root, depth);
+    Ok(InstanceTree { source: TreeSource::Files, root })
+}
+
+/// Whether the Studio plugin is connected to this project's session.
+pub(crate) async fn plugin_connected(status: &LauncherStatus, project_path: &str) -> bool {
+    let shared = status.shared();
+    let guard = shared.lock().await;
+    guard.active && guard.project_path == project_path && guard.last_plugin_seen.is_some_and(|t| t.elapsed() < LIVE_WINDOW)
+}
+
+/// Builds the full instance tree Rojo would sync from default.project.json.
+pub(crate) fn project_tree(project_root: &Path) -> Result<InstanceNode> {
+    let project_file = project_root.join("default.project.json");
+    let content = fs::read_to_string(&project_file).map_err(|e| {
+        InstallerError::Custom(format!("Could not read {}: {e}", project_file.display()))
+    })?;
+    let project: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| InstallerError::Custom(format!("Invalid default.project.json: {e}")))?;
+
+    let mut root = project_node(project_root, "game", &project["tree"]);
+    root.class_name = "DataModel".into();
+    assign_paths(&mut root, "");
+    Ok(root)
+}
+
+/// A project.json node: its `$path` (if any) mapped from disk, plus nested nodes.
+fn project_node(project_root: &Path, name: &str, node: &serde_json::Value) -> InstanceNode {
+    let mut instance = match node["$path"].as_str() {
+        Some(rel) => path_node(project_root, &project_root.join(rel), name),
+        None => None,
+    }
+    .unwrap_or_else(|| InstanceNode {
+        name: name.to_string(),
+        class_name: "Folder".into(),
+        ..Default::default()
+    });
+    if let Some(class_name) = node["$className"].as_str() {
+        instance.class_name = class_name.to_string();
+    }
+    merge_properties(&mut instance, &node["$properties"]);
+    if let Some(obj) = node.as_object() {
+        for (key, child) in obj.iter().filter(|(k, _)| !k.starts_with('$')) {
+            instance.children.retain(|c| c.name != *key);
+            instance.children.push(project_node(project_root, key, child));
+        }
+    }
+    instance.children.sort_by(|a, b| a.name.cmp(&b.name));
+    instance
+}
+
+/// Maps a file or folder to an instance named `name`.
+fn path_node(project_root: &Path, path: &Path, name: &str) -> Option<InstanceNode> {
+    let file = path
+        .strip_prefix(project_root)
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .ok();
+
+    if !path.is_dir() {
+        let file_name = path.file_name()?.to_string_lossy().to_string();
+        let (_, class_name) = classify_file(&file_name)?;
+        let mut node = InstanceNode { name: name.to_string(), class_name: class_name.into(), file, ..Default::default() };
+        if class_name == "Model" && file_name.ends_with(".model.json") {
+            apply_model_json(&mut node, path);
+        }
+        if let Some(meta) = read_json(&path.with_file_name(format!("{name}.meta.json"))) {
+            merge_properties(&mut node, &meta["properties"]);
+        }
+        return Some(node);
+    }
+
+    let mut node = InstanceNode { name: name.to_string(), class_name: "Folder".into(), file, ..Default::default() };
+    let Ok(entries) = fs::read_dir(path) else { return Some(node) };
+    for entry in entries.flatten() {
+        let entry_name = entry.file_name().to_string_lossy().to_string();
+        if entry_name.starts_with('.') || entry_name.ends_with(".meta.json") {
+            continue;
+        }
+        let entry_path = entry.path();
+        if entry_path.is_dir() {
+            if let Some(child) = path_node(project_root, &entry_path, &entry_name) {
+                node.children.push(child);
+            }
+            continue;
+        }
+        let Some((stem, class_name)) = classify_file(&entry_name) else { continue };
+        if stem == "init" {
+            // init scripts turn their folder into the script itself
+            node.class_name = class_name.into();
+            continue;
+        }
+        if let Some(child) = path_node(project_root, &entry_path, stem) {
+            node.children.push(child);
+        }
+    }
+    if let Some(meta) = read_json(&path.join("init.meta.json")) {
+        if let Some(class_name) = meta["className"].as_str() {
+            node.class_name = class_name.to_string();
+        }
+        merge_properties(&mut node, &meta["properties"]);
+    }
+    node.children.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(node)
+}
+
+/// Instance name and class for a file, following Rojo's naming rules.
+/// None for files Rojo doesn't turn into instances.
+fn classify_file(file_name: &str) -> Option<(&str, &'static str)> {
+    const RULES: &[(&str, &str)] = &[
+        (".server.luau", "Script"),
+        (".server.lua", "Script"),
+        (".client.luau", "LocalScript"),
+        (".client.lua", "LocalScript"),
+        (".luau", "ModuleScript"),
+        (".lua", "ModuleScript"),
+        (".model.json", "Model"),
+        (".project.json", "Folder"),
+        (".json", "ModuleScript"),
+        (".txt", "StringValue"),
+        (".csv", "LocalizationTable"),
+        (".rbxm", "Model"),
+        (".rbxmx", "Model"),
+    ];
+    RULES
+        .iter()
+        .find_map(|(ext, class_name)| file_name.strip_suffix(ext).map(|stem| (stem, *class_name)))
+        .filter(|(stem, _)| !stem.is_empty())
+}
+
+/// Takes the class and children from a `.model.json` file.
+fn apply_model_json(node: &mut InstanceNode, path: &Path) {
+    let Some(model) = read_json(path) else { return };
+    *node = model_node(&node.name, &model, node.file.take());
+}
+
+fn model_node(name: &str, model: &serde_json::Value, file: Option<String>) -> InstanceNode {
+    let mut children: Vec<InstanceNode> = model["Children"]
+        .as_array()
+        .map(|list| {
+            list.iter()
+                .map(|child| model_node(child["Name"].as_str().unwrap_or("Instance"), child, None))
+                .collect()
+        })
+        .unwrap_or_default();
+    children.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut node = InstanceNode {
+        name: name.to_string(),
+        class_name: model["ClassName"].as_str().unwrap_or("Model").to_string(),
+        file,
+        children,
+        ..Default::default()
+    };
+    merge_properties(&mut node, &model["Properties"]);
+    node
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Adds a Rojo properties object to the node.
+fn merge_properties(node: &mut InstanceNode, properties: &serde_json::Value) {
+    if let Some(obj) = properties.as_object() {
+        node.properties.extend(obj.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+}
+
+fn assign_paths(node: &mut InstanceNode, path: &str) {
+    node.path = path.to_string();
+    for child in &mut node.children {
+        let child_path = if path.is_empty() { child.name.clone() } else { format!("{path}/{}", child.name) };
+        assign_paths(child, &child_path);
+    }
+}
+
+/// Follows a slash-separated path down from `root`.
+pub(crate) fn find_instance<'a>(root: &'a InstanceNode, path: &str) -> Option<&'a InstanceNode> {
+    path.split('/')
+        .filter(|part| !part.is_empty())
+        .try_fold(root, |node, part| node.children.iter().find(|c| c.name == part))
+}
+
+fn truncate(node: &mut InstanceNode, depth: usize) {
+    if depth == 0 {
+        node.has_more = !node.children.is_empty();
+        node.children.clear();
+        return;
+    }
+    for child in &mut node.children {
+        truncate(child, depth - 1);
+    }
+}
+
+/// Most matches `search_instances` returns.
+const SEARCH_LIMIT: usize = 200;
+
+/// An instance found by `search_instances`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceMatch {
+    pub name: String,
+    pub class_name: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, serde_json::Value>,
+}
+
+/// One whitespace-separated term of a search query.
+#[derive(Debug, PartialEq)]
+enum QueryTerm {
+    /// `Key=Value`: a property with this value (case-insensitive).
+    Property(String, String),
+    /// Anything else: the exact class name, or part of the instance name.
+    Text(String),
+}
+
+/// Searches the project's instances by name, class name or property value.
+/// Every term must match, e.g. `Part Anchored=false` finds unanchored Parts.
+#[tauri::command]
+pub async fn search_instances(project_path: String, query: String) -> Result<Vec<InstanceMatch>> {
+    let project_path = expand_tilde(&project_path);
+    let terms = parse_query(&query);
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let tree = project_tree(Path::new(&project_path))?;
+    let mut matches = Vec::new();
+    collect_matches(&tree, &terms, &mut matches);
+    Ok(matches)
+}
+
+fn parse_query(query: &str) -> Vec<QueryTerm> {
+    query
+        .split_whitespace()
+        .map(|term| match term.split_once('=') {
+            Some((key, value)) if !key.is_empty() => QueryTerm::Property(key.to_lowercase(), value.to_lowercase()),
+            _ => QueryTerm::Text(term.to_lowercase()),
+        })
+        .collect()
+}
+
+fn collect_matches(node: &InstanceNode, terms: &[QueryTerm], out: &mut Vec<InstanceMatch>) {
+    if out.len() >= SEARCH_LIMIT {
+        return;
+    }
+    if terms.iter().all(|term| term_matches(node, term)) {
+        out.push(InstanceMatch {
+            name: node.name.clone(),
+            class_name: node.class_name.clone(),
+            path: node.path.clone(),
+            file: node.file.clone(),
+            properties: node.properties.clone(),
+        });
+    }
+    for child in &node.children {
+        collect_matches(child, terms, out);
+    }
+}
+
+fn term_matches(node: &InstanceNode, term: &QueryTerm) -> bool {
+    match term {
+        QueryTerm::Text(text) => {
+            node.class_name.to_lowercase() == *text || node.name.to_lowercase().contains(text.as_str())
+        }
+        QueryTerm::Property(key, value) => node
+            .properties
+            .iter()
+            .any(|(k, v)| k.to_lowercase() == *key && property_text(v).to_lowercase() == *value),
+    }
+}
+
+/// A property value as text. Unwraps Rojo's explicit form (`{"Bool": false}`).
+fn property_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Object(obj) if obj.len() == 1 => property_text(obj.values().next().unwrap()),
+        serde_json::Value::Array(items) => items.iter().map(property_text).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+/// Reads the hierarchy under `path` from Studio through the MCP relay.
+async fn studio_tree(path: &str, depth: usize) -> Result<InstanceNode> {
+    let code = format!(
+        r#"local HttpService = game:GetService("HttpService")
+local root = game
+for name in string.gmatch({path:?}, "[^/]+") do
+	root = root:FindFirstChild(name)
+	if not root then error("No instance at " .. {path:?}) end
+end
+local function walk(instance, depth)
+	local node = {{ name = instance.Name, className = instance.ClassName, children = {{}} }}
+	local ok, children = pcall(instance.GetChildren, instance)
+	if not ok then children = {{}} end
+	if depth > 0 then
+		for _, child in children do
+			table.insert(node.children, walk(child, depth - 1))
+		end
+	else
+		node.hasMore = #children > 0
+	end
+	return node
+end
+return HttpService:JSONEncode(walk(root, {depth}))"#
+    );
+    let result = studio::run_in_studio(&code).await?;
+    let mut root: InstanceNode = serde_json::from_str(&result)
+        .map_err(|e| InstallerError::Custom(format!("Unexpected tree from Studio: {e}")))?;
+    assign_paths(&mut root, path.trim_matches('/'));
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_file() {
+        assert_eq!(classify_file("Main.server.luau"), Some(("Main", "Script")));
+        assert_eq!(classify_file("Input.client.lua"), Some(("Input", "LocalScript")));
+        assert_eq!(classify_file("Util.luau"), Some(("Util", "ModuleScript")));
+        assert_eq!(classify_file("Door.model.json"), Some(("Door", "Model")));
+        assert_eq!(classify_file("Config.json"), Some(("Config", "ModuleScript")));
+        assert_eq!(classify_file("init.luau"), Some(("init", "ModuleScript")));
+        assert_eq!(classify_file("README.md"), None);
+    }
+
+    #[test]
+    fn test_search_terms() {
+        let terms = parse_query("Part Anchored=false");
+        assert_eq!(terms, vec![
+            QueryTerm::Text("part".into()),
+            QueryTerm::Property("anchored".into(), "false".into()),
+        ]);
+
+        let mut node = InstanceNode { name: "Floor".into(), class_name: "Part".into(), ..Default::default() };
+        node.properties.insert("Anchored".into(), serde_json::json!({ "Bool": false }));
+        assert!(terms.iter().all(|t| term_matches(&node, t)));
+
+        node.properties.insert("Anchored".into(), serde_json::json!(true));
+        assert!(!terms.iter().all(|t| term_matches(&node, t)));
+        assert!(term_matches(&node, &QueryTerm::Text("flo".into())));
+    }
+}
@@ -0,0 +1,239 @@
+//! Session log export: a trimmed summary of a session (errors and warnings
+//! with surrounding lines) as a single markdown file, or a zip with the
+//! summary and the full logs, for sharing on Discord or in an issue.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::commands::crash;
+use crate::commands::install_report;
+use crate::commands::logs::{redact_pii, LogLevel};
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Lines kept before and after each error or warning.
+const CONTEXT_LINES: usize = 3;
+/// Most recent trimmed lines kept per log in the summary.
+const MAX_SECTION_LINES: usize = 200;
+
+/// Exports a session's logs to `.roxlit/exports/` and returns the file path.
+///
+/// - `session`: `"current"` or a session id from `sessions.jsonl`
+/// - `format`: `"markdown"` (summary only) or `"zip"` (summary + full logs)
+/// - `redact`: masks UserIds and the user's home directory
+/// - `include_crashes`: adds pending crash reports (see `list_crash_reports`)
+///
+/// The latest install report (see `install_report`) is always included.
+#[tauri::command]
+pub async fn export_session_log(
+    project_path: String,
+    session: String,
+    format: String,
+    redact: Option<bool>,
+    include_crashes: Option<bool>,
+) -> Result<String> {
+    let project_path = expand_tilde(&project_path);
+    let logs_dir = Path::new(&project_path).join(".roxlit").join("logs");
+    let redact = redact.unwrap_or(false);
+    crate::commands::analytics::track_feature("export_session_log");
+
+    // The label ends up in file names, so only session ids like `12` or
+    // `current` get through, never a path.
+    if session.is_empty() || crate::commands::baseline::slug(&session) != session {
+        return Err(InstallerError::Custom(format!("Invalid session: {session}")));
+    }
+    let (label, system_file, output_file) = session_files(&logs_dir, &session);
+    let read = |path: &Path| -> String {
+        let content = std::fs::read_to_string(path).unwrap_or_default();
+        if redact {
+            redact_text(&content)
+        } else {
+            content
+        }
+    };
+    let system = read(&system_file);
+    let output = read(&output_file);
+    if system.is_empty() && output.is_empty() {
+        return Err(InstallerError::Custom(format!("No logs found for session {session}")));
+    }
+
+    let crash_files = if include_crashes.unwrap_or(false) {
+        crash::pending_report_files()
+    } else {
+        Vec::new()
+    };
+    let crashes: Vec<(String, String)> = crash_files
+        .iter()
+        .map(|p| (p.file_name().unwrap_or_default().to_string_lossy().to_string(), read(p)))
+        .collect();
+
+    let install_report = install_report::latest_report_file().map(|p| read(&p));
+
+    let started_at = session_started_at(&logs_dir, &label).unwrap_or_default();
+    let mut summary = summary_markdown(&label, &started_at, &output, &system);
+    if !crashes.is_empty() {
+        summary.push_str("\n## Crash reports\n");
+        for (name, report) in &crashes {
+            summary.push_str(&format!("\n### {name}\n\n```\n{}\n```\n", report.trim().replace("```", "'''")));
+        }
+    }
+    if let Some(report) = &install_report {
+        summary.push_str(&format!("\n## Last installation\n\n```json\n{}\n```\n", report.trim()));
+    }
+
+    let export_dir = Path::new(&project_path).join(".roxlit").join("exports");
+    std::fs::create_dir_all(&export_dir)?;
+
+    let dest = match format.as_str() {
+        "markdown" | "md" => {
+            let dest = export_dir.join(format!("session-{label}.md"));
+            std::fs::write(&dest, summary)?;
+            dest
+        }
+        "zip" => {
+            let dest = export_dir.join(format!("session-{label}.zip"));
+            let crash_names: Vec<String> = crashes.iter().map(|(name, _)| format!("crashes/{name}")).collect();
+            let mut files = vec![("summary.md", summary.as_str()), ("output.log", &output), ("system.log", &system)];
+            files.extend(crash_names.iter().zip(&crashes).map(|(name, (_, report))| (name.as_str(), report.as_str())));
+            if let Some(report) = &install_report {
+                files.push(("install-report.json", report));
+            }
+            write_zip(&dest, &files)?;
+            dest
+        }
+        other => {
+            return Err(InstallerError::Custom(format!(
+                "Unknown export format \"{other}\" (expected \"markdown\" or \"zip\")"
+            )))
+        }
+    };
+    crash::mark_exported(&crash_files);
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+/// Resolves a session to `(label, system log, output log)`. Past sessions are
+/// stored as `{id}-system.log` / `{id}-output.log`, like the MCP `get_sessions` tool.
+fn session_files(logs_dir: &Path, session: &str) -> (String, PathBuf, PathBuf) {
+    let rotated_system = logs_dir.join(format!("{session}-system.log"));
+    let rotated_output = logs_dir.join(format!("{session}-output.log"));
+    if session != "current" && (rotated_system.exists() || rotated_output.exists()) {
+        return (session.to_string(), rotated_system, rotated_output);
+    }
+    ("current".to_string(), logs_dir.join("system.log"), logs_dir.join("output.log"))
+}
+
+/// Start time of a session from the manifest; the last entry for "current".
+fn session_started_at(logs_dir: &Path, label: &str) -> Option<String> {
+    let content = std::fs::read_to_string(logs_dir.join("sessions.jsonl")).ok()?;
+    let entries: Vec<serde_json::Value> = content
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+    let entry = if label == "current" {
+        entries.last()
+    } else {
+        entries
+            .iter()
+            .find(|e| e["session_id"].as_u64().is_some_and(|id| id.to_string() == label))
+    }?;
+    entry["started_at"].as_str().map(String::from)
+}
+
+fn summary_markdown(label: &str, started_at: &str, output: &str, system: &str) -> String {
+    let mut md = String::from("# Roxlit session log\n\n");
+    md.push_str(&format!("- Session: {label}"));
+    if !started_at.is_empty() {
+        md.push_str(&format!(" (started {started_at})"));
+    }
+    md.push_str(&format!(
+        "\n- Roxlit {} on {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS
+    ));
+    for (title, content) in [("Studio output", output), ("System", system)] {
+        md.push_str(&format!("\n## {title}\n\n"));
+        let trimmed = trim_log(content, CONTEXT_LINES, MAX_SECTION_LINES);
+        if trimmed.is_empty() {
+            md.push_str("No errors or warnings.\n");
+        } else {
+            // A fence inside the log would end the code block early
+            md.push_str(&format!("```\n{}\n```\n", trimmed.replace("```", "'''")));
+        }
+    }
+    md
+}
+
+/// Keeps errors, warnings and playtest markers with `context` lines around
+/// each, marking gaps with `…`. Only the last `max_lines` lines are kept.
+fn trim_log(content: &str, context: usize, max_lines: usize) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut keep = vec![false; lines.len()];
+    for (i, line) in lines.iter().enumerate() {
+        if LogLevel::infer(line) != LogLevel::Info || line.contains("═══════") {
+            let start = i.saturating_sub(context);
+            let end = (i + context + 1).min(lines.len());
+            keep[start..end].iter_mut().for_each(|k| *k = true);
+        }
+    }
+
+    let mut out: Vec<&str> = Vec::new();
+    let mut prev = None;
+    for (i, line) in lines.iter().enumerate().filter(|(i, _)| keep[*i]) {
+        if prev.is_some_and(|p| p + 1 != i) {
+            out.push("…");
+        }
+        out.push(line);
+        prev = Some(i);
+    }
+
+    if out.len() > max_lines {
+        let omitted = out.len() - max_lines;
+        let mut tail = vec![format!("({omitted} earlier lines omitted)")];
+        tail.extend(out[omitted..].iter().map(|s| s.to_string()));
+        return tail.join("\n");
+    }
+    out.join("\n")
+}
+
+/// Masks UserIds and replaces the home directory (which contains the OS
+/// username) with `~`.
+fn redact_text(content: &str) -> String {
+    let mut out = redact_pii(content, &[]);
+    if let Some(home) = dirs::home_dir() {
+        let home = home.to_string_lossy().to_string();
+        if !home.is_empty() {
+            out = out.replace(&home, "~");
+        }
+    }
+    out
+}
+
+fn write_zip(dest: &Path, files: &[(&str, &str)]) -> Result<()> {
+    let file = std::fs::File::create(dest)?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default();
+    for (name, content) in files {
+        if content.is_empty() {
+            continue;
+        }
+        zip.start_file(*name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_log() {
+        let log = "a\nb\nc\n[ERROR] boom\nd\ne\nf\ng\nh";
+        assert_eq!(trim_log(log, 1, 100), "c\n[ERROR] boom\nd");
+        assert_eq!(trim_log("x\nwarn 1\ny\nz\nwarn 2", 0, 100), "warn 1\n…\nwarn 2");
+        assert_eq!(trim_log("warn 1\nwarn 2\nwarn 3", 0, 2), "(1 earlier lines omitted)\nwarn 2\nwarn 3");
+        assert_eq!(trim_log("all good", 3, 100), "");
+    }
+}
@@ -1,7 +1,12 @@
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::sync::{mpsc, oneshot, Mutex};
 
+use crate::commands::config::{PluginConfig, ScriptFilter};
+use crate::commands::health::{self, Metric};
+use crate::commands::ports;
+
 /// Shared state exposed to the Studio plugin via HTTP on port 19556.
 /// Updated by start_rojo/stop_rojo to reflect whether "Start Development" is active.
 pub struct LauncherStatus {
@@ -18,6 +23,39 @@ pub(crate) struct LauncherStatusInner {
     pub(crate) linked_place_id: Option<u64>,
     pub(crate) linked_universe_id: Option<u64>,
     pub(crate) linked_place_name: Option<String>,
+    /// Mask player names and UserIds in captured Studio output (opt-in setting).
+    pub(crate) redact_logs: bool,
+    /// Players currently in the playtest, reported by the plugin via POST /players.
+    pub(crate) players: Vec<PlayerInfo>,
+    /// When "Start Development" was last pressed.
+    pub(crate) started_at: Option<std::time::Instant>,
+    /// Last request from the Studio plugin (`/status` poll or log batch).
+    pub(crate) last_plugin_seen: Option<std::time::Instant>,
+    /// Write Studio output to output.log (the project's `captureLogs` setting).
+    pub(crate) capture_logs: bool,
+    /// Shared Team Create log that Studio output is also appended to.
+    pub(crate) shared_log: Option<std::path::PathBuf>,
+    /// Version the Roxlit plugin reported in its last `/status` poll.
+    pub(crate) plugin_version: Option<String>,
+    /// An update of an outdated plugin was attempted this session.
+    pub(crate) plugin_update_attempted: bool,
+    /// The plugin was replaced; Studio must restart to load it.
+    pub(crate) plugin_restart_required: bool,
+    /// Capture settings served at `GET /plugin-config`.
+    pub(crate) plugin_config: PluginConfig,
+    /// Bumped on every change, so the plugin only reapplies a new config.
+    pub(crate) plugin_config_revision: u64,
+}
+
+/// A player in the running playtest, as reported by the Studio plugin.
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PlayerInfo {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub user_id: u64,
 }
 
 impl Default for LauncherStatus {
@@ -31,6 +69,17 @@ impl Default for LauncherStatus {
                 linked_place_id: None,
                 linked_universe_id: None,
                 linked_place_name: None,
+                redact_logs: false,
+                players: Vec::new(),
+                started_at: None,
+                last_plugin_seen: None,
+                capture_logs: true,
+                shared_log: None,
+                plugin_version: None,
+                plugin_update_attempted: false,
+                plugin_restart_required: false,
+                plugin_config: PluginConfig::default(),
+                plugin_config_revision: 0,
             })),
         }
     }
@@ -44,21 +93,43 @@ impl LauncherStatus {
         guard.active = true;
         guard.project_path = project_path.to_string();
         guard.project_name = project_name.to_string();
+        guard.plugin_version = None;
+        guard.started_at = Some(std::time::Instant::now());
+        guard.last_plugin_seen = None;
 
         // Load placeId from config so the plugin can verify before connecting
         if let Some(config) = crate::commands::config::load_config().await {
+            guard.capture_logs = true;
+            guard.shared_log = None;
             if let Some(project) = config.projects.iter().find(|p| p.path == project_path) {
                 guard.linked_place_id = project.place_id;
                 guard.linked_universe_id = project.universe_id;
+                guard.capture_logs = project.settings.capture_logs();
+                guard.shared_log = project
+                    .settings
+                    .shared_log
+                    .as_deref()
+                    .map(|p| std::path::Path::new(project_path).join(crate::util::expand_tilde(p)));
             }
+            guard.redact_logs = config.redact_logs.unwrap_or(false);
+            guard.plugin_config = config.plugin_config.unwrap_or_default();
+            guard.plugin_config_revision += 1;
         }
     }
 
+    /// Changes the plugin's capture settings for the running session.
+    pub async fn set_plugin_config(&self, plugin_config: PluginConfig) {
+        let mut guard = self.inner.lock().await;
+        guard.plugin_config = plugin_config;
+        guard.plugin_config_revision += 1;
+    }
+
     /// Mark the launcher as inactive.
     pub async fn set_inactive(&self) {
         let mut guard = self.inner.lock().await;
         guard.active = false;
         guard.rojo_port = None;
+        guard.players.clear();
     }
 
     /// Get a clone of the inner Arc for passing to the log server.
@@ -176,26 +247,166 @@ pub(crate) async fn load_trackers(project_path: &str) -> Vec<TelemetryTracker> {
 // ─── Logger State ────────────────────────────────────────────────────────────
 
 /// Managed Tauri state holding the current session logger (if any).
+#[derive(Clone)]
 pub struct LoggerState {
     pub logger: Arc<Mutex<Option<SessionLogger>>>,
+    /// Recent lines from both log files, kept across sessions so the UI can
+    /// poll incrementally and resume after reconnecting.
+    pub buffer: Arc<std::sync::Mutex<LogBuffer>>,
 }
 
 impl Default for LoggerState {
     fn default() -> Self {
         Self {
             logger: Arc::new(Mutex::new(None)),
+            buffer: Arc::new(std::sync::Mutex::new(LogBuffer::default())),
+        }
+    }
+}
+
+impl LoggerState {
+    /// End the session log, if one is open (see `SessionLogger::close`).
+    pub async fn close(&self) {
+        let logger = self.logger.lock().await.take();
+        if let Some(logger) = logger {
+            logger.close().await;
+        }
+    }
+
+    /// Logs `lines` under `[prefix]` to the session's system.log, or only to
+    /// the in-memory buffer when no session is running.
+    pub async fn log_system(&self, prefix: &str, lines: &[String]) {
+        if let Some(logger) = self.logger.lock().await.as_ref() {
+            let tx = logger.system_sender();
+            for line in lines {
+                send_log(&tx, prefix, line);
+            }
+            return;
+        }
+        if let Ok(mut buffer) = self.buffer.lock() {
+            let ts = format_time_short(unix_millis());
+            for line in lines.iter().filter(|line| log_allowed(prefix, LogLevel::infer(line))) {
+                buffer.push("system", &LogLine::new(format!("{ts} [{prefix}] {line}\n")));
+            }
+        }
+    }
+}
+
+/// Lines kept in the in-memory log buffer.
+const LOG_BUFFER_CAPACITY: usize = 5000;
+
+/// A log line as stored in the in-memory buffer.
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    /// Global order in which the line was logged (see `LogLine`).
+    pub seq: u64,
+    /// Unix milliseconds when the line was written.
+    pub timestamp: u64,
+    /// "system" or "output", matching the log file.
+    pub source: String,
+    pub line: String,
+}
+
+/// Ring buffer of the most recent log lines, ordered by seq.
+#[derive(Default)]
+pub struct LogBuffer {
+    entries: std::collections::VecDeque<LogEntry>,
+    next_seq: u64,
+}
+
+impl LogBuffer {
+    pub(crate) fn push(&mut self, source: &str, line: &LogLine) {
+        let text = line.text.trim_matches('\n');
+        if text.is_empty() {
+            return;
+        }
+        if self.entries.len() == LOG_BUFFER_CAPACITY {
+            self.entries.pop_front();
         }
+        let entry = LogEntry {
+            seq: line.seq,
+            timestamp: unix_millis(),
+            source: source.to_string(),
+            line: text.to_string(),
+        };
+        // The two writer tasks drain independently, so a line can arrive
+        // after one that was logged later
+        let idx = self.entries.partition_point(|e| e.seq < line.seq);
+        self.entries.insert(idx, entry);
+        self.next_seq = self.next_seq.max(line.seq + 1);
+    }
+
+    /// Entries logged at or after `seq`, oldest first.
+    pub(crate) fn since(&self, seq: u64) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().filter(move |e| e.seq >= seq)
+    }
+
+    /// The last `n` entries, oldest first.
+    fn last(&self, n: usize) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter().skip(self.entries.len().saturating_sub(n))
+    }
+
+    /// The last `n` lines, oldest first, prefixed with their source.
+    pub fn tail(&self, n: usize) -> Vec<String> {
+        self.last(n).map(|e| format!("[{}] {}", e.source, e.line)).collect()
     }
 }
 
+/// Result of `get_logs_since`.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSlice {
+    pub entries: Vec<LogEntry>,
+    /// Pass this as `seq` on the next poll.
+    pub next_seq: u64,
+    /// True if requested lines are missing from `entries`: evicted after the
+    /// requested seq, or left out by `limit`.
+    pub missed: bool,
+}
+
+/// Returns buffered log lines with `seq >= seq` or, if no seq is given,
+/// written at or after `timestamp` (unix milliseconds). With neither, returns the
+/// whole buffer, e.g. for a view that attaches after lines were logged (a
+/// reloaded frontend, a second window). `limit` keeps only the most recent
+/// matching lines.
+#[tauri::command]
+pub async fn get_logs_since(
+    state: tauri::State<'_, LoggerState>,
+    seq: Option<u64>,
+    timestamp: Option<u64>,
+    limit: Option<usize>,
+) -> std::result::Result<LogSlice, String> {
+    let buffer = state.buffer.lock().map_err(|e| e.to_string())?;
+    let oldest = buffer.entries.front().map_or(buffer.next_seq, |e| e.seq);
+    let mut entries: Vec<LogEntry> = buffer
+        .entries
+        .iter()
+        .filter(|e| match (seq, timestamp) {
+            (Some(seq), _) => e.seq >= seq,
+            (None, Some(ts)) => e.timestamp >= ts,
+            (None, None) => true,
+        })
+        .cloned()
+        .collect();
+    let cut = limit.map_or(0, |limit| entries.len().saturating_sub(limit));
+    entries.drain(..cut);
+    Ok(LogSlice {
+        entries,
+        next_seq: buffer.next_seq,
+        missed: seq.is_some_and(|s| s < oldest) || cut > 0,
+    })
+}
+
 /// Async session logger that writes to two separate log files:
 /// - `system.log` — Roxlit infrastructure (rojo, roxlit, mcp events)
 /// - `output.log` — Studio game output (prints, warns, errors from user scripts)
 ///
 /// Uses mpsc channels so callers never block on disk I/O.
 pub struct SessionLogger {
-    system_tx: mpsc::UnboundedSender<String>,
-    output_tx: mpsc::UnboundedSender<String>,
+    system_tx: mpsc::UnboundedSender<LogLine>,
+    output_tx: mpsc::UnboundedSender<LogLine>,
+    writers: Vec<tokio::task::JoinHandle<()>>,
 }
 
 impl SessionLogger {
@@ -206,7 +417,11 @@ impl SessionLogger {
     /// - Writes session entry to `sessions.jsonl` manifest
     /// - Cleans up old sessions (keeps max 10)
     /// - Spawns background writer tasks for both files
-    pub async fn new(project_path: &str, project_name: &str) -> Option<Self> {
+    pub async fn new(
+        project_path: &str,
+        project_name: &str,
+        buffer: Arc<std::sync::Mutex<LogBuffer>>,
+    ) -> Option<Self> {
         let logs_dir = std::path::Path::new(project_path)
             .join(".roxlit")
             .join("logs");
@@ -215,6 +430,8 @@ impl SessionLogger {
             return None;
         }
 
+        reload_log_levels();
+
         let ts = unix_timestamp();
 
         // Rotate previous log files
@@ -228,6 +445,11 @@ impl SessionLogger {
             let rotated = logs_dir.join(format!("{ts}-output.log"));
             let _ = tokio::fs::rename(&output_file, &rotated).await;
         }
+        let errors_file = logs_dir.join("latest-errors.log");
+        let _ = tokio::fs::remove_file(&errors_file).await;
+        let error_log = crate::commands::config::find_project(project_path)
+            .is_some_and(|p| p.settings.error_log.unwrap_or(false))
+            .then(|| ErrorLog::new(errors_file));
         let telemetry_file = logs_dir.join("telemetry.log");
         if telemetry_file.exists() {
             let _ = tokio::fs::remove_file(&telemetry_file).await;
@@ -272,52 +494,197 @@ impl SessionLogger {
             Err(_) => return None,
         };
 
-        let (system_tx, system_rx) = mpsc::unbounded_channel::<String>();
-        let (output_tx, output_rx) = mpsc::unbounded_channel::<String>();
+        let (system_tx, system_rx) = mpsc::unbounded_channel::<LogLine>();
+        let (output_tx, output_rx) = mpsc::unbounded_channel::<LogLine>();
 
-        tokio::spawn(writer_task(sys_file, system_rx));
-        tokio::spawn(output_writer_task(out_file, logs_dir.clone(), output_rx));
+        let writers = vec![
+            tokio::spawn(writer_task(sys_file, system_rx, buffer.clone())),
+            tokio::spawn(output_writer_task(out_file, logs_dir.clone(), output_rx, buffer, error_log)),
+        ];
 
         // Write headers
         let header = format!(
             "=== Roxlit Session — {} ===\n\n",
             format_timestamp(unix_timestamp())
         );
-        let _ = system_tx.send(header.clone());
-        let _ = output_tx.send(header);
+        let _ = system_tx.send(LogLine::new(header.clone()));
+        let _ = output_tx.send(LogLine::new(header));
+
+        Some(Self { system_tx, output_tx, writers })
+    }
 
-        Some(Self { system_tx, output_tx })
+    /// End the session: both writers write every line queued so far, then the
+    /// "Session ended" footer. Senders cloned from this logger may outlive it,
+    /// so the writers are told to stop instead of waiting for the channels to
+    /// close. Gives up after `LOG_FLUSH_TIMEOUT`.
+    pub async fn close(self) {
+        let _ = self.system_tx.send(LogLine::new(CLOSE_SENTINEL.to_string()));
+        let _ = self.output_tx.send(LogLine::new(CLOSE_SENTINEL.to_string()));
+        let _ = tokio::time::timeout(LOG_FLUSH_TIMEOUT, futures_util::future::join_all(self.writers)).await;
     }
 
     /// Clone the system log sender (for rojo, roxlit, mcp events).
-    pub fn system_sender(&self) -> mpsc::UnboundedSender<String> {
+    pub fn system_sender(&self) -> mpsc::UnboundedSender<LogLine> {
         self.system_tx.clone()
     }
 
     /// Clone the output log sender (for Studio game output).
-    pub fn output_sender(&self) -> mpsc::UnboundedSender<String> {
+    pub fn output_sender(&self) -> mpsc::UnboundedSender<LogLine> {
         self.output_tx.clone()
     }
 }
 
+// ─── Log Level Filtering ─────────────────────────────────────────────────────
+// Per-prefix minimum levels from the `logLevels` setting, e.g.
+// `{"studio": "warn", "rojo": "off"}`. Prefixes without an entry log everything.
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+    /// Only valid as a minimum: drops every line.
+    Off,
+}
+
+impl LogLevel {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" | "debug" | "all" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" => Some(Self::Error),
+            "off" | "none" => Some(Self::Off),
+            _ => None,
+        }
+    }
+
+    /// Best-effort level of a free-form tool output line.
+    pub fn infer(line: &str) -> Self {
+        let lower = line.to_ascii_lowercase();
+        if lower.contains("error") || lower.contains("panic") {
+            Self::Error
+        } else if lower.contains("warn") {
+            Self::Warn
+        } else {
+            Self::Info
+        }
+    }
+}
+
+/// Active minimum levels, replaced when a session starts or settings change.
+static LOG_LEVELS: std::sync::RwLock<Option<std::collections::BTreeMap<String, LogLevel>>> =
+    std::sync::RwLock::new(None);
+
+/// Active per-script filter for Studio output, replaced with `LOG_LEVELS`.
+static SCRIPT_FILTER: std::sync::RwLock<Option<ScriptFilter>> = std::sync::RwLock::new(None);
+
+/// Load the `logLevels` and `scriptFilter` settings into the active filters.
+pub fn reload_log_levels() {
+    let config = crate::commands::config::read_config();
+    if let Ok(mut guard) = SCRIPT_FILTER.write() {
+        *guard = config.as_ref().and_then(|c| c.script_filter.clone());
+    }
+    let levels = config
+        .and_then(|c| c.log_levels)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(prefix, level)| Some((prefix, LogLevel::parse(&level)?)))
+        .collect();
+    if let Ok(mut guard) = LOG_LEVELS.write() {
+        *guard = Some(levels);
+    }
+}
+
+/// Whether a line with this prefix and level passes the configured minimum.
+/// Used for both the log files and what is streamed to the frontend.
+pub fn log_allowed(prefix: &str, level: LogLevel) -> bool {
+    let Ok(guard) = LOG_LEVELS.read() else { return true };
+    match guard.as_ref().and_then(|levels| levels.get(prefix)) {
+        Some(min) => *min != LogLevel::Off && level >= *min,
+        None => true,
+    }
+}
+
+/// Whether a Studio message passes the muted/soloed scripts, judged by its
+/// leading `[ScriptName]`. Errors are never filtered.
+fn script_allowed(filter: &ScriptFilter, message: &str, level: LogLevel) -> bool {
+    if level == LogLevel::Error {
+        return true;
+    }
+    let script = message
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(name, _)| name.trim());
+    let listed = |names: &[String]| script.is_some_and(|s| names.iter().any(|n| n.eq_ignore_ascii_case(s)));
+    if !filter.solo.is_empty() {
+        return listed(&filter.solo);
+    }
+    !listed(&filter.muted)
+}
+
 /// Format a log line with short timestamp and send it through a sender.
 /// Convenience for reader tasks that already have a cloned sender.
-pub fn send_log(tx: &mpsc::UnboundedSender<String>, prefix: &str, line: &str) {
-    let ts = format_time_short(unix_timestamp());
+/// Lines below the prefix's configured minimum level are dropped.
+pub fn send_log(tx: &mpsc::UnboundedSender<LogLine>, prefix: &str, line: &str) {
+    if !log_allowed(prefix, LogLevel::infer(line)) {
+        return;
+    }
+    let ts = format_time_short(unix_millis());
     let formatted = format!("{ts} [{prefix}] {line}\n");
-    let _ = tx.send(formatted);
+    let _ = tx.send(LogLine::new(formatted));
+}
+
+/// Global log sequence counter, shared by system.log and output.log.
+static LOG_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Seq the next logged line will get: lines logged from now on have this or higher.
+pub(crate) fn current_seq() -> u64 {
+    LOG_SEQ.load(Ordering::Relaxed)
+}
+
+/// A formatted line on its way to a log file. `seq` is taken when the line is
+/// logged, so lines can be put back in order after the async writers.
+pub struct LogLine {
+    pub seq: u64,
+    pub text: String,
+}
+
+impl LogLine {
+    pub fn new(text: String) -> Self {
+        Self {
+            seq: LOG_SEQ.fetch_add(1, Ordering::Relaxed),
+            text,
+        }
+    }
 }
 
 /// Sentinel value sent through the output channel to trigger log rotation.
 const ROTATE_SENTINEL: &str = "\0ROTATE";
+/// Sentinel value sent through both channels to end the session log.
+const CLOSE_SENTINEL: &str = "\0CLOSE";
+
+/// Longest `SessionLogger::close` waits for the log files to be written.
+const LOG_FLUSH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+/// Longest a stopping log server waits for requests already being handled.
+const DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
 
 /// Background task that receives lines from the channel and writes to disk.
-async fn writer_task(file: tokio::fs::File, mut rx: mpsc::UnboundedReceiver<String>) {
+async fn writer_task(
+    file: tokio::fs::File,
+    mut rx: mpsc::UnboundedReceiver<LogLine>,
+    buffer: Arc<std::sync::Mutex<LogBuffer>>,
+) {
     use tokio::io::AsyncWriteExt;
     let mut writer = tokio::io::BufWriter::new(file);
 
     while let Some(line) = rx.recv().await {
-        let _ = writer.write_all(line.as_bytes()).await;
+        if line.text == CLOSE_SENTINEL {
+            break;
+        }
+        if let Ok(mut b) = buffer.lock() {
+            b.push("system", &line);
+        }
+        let _ = writer.write_all(line.text.as_bytes()).await;
         let _ = writer.flush().await;
     }
 
@@ -329,19 +696,61 @@ async fn writer_task(file: tokio::fs::File, mut rx: mpsc::UnboundedReceiver<Stri
     let _ = writer.flush().await;
 }
 
+/// Most bytes kept in latest-errors.log; the oldest errors are dropped first.
+const ERROR_LOG_MAX_BYTES: usize = 16 * 1024;
+
+/// `latest-errors.log`: the session's Studio errors, newest last, rewritten
+/// on every new error so it stays small enough to poll.
+struct ErrorLog {
+    path: std::path::PathBuf,
+    lines: std::collections::VecDeque<String>,
+    bytes: usize,
+}
+
+impl ErrorLog {
+    fn new(path: std::path::PathBuf) -> Self {
+        Self { path, lines: Default::default(), bytes: 0 }
+    }
+
+    /// Keeps the line if it's a Studio error. Returns true if it was.
+    fn push(&mut self, text: &str) -> bool {
+        if !text.contains(" [ERROR] ") {
+            return false;
+        }
+        self.bytes += text.len();
+        self.lines.push_back(text.to_string());
+        while self.bytes > ERROR_LOG_MAX_BYTES && self.lines.len() > 1 {
+            if let Some(old) = self.lines.pop_front() {
+                self.bytes -= old.len();
+            }
+        }
+        true
+    }
+
+    async fn write(&self) {
+        let content: String = self.lines.iter().map(String::as_str).collect();
+        let _ = tokio::fs::write(&self.path, content).await;
+    }
+}
+
 /// Background writer for output.log that supports mid-session rotation.
 /// When it receives ROTATE_SENTINEL, it closes the current file, renames it
 /// to {timestamp}-output.log, and opens a fresh output.log.
 async fn output_writer_task(
     file: tokio::fs::File,
     logs_dir: std::path::PathBuf,
-    mut rx: mpsc::UnboundedReceiver<String>,
+    mut rx: mpsc::UnboundedReceiver<LogLine>,
+    buffer: Arc<std::sync::Mutex<LogBuffer>>,
+    mut error_log: Option<ErrorLog>,
 ) {
     use tokio::io::AsyncWriteExt;
     let mut writer = tokio::io::BufWriter::new(file);
 
     while let Some(line) = rx.recv().await {
-        if line == ROTATE_SENTINEL {
+        if line.text == CLOSE_SENTINEL {
+            break;
+        }
+        if line.text == ROTATE_SENTINEL {
             // Flush and close current file
             let _ = writer.flush().await;
             drop(writer);
@@ -385,8 +794,16 @@ async fn output_writer_task(
             continue;
         }
 
-        let _ = writer.write_all(line.as_bytes()).await;
+        if let Ok(mut b) = buffer.lock() {
+            b.push("output", &line);
+        }
+        let _ = writer.write_all(line.text.as_bytes()).await;
         let _ = writer.flush().await;
+        if let Some(errors) = error_log.as_mut() {
+            if errors.push(&line.text) {
+                errors.write().await;
+            }
+        }
     }
 
     let footer = format!(
@@ -405,9 +822,17 @@ fn unix_timestamp() -> u64 {
         .as_secs()
 }
 
+/// Get current Unix timestamp in milliseconds.
+pub(crate) fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
 /// Format a Unix timestamp as ISO 8601 UTC (e.g. "2025-06-15T10:30:00Z").
 /// No chrono dependency — pure arithmetic (inverse of update.rs::parse_iso8601_to_unix).
-fn format_timestamp(secs: u64) -> String {
+pub(crate) fn format_timestamp(secs: u64) -> String {
     let s = secs as i64;
 
     let sec = s % 60;
@@ -450,18 +875,25 @@ fn is_leap(y: i64) -> bool {
     (y % 4 == 0 && y % 100 != 0) || y % 400 == 0
 }
 
-/// Format a Unix timestamp as short time "HH:MM:SS" (UTC).
-fn format_time_short(secs: u64) -> String {
-    let s = secs as i64;
+/// Format a Unix timestamp in milliseconds as short time "HH:MM:SS.mmm" (UTC).
+pub(crate) fn format_time_short(millis: u64) -> String {
+    let ms = millis % 1000;
+    let s = (millis / 1000) as i64;
     let sec = s % 60;
     let min = (s / 60) % 60;
     let hour = (s / 3600) % 24;
-    format!("{hour:02}:{min:02}:{sec:02}")
+    format!("{hour:02}:{min:02}:{sec:02}.{ms:03}")
+}
+
+/// A running Studio log HTTP server.
+pub struct LogServer {
+    task: tokio::task::JoinHandle<()>,
+    shutdown: oneshot::Sender<()>,
 }
 
 /// Managed Tauri state for the Studio log HTTP server.
 pub struct LogServerState {
-    handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    handle: Arc<Mutex<Option<LogServer>>>,
 }
 
 impl Default for LogServerState {
@@ -473,52 +905,57 @@ impl Default for LogServerState {
 }
 
 impl LogServerState {
-    /// Store the server task handle.
-    pub async fn set_handle(&self, h: tokio::task::JoinHandle<()>) {
+    /// Store the running server.
+    pub async fn set_handle(&self, server: LogServer) {
         let mut guard = self.handle.lock().await;
-        *guard = Some(h);
+        *guard = Some(server);
     }
 
-    /// Abort the server task synchronously (for window close handler).
-    pub fn kill_sync(&self) {
-        if let Ok(mut guard) = self.handle.try_lock() {
-            if let Some(handle) = guard.take() {
-                handle.abort();
-            }
-        }
-    }
-
-    /// Abort the server task asynchronously.
+    /// Stop the server: it stops accepting connections and waits up to
+    /// `DRAIN_TIMEOUT` for the ones in flight, so the plugin's last log batch
+    /// of the session still reaches the log.
     pub async fn stop(&self) {
-        let mut guard = self.handle.lock().await;
-        if let Some(handle) = guard.take() {
-            handle.abort();
+        let server = self.handle.lock().await.take();
+        if let Some(server) = server {
+            let _ = server.shutdown.send(());
+            let _ = server.task.await;
         }
     }
 }
 
-/// Start the HTTP log server on 127.0.0.1:19556.
+/// Start the HTTP log server on 127.0.0.1:19556. There is no fallback port:
+/// the Studio plugin only looks for the launcher there.
 ///
-/// Returns `Some(JoinHandle)` on success, `None` if the port is busy (non-critical).
+/// Returns `None` if the port is taken (non-critical).
 /// The server accepts these endpoints:
 /// - `GET /health` → responds `200 ok`
-/// - `GET /status` → JSON with launcher active state, project info
+/// - `GET /status[?pluginVersion=x.y.z]` → JSON with launcher active state, project info;
+///   an outdated plugin version triggers a plugin update
+/// - `GET /plugin-config` → JSON capture settings (`PluginConfig` plus a `revision`) the plugin polls
 /// - `POST /log` → parses a JSON batch of `{message, level, timestamp}` and writes to output.log
 /// - `POST /link-place` → receives `{placeId, placeName}` from Studio plugin
+/// - `POST /players` → receives `[{name, displayName, userId}]`, the live player list used for redaction
 pub async fn start_log_server(
-    system_tx: mpsc::UnboundedSender<String>,
-    output_tx: mpsc::UnboundedSender<String>,
+    system_tx: mpsc::UnboundedSender<LogLine>,
+    output_tx: mpsc::UnboundedSender<LogLine>,
     status: Arc<Mutex<LauncherStatusInner>>,
     mcp: Arc<Mutex<McpStateInner>>,
     telemetry: Arc<Mutex<TelemetryStateInner>>,
-) -> Option<tokio::task::JoinHandle<()>> {
-    let listener = TcpListener::bind("127.0.0.1:19556").await.ok()?;
+) -> Option<LogServer> {
+    let listener = TcpListener::bind(("127.0.0.1", ports::LOG_SERVER_PORT)).await.ok()?;
 
-    let handle = tokio::spawn(async move {
+    let (shutdown, mut shutdown_rx) = oneshot::channel::<()>();
+    let task = tokio::spawn(async move {
+        let mut connections = tokio::task::JoinSet::new();
         loop {
-            let (stream, _) = match listener.accept().await {
-                Ok(conn) => conn,
-                Err(_) => continue,
+            let stream = tokio::select! {
+                _ = &mut shutdown_rx => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(_) => continue,
+                },
+                // Reap finished connections so the set doesn't grow all session
+                Some(_) = connections.join_next(), if !connections.is_empty() => continue,
             };
 
             let sys_tx = system_tx.clone();
@@ -526,20 +963,95 @@ pub async fn start_log_server(
             let status = status.clone();
             let mcp = mcp.clone();
             let telemetry = telemetry.clone();
-            tokio::spawn(async move {
+            connections.spawn(async move {
                 handle_connection(stream, sys_tx, out_tx, status, mcp, telemetry).await;
             });
         }
+
+        drop(listener);
+        let drain = async { while connections.join_next().await.is_some() {} };
+        if tokio::time::timeout(DRAIN_TIMEOUT, drain).await.is_err() {
+            send_log(
+                &system_tx,
+                "roxlit",
+                &format!("Studio log server stopped with {} request(s) unfinished", connections.len()),
+            );
+        }
+        // Dropping the set aborts whatever is still running
     });
 
-    Some(handle)
+    Some(LogServer { task, shutdown })
+}
+
+/// Roxlit plugin version released with this launcher.
+const EXPECTED_PLUGIN_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Value of a query string parameter in an HTTP request line.
+fn query_param<'a>(request_line: &'a str, key: &str) -> Option<&'a str> {
+    let target = request_line.split_whitespace().nth(1)?;
+    let query = target.split_once('?')?.1;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+}
+
+/// Body of `GET /plugin-config`: the settings plus their revision.
+fn plugin_config_json(config: &PluginConfig, revision: u64) -> String {
+    let mut value = serde_json::to_value(config).unwrap_or_default();
+    value["revision"] = revision.into();
+    value.to_string()
+}
+
+/// Records the plugin's reported version and, the first time an outdated one
+/// shows up this session, downloads the matching plugin in the background.
+fn check_plugin_version(
+    shared: &Arc<Mutex<LauncherStatusInner>>,
+    status: &mut LauncherStatusInner,
+    version: &str,
+    system_tx: &mpsc::UnboundedSender<LogLine>,
+) {
+    if status.plugin_version.as_deref() != Some(version) {
+        send_log(system_tx, "roxlit", &format!("Roxlit plugin v{version} connected"));
+        status.plugin_version = Some(version.to_string());
+    }
+    if status.plugin_update_attempted
+        || !crate::commands::update::is_newer_version(version, EXPECTED_PLUGIN_VERSION)
+    {
+        return;
+    }
+    status.plugin_update_attempted = true;
+
+    let shared = shared.clone();
+    let tx = system_tx.clone();
+    let version = version.to_string();
+    tokio::spawn(async move {
+        match crate::commands::install::update_roxlit_plugin().await {
+            Ok(_) => {
+                shared.lock().await.plugin_restart_required = true;
+                send_log(
+                    &tx,
+                    "roxlit",
+                    &format!(
+                        "Roxlit plugin v{version} is outdated; installed v{EXPECTED_PLUGIN_VERSION}. Restart Roblox Studio to load it."
+                    ),
+                );
+            }
+            Err(e) => send_log(
+                &tx,
+                "roxlit",
+                &format!("Roxlit plugin v{version} is outdated, but updating it failed: {e}"),
+            ),
+        }
+    });
 }
 
 /// Handle a single TCP connection with minimal HTTP parsing.
 async fn handle_connection(
     mut stream: tokio::net::TcpStream,
-    system_tx: mpsc::UnboundedSender<String>,
-    output_tx: mpsc::UnboundedSender<String>,
+    system_tx: mpsc::UnboundedSender<LogLine>,
+    output_tx: mpsc::UnboundedSender<LogLine>,
     status: Arc<Mutex<LauncherStatusInner>>,
     mcp: Arc<Mutex<McpStateInner>>,
     telemetry: Arc<Mutex<TelemetryStateInner>>,
@@ -606,7 +1118,11 @@ async fn handle_connection(
     }
 
     if first_line.starts_with("GET /status") {
-        let guard = status.lock().await;
+        let mut guard = status.lock().await;
+        guard.last_plugin_seen = Some(std::time::Instant::now());
+        if let Some(version) = query_param(first_line, "pluginVersion") {
+            check_plugin_version(&status, &mut guard, version, &system_tx);
+        }
         let linked_place = match guard.linked_place_id {
             Some(id) => format!("{id}"),
             None => "null".to_string(),
@@ -616,12 +1132,14 @@ async fn handle_connection(
             None => "null".to_string(),
         };
         let json = format!(
-            r#"{{"active":{},"projectPath":"{}","projectName":"{}","linkedPlaceId":{},"rojoPort":{}}}"#,
+            r#"{{"active":{},"projectPath":"{}","projectName":"{}","linkedPlaceId":{},"rojoPort":{},"pluginVersion":"{}","pluginRestartRequired":{}}}"#,
             guard.active,
             guard.project_path.replace('\\', "\\\\").replace('"', "\\\""),
             guard.project_name.replace('"', "\\\""),
             linked_place,
             rojo_port,
+            EXPECTED_PLUGIN_VERSION,
+            guard.plugin_restart_required,
         );
         let response = format!(
             "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\nAccess-Control-Allow-Origin: *\r\n\r\n{}",
@@ -632,6 +1150,20 @@ async fn handle_connection(
         return;
     }
 
+    if first_line.starts_with("GET /plugin-config") {
+        let json = {
+            let guard = status.lock().await;
+            plugin_config_json(&guard.plugin_config, guard.plugin_config_revision)
+        };
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\nAccess-Control-Allow-Origin: *\r\n\r\n{}",
+            json.len(),
+            json,
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        return;
+    }
+
     if first_line.starts_with("POST /link-place") {
         if let Some(body_start) = request.find("\r\n\r\n") {
             let body = &request[body_start + 4..];
@@ -661,10 +1193,32 @@ async fn handle_connection(
         return;
     }
 
+    if first_line.starts_with("POST /players") {
+        if let Some(body_start) = request.find("\r\n\r\n") {
+            let body = &request[body_start + 4..];
+            if let Ok(players) = serde_json::from_str::<Vec<PlayerInfo>>(body) {
+                status.lock().await.players = players;
+            }
+        }
+        let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\nAccess-Control-Allow-Origin: *\r\n\r\nok";
+        let _ = stream.write_all(response.as_bytes()).await;
+        return;
+    }
+
     if first_line.starts_with("POST /log") {
         if let Some(body_start) = request.find("\r\n\r\n") {
             let body = &request[body_start + 4..];
-            process_log_batch(&output_tx, body);
+            let (capture, players, shared_log) = {
+                let mut guard = status.lock().await;
+                guard.last_plugin_seen = Some(std::time::Instant::now());
+                (guard.capture_logs, guard.redact_logs.then(|| guard.players.clone()), guard.shared_log.clone())
+            };
+            if capture {
+                let lines = process_log_batch(&output_tx, body, players.as_deref());
+                if let Some(path) = shared_log {
+                    tokio::task::spawn_blocking(move || append_shared_log(&path, &lines));
+                }
+            }
         }
         let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\nAccess-Control-Allow-Origin: *\r\n\r\nok";
         let _ = stream.write_all(response.as_bytes()).await;
@@ -926,29 +1480,183 @@ async fn handle_connection(
 ///
 /// Studio logs use a clean format: just timestamp + message for normal output,
 /// with [ERROR] or [WARN] prefix only for errors/warnings.
-fn process_log_batch(tx: &mpsc::UnboundedSender<String>, body: &str) {
+/// With `redact` set, player names and UserIds are masked before writing.
+/// Returns the lines written.
+fn process_log_batch(tx: &mpsc::UnboundedSender<LogLine>, body: &str, redact: Option<&[PlayerInfo]>) -> Vec<String> {
     let entries: Vec<serde_json::Value> = match serde_json::from_str(body) {
         Ok(v) => v,
-        Err(_) => return,
+        Err(_) => return Vec::new(),
     };
+    // The plugin flushes buffered output in batches; its own timestamps are
+    // the real order. An entry without one borrows the previous entry's, so
+    // it stays right after the line it followed.
+    let first = entries.iter().find_map(|e| e["timestamp"].as_f64()).unwrap_or(0.0);
+    let mut keyed: Vec<(f64, usize, serde_json::Value)> = Vec::with_capacity(entries.len());
+    let mut last = first;
+    for (i, entry) in entries.into_iter().enumerate() {
+        last = entry["timestamp"].as_f64().unwrap_or(last);
+        keyed.push((last, i, entry));
+    }
+    keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    let entries: Vec<serde_json::Value> = keyed.into_iter().map(|(_, _, e)| e).collect();
+
+    let now = unix_millis();
+    if let Some(newest) = entries.iter().filter_map(|e| e["timestamp"].as_f64()).reduce(f64::max) {
+        // The plugin sends os.time() seconds or milliseconds
+        let sent_ms = if newest > 1e11 { newest } else { newest * 1000.0 } as u64;
+        health::record(Metric::BatchLag, now.saturating_sub(sent_ms));
+    }
 
-    let ts = format_time_short(unix_timestamp());
+    let ts = format_time_short(now);
+    let script_filter = SCRIPT_FILTER.read().ok().and_then(|guard| guard.clone());
+    let mut written = Vec::new();
     for entry in &entries {
         let message = entry["message"].as_str().unwrap_or("");
         let level = entry["level"].as_str().unwrap_or("info");
+        let min_level = match level {
+            "marker" => None, // Playtest markers are never filtered
+            "error" => Some(LogLevel::Error),
+            "warn" => Some(LogLevel::Warn),
+            _ => Some(LogLevel::Info),
+        };
+        // The Rojo plugin's own messages (connection state, patch warnings) get their own prefix
+        let rojo_plugin = rojo_plugin_message(message);
+        let source = if rojo_plugin.is_some() { "rojo-plugin" } else { "studio" };
+        if min_level.is_some_and(|l| !log_allowed(source, l)) {
+            continue;
+        }
+        if let (Some(filter), Some(level)) = (&script_filter, min_level) {
+            if !script_allowed(filter, message, level) {
+                continue;
+            }
+        }
+        // Redacted after the Rojo plugin's prefix comes off: this is the text written
+        let text = rojo_plugin.unwrap_or(message);
+        let redacted;
+        let text = match redact {
+            Some(players) => {
+                redacted = redact_pii(text, players);
+                redacted.as_str()
+            }
+            None => text,
+        };
 
-        let formatted = match level {
-            "marker" => format!("{ts} ═══════ {message} ═══════\n"),
-            "error" => format!("{ts} [ERROR] {message}\n"),
-            "warn" => format!("{ts} [WARN] {message}\n"),
-            _ => format!("{ts} {message}\n"),
+        let formatted = match (level, rojo_plugin.is_some()) {
+            ("error", true) => format!("{ts} [rojo-plugin] [ERROR] {text}\n"),
+            ("warn", true) => format!("{ts} [rojo-plugin] [WARN] {text}\n"),
+            (_, true) => format!("{ts} [rojo-plugin] {text}\n"),
+            _ => match level {
+                "marker" => format!("{ts} ═══════ {text} ═══════\n"),
+                "error" => format!("{ts} [ERROR] {text}\n"),
+                "warn" => format!("{ts} [WARN] {text}\n"),
+                _ => format!("{ts} {text}\n"),
+            },
         };
-        let _ = tx.send(formatted);
+        written.push(formatted.clone());
+        let _ = tx.send(LogLine::new(formatted));
     }
+    written
+}
+
+/// Prefixes the Rojo Studio plugin puts on its Output messages.
+const ROJO_PLUGIN_PREFIXES: &[&str] = &["[Rojo-Trace]", "[Rojo-Debug]", "[Rojo-Info]", "[Rojo-Warn]", "[Rojo-Error]", "[Rojo]"];
+
+/// If a Studio message came from the Rojo plugin, the message without its prefix.
+fn rojo_plugin_message(message: &str) -> Option<&str> {
+    ROJO_PLUGIN_PREFIXES
+        .iter()
+        .find_map(|prefix| message.strip_prefix(prefix))
+        .map(str::trim_start)
+}
+
+/// Appends Studio output lines to the shared Team Create log, tagged with
+/// this user's name so collaborators' output can be told apart.
+fn append_shared_log(path: &std::path::Path, lines: &[String]) {
+    use std::io::Write;
+    if lines.is_empty() {
+        return;
+    }
+    let user = std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .unwrap_or_else(|_| "unknown".into());
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) else {
+        return;
+    };
+    let tagged: String = lines.iter().map(|line| format!("[{user}] {line}")).collect();
+    // One write per batch, so lines from different machines don't interleave mid-batch
+    let _ = file.write_all(tagged.as_bytes());
+}
+
+/// Masks player names, display names and UserIds in a log message.
+/// Known players come from the plugin's live list; UserIds are also caught by
+/// pattern (`UserId: 123`, `userId=123`, `roblox.com/users/123`) so players who
+/// already left are covered.
+pub(crate) fn redact_pii(message: &str, players: &[PlayerInfo]) -> String {
+    let mut out = message.to_string();
+    for player in players {
+        if player.user_id != 0 {
+            out = replace_word(&out, &player.user_id.to_string(), "<userid>");
+        }
+        for name in [&player.name, &player.display_name] {
+            // Very short names would mask ordinary words
+            if name.chars().count() >= 3 {
+                out = replace_word(&out, name, "<player>");
+            }
+        }
+    }
+
+    // Digits following a UserId label or a profile URL
+    let mut result = String::with_capacity(out.len());
+    let mut i = 0;
+    while i < out.len() {
+        let label_len = ["userid", "users/"]
+            .iter()
+            .find(|label| out[i..].get(..label.len()).is_some_and(|s| s.eq_ignore_ascii_case(label)))
+            .map(|label| label.len());
+        let Some(label_len) = label_len else {
+            let c = out[i..].chars().next().unwrap_or(' ');
+            result.push(c);
+            i += c.len_utf8();
+            continue;
+        };
+        let mut j = i + label_len;
+        result.push_str(&out[i..j]);
+        // Separators between label and number: `:`, `=`, spaces, quotes
+        while j < out.len() && matches!(out.as_bytes()[j], b':' | b'=' | b' ' | b'"' | b'\'') {
+            result.push(out.as_bytes()[j] as char);
+            j += 1;
+        }
+        let digits = out[j..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits > 0 {
+            result.push_str("<userid>");
+            j += digits;
+        }
+        i = j;
+    }
+    result
+}
+
+/// Replaces whole-word occurrences of `needle` (not part of a longer identifier).
+fn replace_word(haystack: &str, needle: &str, replacement: &str) -> String {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut out = String::with_capacity(haystack.len());
+    let mut rest = haystack;
+    while let Some(pos) = rest.find(needle) {
+        let before_ok = !rest[..pos].chars().next_back().is_some_and(is_word);
+        let after_ok = !rest[pos + needle.len()..].chars().next().is_some_and(is_word);
+        out.push_str(&rest[..pos]);
+        out.push_str(if before_ok && after_ok { replacement } else { needle });
+        rest = &rest[pos + needle.len()..];
+    }
+    out.push_str(rest);
+    out
 }
 
 /// Delete rotated log files older than 7 days. Also cleans up legacy `session-*.log` files.
-async fn cleanup_old_sessions(logs_dir: &std::path::Path) {
+pub(crate) async fn cleanup_old_sessions(logs_dir: &std::path::Path) {
     let mut entries = match tokio::fs::read_dir(logs_dir).await {
         Ok(rd) => rd,
         Err(_) => return,
@@ -1073,3 +1781,97 @@ async fn cleanup_session_manifest(
     };
     let _ = tokio::fs::write(&manifest, new_content).await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_buffer_eviction() {
+        let mut buffer = LogBuffer::default();
+        for i in 0..LOG_BUFFER_CAPACITY as u64 + 2 {
+            buffer.push("system", &LogLine { seq: i, text: format!("line {i}\n") });
+        }
+        assert_eq!(buffer.entries.front().map(|e| e.seq), Some(2));
+        assert_eq!(buffer.tail(1), [format!("[system] line {}", LOG_BUFFER_CAPACITY + 1)]);
+    }
+
+    #[test]
+    fn test_rojo_plugin_message() {
+        assert_eq!(rojo_plugin_message("[Rojo-Warn] Patch could not be applied"), Some("Patch could not be applied"));
+        assert_eq!(rojo_plugin_message("[Rojo-Info] Connected to 127.0.0.1:34872"), Some("Connected to 127.0.0.1:34872"));
+        assert_eq!(rojo_plugin_message("[Combat] Rojo-like message"), None);
+    }
+
+    #[test]
+    fn test_script_allowed() {
+        let muted = ScriptFilter { muted: vec!["Spammy".into()], ..Default::default() };
+        assert!(!script_allowed(&muted, "[spammy] tick 42", LogLevel::Info));
+        assert!(script_allowed(&muted, "[spammy] failed to load", LogLevel::Error));
+        assert!(script_allowed(&muted, "[Combat] hit", LogLevel::Info));
+        assert!(script_allowed(&muted, "no prefix", LogLevel::Warn));
+
+        let solo = ScriptFilter { solo: vec!["Combat".into()], ..muted };
+        assert!(script_allowed(&solo, "[Combat] hit", LogLevel::Warn));
+        assert!(!script_allowed(&solo, "[Inventory] opened", LogLevel::Info));
+        assert!(!script_allowed(&solo, "no prefix", LogLevel::Info));
+    }
+
+    #[test]
+    fn test_plugin_config_json() {
+        let config = PluginConfig { batch_interval_ms: Some(250), paused: true, ..Default::default() };
+        let json: serde_json::Value = serde_json::from_str(&plugin_config_json(&config, 3)).unwrap();
+        assert_eq!(json, serde_json::json!({ "batchIntervalMs": 250, "paused": true, "metrics": false, "revision": 3 }));
+    }
+
+    #[test]
+    fn test_query_param() {
+        let line = "GET /status?pluginVersion=0.12.0&x=1 HTTP/1.1";
+        assert_eq!(query_param(line, "pluginVersion"), Some("0.12.0"));
+        assert_eq!(query_param(line, "x"), Some("1"));
+        assert_eq!(query_param("GET /status HTTP/1.1", "pluginVersion"), None);
+    }
+
+    #[test]
+    fn test_error_log_cap() {
+        let mut errors = ErrorLog::new(std::path::PathBuf::from("latest-errors.log"));
+        assert!(!errors.push("12:00:00 hello\n"));
+        let line = format!("12:00:01 [ERROR] {}\n", "x".repeat(1000));
+        for _ in 0..40 {
+            assert!(errors.push(&line));
+        }
+        assert!(errors.bytes <= ERROR_LOG_MAX_BYTES);
+        assert_eq!(errors.lines.len(), ERROR_LOG_MAX_BYTES / line.len());
+    }
+
+    #[test]
+    fn test_redact_pii() {
+        let players = vec![PlayerInfo {
+            name: "builderman".into(),
+            display_name: "Builder".into(),
+            user_id: 156,
+        }];
+        assert_eq!(
+            redact_pii("builderman (156) joined, Builder says hi", &players),
+            "<player> (<userid>) joined, <player> says hi"
+        );
+        // Part of a longer word is left alone
+        assert_eq!(redact_pii("Builders unite", &players), "Builders unite");
+        // UserIds of players not in the live list are caught by pattern
+        assert_eq!(
+            redact_pii("Saved data for UserId: 987654", &[]),
+            "Saved data for UserId: <userid>"
+        );
+        assert_eq!(
+            redact_pii("see https://www.roblox.com/users/42/profile", &[]),
+            "see https://www.roblox.com/users/<userid>/profile"
+        );
+
+        // Rojo plugin messages are redacted too
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let body = r#"[{"message":"[Rojo-Warn] builderman's patch was rejected","level":"warn"}]"#;
+        let written = process_log_batch(&tx, body, Some(&players));
+        assert_eq!(written.len(), 1);
+        assert!(written[0].ends_with("[rojo-plugin] [WARN] <player>'s patch was rejected\n"));
+    }
+}
@@ -0,0 +1,138 @@
+//! Weekly maintenance, run in the background unless the `maintenance` setting
+//! is off: trims each project's backups to its size limit, deletes old session
+//! logs and place builds, refreshes the context packs, and updates the Rojo
+//! plugin if it no longer matches the pinned Rojo. Nothing here discards
+//! data it can't tell is disposable or replaces the Roxlit plugin behind the
+//! user's back; those stay user actions. One summary line goes to the
+//! session log (the in-memory log outside a session), the details after it.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::commands::config::{self, ProjectEntry};
+use crate::commands::logs::{unix_millis, LoggerState};
+use crate::commands::{backup, context, install};
+
+/// Time between two runs.
+const INTERVAL_SECS: u64 = 7 * 24 * 3600;
+/// Wait after launch, to stay out of the way of startup and the first session.
+const STARTUP_DELAY: Duration = Duration::from_secs(2 * 60);
+/// How often a long-running launcher checks whether a run is due.
+const CHECK_EVERY: Duration = Duration::from_secs(6 * 3600);
+/// Place builds in `.roxlit/build` older than this are deleted (`rojo build`
+/// makes a new one whenever one is needed).
+const PLACE_BUILD_RETENTION: Duration = Duration::from_secs(30 * 24 * 3600);
+
+/// Runs maintenance shortly after startup if a week has passed since the
+/// last run, then checks again every few hours while the launcher stays open.
+pub async fn run_when_due(logger_state: LoggerState) {
+    tokio::time::sleep(STARTUP_DELAY).await;
+    loop {
+        let config = config::read_config().unwrap_or_default();
+        let now = unix_millis() / 1000;
+        let due = config.last_maintenance.is_none_or(|last| now.saturating_sub(last) >= INTERVAL_SECS);
+        if config.maintenance.unwrap_or(true) && due {
+            let lines = run().await;
+            logger_state.log_system("maintenance", &lines).await;
+        }
+        tokio::time::sleep(CHECK_EVERY).await;
+    }
+}
+
+/// Runs maintenance now, whether or not it's due. Returns the summary line
+/// followed by what each step did.
+#[tauri::command]
+pub async fn run_maintenance(state: tauri::State<'_, LoggerState>) -> crate::error::Result<Vec<String>> {
+    let lines = run().await;
+    state.log_system("maintenance", &lines).await;
+    Ok(lines)
+}
+
+async fn run() -> Vec<String> {
+    let projects: Vec<ProjectEntry> = config::read_config()
+        .map(|c| c.projects)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| !p.missing && Path::new(&p.path).is_dir())
+        .collect();
+
+    let mut details = Vec::new();
+    let mut freed: u64 = 0;
+    let mut problems = 0;
+    for project in &projects {
+        let root = Path::new(&project.path);
+
+        if root.join(".git").exists() {
+            let path = project.path.clone();
+            let limit = project.settings.backup_limit_mb.unwrap_or(100).saturating_mul(1024 * 1024);
+            let (before, after) = tokio::task::spawn_blocking(move || {
+                let before = backup::total_stash_size(&path);
+                backup::cleanup_by_size(&path, limit);
+                (before, backup::total_stash_size(&path))
+            })
+            .await
+            .unwrap_or_default();
+            if after < before {
+                freed += before - after;
+                details.push(format!("{}: pruned backups to {} MB", project.name, after / (1024 * 1024)));
+            }
+        }
+
+        crate::commands::logs::cleanup_old_sessions(&root.join(".roxlit").join("logs")).await;
+
+        let (removed, bytes) = remove_old_place_builds(root);
+        if removed > 0 {
+            freed += bytes;
+            details.push(format!("{}: deleted {removed} old place build(s)", project.name));
+        }
+
+        if let Err(e) = context::write_context_packs(root).and_then(|_| context::sync_custom_packs(root)) {
+            problems += 1;
+            details.push(format!("{}: couldn't refresh context packs: {e}", project.name));
+        }
+    }
+
+    match install::refresh_rojo_plugin(None).await {
+        Ok(Some(version)) => details.push(format!("Updated the Rojo plugin to {version}")),
+        Ok(None) => {}
+        Err(e) => {
+            problems += 1;
+            details.push(format!("Couldn't update the Rojo plugin: {e}"));
+        }
+    }
+
+    let _ = config::save_last_maintenance(unix_millis() / 1000);
+
+    let mut summary = format!(
+        "Weekly maintenance: {} project(s) checked, {} MB freed",
+        projects.len(),
+        freed / (1024 * 1024)
+    );
+    if problems > 0 {
+        summary.push_str(&format!(", {problems} problem(s)"));
+    }
+    let mut lines = vec![summary];
+    lines.extend(details);
+    lines
+}
+
+/// Deletes place builds not written for `PLACE_BUILD_RETENTION`. Baseline
+/// places (`.roxlit/baselines`) are kept. Returns how many and their size.
+fn remove_old_place_builds(root: &Path) -> (usize, u64) {
+    let mut removed = 0;
+    let mut bytes = 0;
+    for entry in std::fs::read_dir(root.join(".roxlit").join("build")).into_iter().flatten().flatten() {
+        let Ok(metadata) = entry.metadata() else { continue };
+        let old = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age > PLACE_BUILD_RETENTION);
+        let is_place = entry.path().extension().is_some_and(|e| e == "rbxl" || e == "rbxlx");
+        if is_place && old && std::fs::remove_file(entry.path()).is_ok() {
+            removed += 1;
+            bytes += metadata.len();
+        }
+    }
+    (removed, bytes)
+}
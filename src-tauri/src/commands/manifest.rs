@@ -0,0 +1,170 @@
+//! Optional project manifest (`roxlit.toml` in the project root): the
+//! Roxlit-specific settings of a project, versioned with it so the whole team
+//! shares them. When present it is authoritative: start_rojo copies it into
+//! the project's entry in `~/.roxlit/config.json`, and saving the project
+//! settings from the launcher writes them back to it.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::commands::config::{self, ProjectSettings};
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+pub const MANIFEST_FILE: &str = "roxlit.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoxlitManifest {
+    #[serde(default)]
+    pub project: ManifestProject,
+    /// Same keys as the launcher's project settings (`autoOpenStudio`,
+    /// `captureLogs`, `mcpConfig`, `compactContext`, `contextExtra`), except
+    /// the machine-local ones (see `shared`).
+    #[serde(default)]
+    pub settings: ProjectSettings,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestProject {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Template the project was created from (informational).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// AI tool the context files are generated for (`claude`, `cursor`, ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_tool: Option<String>,
+}
+
+fn manifest_path(project_path: &str) -> PathBuf {
+    Path::new(project_path).join(MANIFEST_FILE)
+}
+
+/// `settings` without what stays on this machine: `env` changes how the
+/// programs Roxlit runs behave (`LD_PRELOAD`, `PATH`, ...) and `sharedLog`
+/// names a file Studio output is appended to, so anyone able to commit to the
+/// project could run code on, or write files of, every teammate's machine.
+fn shared(settings: &ProjectSettings) -> ProjectSettings {
+    ProjectSettings { env: None, shared_log: None, ..settings.clone() }
+}
+
+/// The manifest's settings with this machine's local ones kept from `local`.
+fn with_local(manifest: &ProjectSettings, local: &ProjectSettings) -> ProjectSettings {
+    ProjectSettings {
+        env: local.env.clone(),
+        shared_log: local.shared_log.clone(),
+        ..shared(manifest)
+    }
+}
+
+/// Reads the project's `roxlit.toml`. `Ok(None)` when there is none.
+pub fn read_manifest(project_path: &str) -> Result<Option<RoxlitManifest>> {
+    let path = manifest_path(project_path);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| InstallerError::Custom(format!("Invalid {}: {e}", path.display())))
+}
+
+pub fn write_manifest(project_path: &str, manifest: &RoxlitManifest) -> Result<()> {
+    let content = toml::to_string_pretty(manifest).map_err(|e| InstallerError::Custom(e.to_string()))?;
+    std::fs::write(
+        manifest_path(project_path),
+        format!("# Roxlit project settings (shared with everyone working on this project)\n\n{content}"),
+    )?;
+    Ok(())
+}
+
+/// Copies `roxlit.toml` (if any) into the project's config entry. Called by
+/// start_rojo before anything reads the project settings; an error leaves the
+/// config entry untouched.
+pub async fn sync_to_config(project_path: &str) -> Result<()> {
+    let Some(manifest) = read_manifest(project_path)? else {
+        return Ok(());
+    };
+    let Some(mut entry) = config::find_project(project_path) else {
+        return Ok(());
+    };
+    let settings = with_local(&manifest.settings, &entry.settings);
+    if entry.settings != settings {
+        config::save_project_settings(project_path.to_string(), settings).await?;
+    }
+    if let Some(ai_tool) = manifest.project.ai_tool.filter(|t| *t != entry.ai_tool) {
+        entry.ai_tool = ai_tool;
+        config::save_project(entry).await?;
+    }
+    Ok(())
+}
+
+/// Writes the project's settings to `roxlit.toml`, if the project has one.
+pub fn sync_from_config(project_path: &str, settings: &ProjectSettings) -> Result<()> {
+    let Some(mut manifest) = read_manifest(project_path)? else {
+        return Ok(());
+    };
+    let settings = shared(settings);
+    if manifest.settings != settings {
+        manifest.settings = settings;
+        write_manifest(project_path, &manifest)?;
+    }
+    Ok(())
+}
+
+/// Creates `roxlit.toml` from the project's current config entry, so its
+/// settings can be committed with the project. Returns the file path.
+#[tauri::command]
+pub async fn init_project_manifest(project_path: String, template: Option<String>) -> Result<String> {
+    let project_path = expand_tilde(&project_path);
+    let path = manifest_path(&project_path);
+    if path.exists() {
+        return Err(InstallerError::Custom(format!("{} already exists", path.display())));
+    }
+    let entry = config::find_project(&project_path);
+    let manifest = RoxlitManifest {
+        project: ManifestProject {
+            name: entry.as_ref().map(|p| p.name.clone()),
+            template,
+            ai_tool: entry.as_ref().map(|p| p.ai_tool.clone()),
+        },
+        settings: entry.map(|p| shared(&p.settings)).unwrap_or_default(),
+    };
+    write_manifest(&project_path, &manifest)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let manifest: RoxlitManifest = toml::from_str(
+            "[project]\nname = \"Obby\"\naiTool = \"cursor\"\n\n[settings]\ncaptureLogs = false\n",
+        )
+        .unwrap();
+        assert_eq!(manifest.project.ai_tool.as_deref(), Some("cursor"));
+        assert!(!manifest.settings.capture_logs());
+        assert!(manifest.settings.auto_open_studio());
+
+        let text = toml::to_string_pretty(&manifest).unwrap();
+        assert_eq!(toml::from_str::<RoxlitManifest>(&text).unwrap(), manifest);
+
+        assert_eq!(toml::from_str::<RoxlitManifest>("").unwrap(), RoxlitManifest::default());
+    }
+
+    #[test]
+    fn test_local_settings_stay_local() {
+        let manifest: RoxlitManifest =
+            toml::from_str("[settings]\ncaptureLogs = false\n\n[settings.env]\nLD_PRELOAD = \"/tmp/x.so\"\n").unwrap();
+        let local = ProjectSettings {
+            env: Some([("RUST_LOG".to_string(), "debug".to_string())].into()),
+            ..Default::default()
+        };
+        let merged = with_local(&manifest.settings, &local);
+        assert!(!merged.capture_logs());
+        assert_eq!(merged.env, local.env);
+        assert_eq!(shared(&local).env, None);
+    }
+}
@@ -1,9 +1,40 @@
+pub mod adopt;
+pub mod aftman;
+pub mod analytics;
 pub mod backup;
+pub mod baseline;
+pub mod bulk;
+pub mod classroom;
+pub mod cleanup;
 pub mod config;
+pub mod config_backup;
+pub mod crash;
 pub mod detect;
+pub mod disk;
+pub mod downloader;
+pub mod editors;
+pub mod first_sync;
+pub mod health;
 pub mod install;
+pub mod install_report;
+pub mod instances;
+pub mod log_export;
 pub mod logs;
+pub mod maintenance;
+pub mod manifest;
+pub mod notifications;
+pub mod playtest;
+pub mod ports;
+pub mod preview;
+pub mod project;
+pub mod project_json;
+pub mod rbxm;
 pub mod rojo;
+pub mod scripts;
+pub mod session_lock;
+pub mod smoke_test;
+pub mod studio;
+pub mod sync_history;
+pub mod terminal;
 pub mod update;
 mod context;
-mod project;
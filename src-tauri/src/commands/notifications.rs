@@ -0,0 +1,42 @@
+//! Desktop notifications for events worth knowing about while the launcher
+//! isn't in front (window unfocused, minimized or hidden): Studio
+//! disconnecting, an automated playtest starting to fail, an update ready to
+//! install. Each category can be turned off in the `notifications` setting;
+//! all are on by default. With the window focused the in-app banner is enough.
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// Studio closed or crashed during a session (`RojoEvent::StudioLost`).
+pub const STUDIO_DISCONNECTED: &str = "studioDisconnected";
+/// An automated playtest has errors after one that passed.
+pub const PLAYTEST_ERRORS: &str = "playtestErrors";
+/// A newer release is available, or its installer has been downloaded.
+pub const UPDATE_READY: &str = "updateReady";
+
+fn enabled(category: &str) -> bool {
+    crate::commands::config::read_config()
+        .and_then(|c| c.notifications)
+        .and_then(|categories| categories.get(category).copied())
+        .unwrap_or(true)
+}
+
+/// The main window isn't where the user is looking. No window counts as
+/// in the background.
+fn in_background(app: &AppHandle) -> bool {
+    let Some(window) = app.get_webview_window("main") else {
+        return true;
+    };
+    !window.is_focused().unwrap_or(false)
+        || window.is_minimized().unwrap_or(false)
+        || !window.is_visible().unwrap_or(true)
+}
+
+/// Shows an OS notification if `category` is on and the launcher is in the
+/// background. Best-effort: a notification that can't be shown is dropped.
+pub(crate) fn notify(app: &AppHandle, category: &str, title: &str, body: &str) {
+    if !enabled(category) || !in_background(app) {
+        return;
+    }
+    let _ = app.notification().builder().title(title).body(body).show();
+}
@@ -0,0 +1,403 @@
+//! Playtests started from the launcher: runs the place in Studio (Run mode,
+//! through the same MCP relay as `run_code`) for a set time, then sorts the
+//! Studio output captured meanwhile into errors, warnings and prints per
+//! script, so the UI can show "last test: 2 errors" without the AI. Results
+//! are kept in `.roxlit/test-results/`.
+//!
+//! A project can also schedule playtests (its `playtestSchedule` setting):
+//! daily at a set time or after a number of syncs, while a session runs. A
+//! scheduled test that errors after the previous test passed is reported
+//! with a `playtestRegression` event.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::ipc::Channel;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::commands::logs::{
+    current_seq, format_timestamp, send_log, unix_millis, LauncherStatus, LauncherStatusInner, LogBuffer, LogLine,
+    LoggerState,
+};
+use crate::commands::notifications;
+use crate::commands::rojo::RojoEvent;
+use crate::commands::studio::run_in_studio;
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Test length when none is given.
+const DEFAULT_DURATION_SECS: u64 = 10;
+const MAX_DURATION_SECS: u64 = 300;
+/// The plugin must have been in touch this recently to start a test.
+const PLUGIN_SEEN_WITHIN: Duration = Duration::from_secs(30);
+/// Time for the plugin's last log batch to arrive after the test stops.
+const FLUSH_WAIT: Duration = Duration::from_secs(3);
+/// Results kept in `.roxlit/test-results/`, oldest removed first.
+const MAX_RESULTS: usize = 50;
+/// How often the scheduler checks whether a test is due.
+const SCHEDULE_CHECK: Duration = Duration::from_secs(30);
+
+const START_RUN: &str = r#"local RunService = game:GetService("RunService")
+if RunService:IsRunning() then
+    error("A playtest is already running in Studio")
+end
+RunService:Run()
+return "running""#;
+const STOP_RUN: &str = r#"game:GetService("RunService"):Stop()
+return "stopped""#;
+
+/// One line of Studio output during the test.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaytestLine {
+    /// "error", "warn" or "info".
+    pub level: String,
+    /// Script the line came from, when it says: `Path.To.Script:12:` in
+    /// errors, or a leading `[ScriptName]` in prints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    pub message: String,
+}
+
+/// Output counts for one script.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptCounts {
+    pub script: String,
+    pub errors: usize,
+    pub warnings: usize,
+    pub prints: usize,
+}
+
+/// When a project runs playtests by itself (its `playtestSchedule` setting).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaytestSchedule {
+    /// Daily at this UTC time, "HH:MM" (the UI converts from local time).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<String>,
+    /// After this many Rojo syncs since the last scheduled test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_syncs: Option<u32>,
+    /// Test length in seconds (default 10).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaytestResult {
+    /// ISO 8601.
+    pub started_at: String,
+    /// "manual", "scheduled" or "syncs".
+    #[serde(default)]
+    pub trigger: String,
+    pub duration_secs: u64,
+    pub errors: usize,
+    pub warnings: usize,
+    pub prints: usize,
+    /// Scripts with output, most errors first. Lines from no known script aren't counted here.
+    pub scripts: Vec<ScriptCounts>,
+    pub lines: Vec<PlaytestLine>,
+}
+
+fn results_dir(project_root: &Path) -> PathBuf {
+    project_root.join(".roxlit").join("test-results")
+}
+
+/// Saved results of a project, newest first.
+fn read_results(project_root: &Path, limit: usize) -> Vec<PlaytestResult> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(results_dir(project_root))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .collect();
+    // Named by start time in unix milliseconds
+    files.sort_by(|a, b| b.cmp(a));
+    files
+        .iter()
+        .filter_map(|p| serde_json::from_str(&std::fs::read_to_string(p).ok()?).ok())
+        .take(limit)
+        .collect()
+}
+
+fn save_result(project_root: &Path, started_millis: u64, result: &PlaytestResult) {
+    let dir = results_dir(project_root);
+    let _ = std::fs::create_dir_all(&dir);
+    if let Ok(json) = serde_json::to_string_pretty(result) {
+        let _ = std::fs::write(dir.join(format!("{started_millis:015}.json")), json);
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir).into_iter().flatten().flatten().map(|e| e.path()).collect();
+    if files.len() > MAX_RESULTS {
+        files.sort();
+        for old in &files[..files.len() - MAX_RESULTS] {
+            let _ = std::fs::remove_file(old);
+        }
+    }
+}
+
+/// Runs the place in Studio for `duration` seconds (default 10) and returns
+/// what it printed. Needs a running session with the Studio plugin connected.
+#[tauri::command]
+pub async fn run_playtest(
+    duration: Option<u64>,
+    status: tauri::State<'_, LauncherStatus>,
+    logger: tauri::State<'_, LoggerState>,
+) -> Result<PlaytestResult> {
+    crate::commands::analytics::track_feature("run_playtest");
+    playtest(&status.shared(), &logger.buffer, duration, "manual").await
+}
+
+pub(crate) async fn playtest(
+    status: &Mutex<LauncherStatusInner>,
+    buffer: &std::sync::Mutex<LogBuffer>,
+    duration: Option<u64>,
+    trigger: &str,
+) -> Result<PlaytestResult> {
+    let duration_secs = duration.unwrap_or(DEFAULT_DURATION_SECS).clamp(1, MAX_DURATION_SECS);
+    let project_path = {
+        let guard = status.lock().await;
+        if !guard.active {
+            return Err(InstallerError::Custom("Start Development before running a playtest".into()));
+        }
+        if guard.last_plugin_seen.is_none_or(|seen| seen.elapsed() >= PLUGIN_SEEN_WITHIN) {
+            return Err(InstallerError::Custom(
+                "The Studio plugin isn't connected. Open the place in Roblox Studio first.".into(),
+            ));
+        }
+        guard.project_path.clone()
+    };
+
+    let started_millis = unix_millis();
+    let started_at = format_timestamp(started_millis / 1000);
+    let first_seq = current_seq();
+    run_in_studio(START_RUN).await?;
+    tokio::time::sleep(Duration::from_secs(duration_secs)).await;
+    let stopped = run_in_studio(STOP_RUN).await;
+    tokio::time::sleep(FLUSH_WAIT).await;
+
+    let lines: Vec<PlaytestLine> = {
+        let buffer = buffer.lock().map_err(|e| InstallerError::Custom(e.to_string()))?;
+        buffer
+            .since(first_seq)
+            .filter(|e| e.source == "output")
+            .filter_map(|e| classify(&e.line))
+            .collect()
+    };
+    // Report the output even if stopping failed, then the failure
+    stopped?;
+
+    let result = summarize(started_at, trigger, duration_secs, lines);
+    save_result(Path::new(&project_path), started_millis, &result);
+    Ok(result)
+}
+
+/// The last playtest run from the launcher in a project, if any.
+#[tauri::command]
+pub async fn get_last_playtest(project_path: String) -> Result<Option<PlaytestResult>> {
+    Ok(read_results(Path::new(&expand_tilde(&project_path)), 1).pop())
+}
+
+/// Saved playtest results of a project, newest first (at most `limit`, default all).
+#[tauri::command]
+pub async fn list_playtest_results(project_path: String, limit: Option<usize>) -> Result<Vec<PlaytestResult>> {
+    Ok(read_results(Path::new(&expand_tilde(&project_path)), limit.unwrap_or(MAX_RESULTS)))
+}
+
+/// Minutes after UTC midnight from "HH:MM".
+fn parse_at(at: &str) -> Option<u64> {
+    let (hours, minutes) = at.trim().split_once(':')?;
+    let (hours, minutes): (u64, u64) = (hours.parse().ok()?, minutes.parse().ok()?);
+    (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+}
+
+/// Day (days since the Unix epoch, UTC) of the project's last timed playtest,
+/// so a restarted session doesn't run the day's test again.
+fn last_scheduled_day(project_root: &Path) -> Option<u64> {
+    let today = unix_millis() / 1000 / 86400;
+    let today_date = format_timestamp(today * 86400);
+    read_results(project_root, MAX_RESULTS)
+        .iter()
+        .any(|r| r.trigger == "scheduled" && r.started_at.get(..10) == today_date.get(..10))
+        .then_some(today)
+}
+
+/// Runs the project's scheduled playtests until aborted (by stop_rojo).
+/// `syncs` counts the session's Rojo syncs. None if nothing is scheduled.
+pub(crate) fn spawn_scheduler(
+    app: tauri::AppHandle,
+    schedule: &PlaytestSchedule,
+    status: Arc<Mutex<LauncherStatusInner>>,
+    buffer: Arc<std::sync::Mutex<LogBuffer>>,
+    syncs: Arc<AtomicU64>,
+    on_event: Channel<RojoEvent>,
+    log_tx: Option<mpsc::UnboundedSender<LogLine>>,
+) -> Option<tokio::task::JoinHandle<()>> {
+    let at = schedule.at.as_deref().and_then(parse_at);
+    let after_syncs = schedule.after_syncs.filter(|n| *n > 0).map(u64::from);
+    if at.is_none() && after_syncs.is_none() {
+        return None;
+    }
+    let duration = schedule.duration_secs;
+
+    Some(tokio::spawn(async move {
+        let mut syncs_at_last_run = syncs.load(Ordering::Relaxed);
+        let project_path = status.lock().await.project_path.clone();
+        let mut last_timed_day = last_scheduled_day(Path::new(&project_path));
+        loop {
+            tokio::time::sleep(SCHEDULE_CHECK).await;
+            let now = unix_millis() / 1000;
+            let today = now / 86400;
+            // Due from `at` on, so a test running across that minute doesn't skip the day
+            let trigger = if at.is_some_and(|at| now % 86400 / 60 >= at) && last_timed_day != Some(today) {
+                last_timed_day = Some(today);
+                "scheduled"
+            } else if after_syncs.is_some_and(|n| syncs.load(Ordering::Relaxed) - syncs_at_last_run >= n) {
+                "syncs"
+            } else {
+                continue;
+            };
+
+            let project_path = status.lock().await.project_path.clone();
+            let previous = read_results(Path::new(&project_path), 1).pop();
+            let outcome = playtest(&status, &buffer, duration, trigger).await;
+            syncs_at_last_run = syncs.load(Ordering::Relaxed);
+            let result = match outcome {
+                Ok(result) => result,
+                Err(e) => {
+                    if let Some(tx) = &log_tx {
+                        send_log(tx, "roxlit", &format!("Automated playtest skipped: {e}"));
+                    }
+                    continue;
+                }
+            };
+            if let Some(tx) = &log_tx {
+                send_log(
+                    tx,
+                    "roxlit",
+                    &format!(
+                        "Automated playtest: {} error(s), {} warning(s), {} print(s)",
+                        result.errors, result.warnings, result.prints
+                    ),
+                );
+            }
+            if result.errors > 0 && previous.is_some_and(|p| p.errors == 0) {
+                let scripts: Vec<String> =
+                    result.scripts.iter().filter(|s| s.errors > 0).map(|s| s.script.clone()).collect();
+                let message = format!(
+                    "The automated playtest now has {} error(s); the previous one passed.",
+                    result.errors
+                );
+                notifications::notify(&app, notifications::PLAYTEST_ERRORS, "Playtest errors", &message);
+                let _ = on_event.send(RojoEvent::PlaytestRegression { errors: result.errors, scripts, message });
+            }
+        }
+    }))
+}
+
+/// Splits a line as written to output.log (`HH:MM:SS.mmm [ERROR] message`)
+/// into level, script and message. Playtest markers are skipped.
+fn classify(line: &str) -> Option<PlaytestLine> {
+    let message = line.split_once(' ').map_or(line, |(_, rest)| rest);
+    if message.starts_with("═══════") {
+        return None;
+    }
+    let message = message.strip_prefix("[rojo-plugin] ").unwrap_or(message);
+    let (level, message) = if let Some(rest) = message.strip_prefix("[ERROR] ") {
+        ("error", rest)
+    } else if let Some(rest) = message.strip_prefix("[WARN] ") {
+        ("warn", rest)
+    } else {
+        ("info", message)
+    };
+    Some(PlaytestLine {
+        level: level.into(),
+        script: script_of(message),
+        message: message.to_string(),
+    })
+}
+
+/// `ServerScriptService.Main` from `ServerScriptService.Main:12: attempt to
+/// index nil`, or `Main` from `[Main] loaded`.
+fn script_of(message: &str) -> Option<String> {
+    if let Some((name, _)) = message.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+        return Some(name.trim().to_string()).filter(|n| !n.is_empty());
+    }
+    let (path, rest) = message.split_once(':')?;
+    let line_number = rest.split(':').next()?;
+    let is_path = !path.is_empty() && !path.contains(' ');
+    (is_path && !line_number.is_empty() && line_number.chars().all(|c| c.is_ascii_digit()))
+        .then(|| path.to_string())
+}
+
+fn summarize(started_at: String, trigger: &str, duration_secs: u64, lines: Vec<PlaytestLine>) -> PlaytestResult {
+    let mut scripts: BTreeMap<String, ScriptCounts> = BTreeMap::new();
+    let (mut errors, mut warnings, mut prints) = (0, 0, 0);
+    for line in &lines {
+        let counts = line.script.as_ref().map(|script| {
+            scripts.entry(script.clone()).or_insert_with(|| ScriptCounts { script: script.clone(), ..Default::default() })
+        });
+        let (total, per_script) = match line.level.as_str() {
+            "error" => (&mut errors, counts.map(|c| &mut c.errors)),
+            "warn" => (&mut warnings, counts.map(|c| &mut c.warnings)),
+            _ => (&mut prints, counts.map(|c| &mut c.prints)),
+        };
+        *total += 1;
+        if let Some(count) = per_script {
+            *count += 1;
+        }
+    }
+    let mut scripts: Vec<ScriptCounts> = scripts.into_values().collect();
+    scripts.sort_by(|a, b| b.errors.cmp(&a.errors).then(b.warnings.cmp(&a.warnings)));
+    PlaytestResult {
+        started_at,
+        trigger: trigger.to_string(),
+        duration_secs,
+        errors,
+        warnings,
+        prints,
+        scripts,
+        lines,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        let lines: Vec<PlaytestLine> = [
+            "12:00:00.000 ═══════ PLAYTEST #3 START ═══════",
+            "12:00:00.100 [Combat] loaded",
+            "12:00:01.000 [ERROR] ServerScriptService.Combat:12: attempt to index nil with 'Health'",
+            "12:00:01.200 [WARN] Infinite yield possible on 'Workspace:WaitForChild(\"Map\")'",
+            "12:00:02.000 [ERROR] ServerScriptService.Combat:40: Stack overflow",
+        ]
+        .iter()
+        .filter_map(|l| classify(l))
+        .collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1].script.as_deref(), Some("ServerScriptService.Combat"));
+        assert_eq!(lines[2].script, None);
+
+        let result = summarize(String::new(), "manual", 10, lines);
+        assert_eq!((result.errors, result.warnings, result.prints), (2, 1, 1));
+        assert_eq!(result.scripts[0].script, "ServerScriptService.Combat");
+        assert_eq!(result.scripts[0].errors, 2);
+        assert_eq!(result.scripts[1].prints, 1);
+    }
+
+    #[test]
+    fn test_parse_at() {
+        assert_eq!(parse_at("02:30"), Some(150));
+        assert_eq!(parse_at(" 23:59"), Some(1439));
+        assert_eq!(parse_at("24:00"), None);
+        assert_eq!(parse_at("noon"), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Local port selection. The log server and Rojo have fixed default ports;
+//! when another program holds one, we identify it. Rojo falls back to the
+//! next free port; the log server can't, since the Studio plugin only
+//! connects to its default port.
+
+/// Port of the Studio log server (plugin, MCP relay).
+pub const LOG_SERVER_PORT: u16 = 19556;
+/// Default port of `rojo serve`.
+pub const ROJO_PORT: u16 = 34872;
+/// Ports tried after the default, in order.
+pub const FALLBACK_RANGE: u16 = 10;
+
+/// Whether `port` can be bound on localhost right now.
+pub fn is_free(port: u16) -> bool {
+    std::net::TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+/// First free port after `port` within the fallback range.
+pub fn next_free(port: u16) -> Option<u16> {
+    (1..=FALLBACK_RANGE)
+        .filter_map(|i| port.checked_add(i))
+        .find(|p| is_free(*p))
+}
+
+/// Name (and PID) of the process listening on `port`, if it can be found.
+pub async fn port_owner(port: u16) -> Option<String> {
+    #[cfg(target_os = "windows")]
+    {
+        let mut cmd = tokio::process::Command::new("netstat");
+        cmd.args(["-ano", "-p", "TCP"]).creation_flags(0x08000000); // CREATE_NO_WINDOW
+        let output = cmd.output().await.ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let suffix = format!(":{port}");
+        let pid = stdout.lines().find_map(|line| {
+            let cols: Vec<&str> = line.split_whitespace().collect();
+            let listening = cols.len() >= 5 && cols[1].ends_with(&suffix) && cols[3] == "LISTENING";
+            listening.then(|| cols[4].to_string())
+        })?;
+
+        let mut cmd = tokio::process::Command::new("tasklist");
+        cmd.args(["/FI", &format!("PID eq {pid}"), "/FO", "CSV", "/NH"])
+            .creation_flags(0x08000000);
+        let output = cmd.output().await.ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match pid.parse().ok().and_then(|p| crate::util::tasklist_image(&stdout, p)) {
+            Some(name) => Some(format!("{name} (PID {pid})")),
+            None => Some(format!("PID {pid}")),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let output = tokio::process::Command::new("lsof")
+            .args(["-nP", &format!("-iTCP:{port}"), "-sTCP:LISTEN", "-Fpc"])
+            .output()
+            .await
+            .ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let pid = stdout.lines().find_map(|l| l.strip_prefix('p'))?;
+        match stdout.lines().find_map(|l| l.strip_prefix('c')) {
+            Some(name) => Some(format!("{name} (PID {pid})")),
+            None => Some(format!("PID {pid}")),
+        }
+    }
+}
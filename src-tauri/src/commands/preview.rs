@@ -0,0 +1,66 @@
+//! Preview sessions: Rojo serves a throwaway copy of the project, so whatever
+//! Studio writes back (two-way sync, deletions) never touches the real files.
+//! start_rojo's own setup (layout migration, missing config files) also runs
+//! on the copy.
+//! Lets users see what syncing would do before connecting their real place.
+
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{InstallerError, Result};
+
+/// Directories not needed to serve the project.
+const SKIPPED_DIRS: &[&str] = &[".git", ".roxlit", "node_modules"];
+
+/// Where the preview copy of a project lives: named after the folder, plus
+/// a hash of the full path so projects with the same folder name don't share it.
+fn preview_dir(project_path: &Path) -> PathBuf {
+    let name = project_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".into());
+    let hash: String = Sha256::digest(project_path.to_string_lossy().as_bytes())
+        .iter()
+        .take(6)
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    std::env::temp_dir().join("roxlit-preview").join(format!("{name}-{hash}"))
+}
+
+/// Replaces the project's preview copy with a fresh one and returns its path.
+pub fn prepare_preview(project_path: &str) -> Result<PathBuf> {
+    let source = Path::new(project_path);
+    let dest = preview_dir(source);
+    if dest.exists() {
+        fs::remove_dir_all(&dest).map_err(|e| {
+            InstallerError::Custom(format!("Could not clear the previous preview at {}: {e}", dest.display()))
+        })?;
+    }
+    copy_tree(source, &dest)?;
+    Ok(dest)
+}
+
+/// Copies `src` into `dest`. Symlinks are skipped: a link back up the tree
+/// would otherwise be copied forever.
+fn copy_tree(src: &Path, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)?.flatten() {
+        let name = entry.file_name();
+        let from = entry.path();
+        let to = dest.join(&name);
+        let Ok(file_type) = entry.file_type() else { continue };
+        if file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            if SKIPPED_DIRS.iter().any(|d| name == *d) {
+                continue;
+            }
+            copy_tree(&from, &to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
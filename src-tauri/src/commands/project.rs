@@ -1,10 +1,140 @@
-use crate::error::Result;
-use crate::templates;
+use crate::error::{InstallerError, Result};
+use crate::templates::{self, ProjectJsonOptions};
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 
-/// Creates the standard Rojo project structure at the given path.
-pub fn create_project(project_path: &str, project_name: &str) -> Result<()> {
+/// A template selectable at project creation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplate {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    /// "lesson" for classroom exercises, "framework" for architecture presets.
+    pub kind: String,
+}
+
+/// Lists the templates that can be passed as `InstallConfig.template`.
+#[tauri::command]
+pub async fn list_project_templates() -> Vec<ProjectTemplate> {
+    let frameworks = templates::frameworks::FRAMEWORKS.iter().map(|f| ProjectTemplate {
+        id: f.id.into(),
+        title: f.title.into(),
+        description: f.description.into(),
+        kind: "framework".into(),
+    });
+    let lessons = templates::lessons::LESSONS.iter().map(|l| ProjectTemplate {
+        id: l.id.into(),
+        title: l.title.into(),
+        description: l.description.into(),
+        kind: "lesson".into(),
+    });
+    frameworks.chain(lessons).collect()
+}
+
+/// A starter module selectable at project creation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarterModuleInfo {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub path: String,
+}
+
+/// Lists the modules that can be passed as `InstallConfig.starter_modules`.
+#[tauri::command]
+pub async fn list_starter_modules() -> Vec<StarterModuleInfo> {
+    templates::modules::MODULES
+        .iter()
+        .map(|m| StarterModuleInfo {
+            id: m.id.into(),
+            title: m.title.into(),
+            description: m.description.into(),
+            path: m.path.into(),
+        })
+        .collect()
+}
+
+/// Writes the selected starter modules. Existing files are left alone.
+pub fn add_starter_modules(project_path: &str, ids: &[String]) -> Result<()> {
+    let root = Path::new(project_path);
+    for id in ids {
+        let module = templates::modules::find(id)
+            .ok_or_else(|| InstallerError::Custom(format!("Unknown starter module: {id}")))?;
+        let path = root.join(module.path);
+        if path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, module.content)?;
+    }
+    Ok(())
+}
+
+/// Applies a template on top of a project created by `create_project`.
+pub fn apply_template(
+    project_path: &str,
+    project_name: &str,
+    template_id: &str,
+    options: &ProjectJsonOptions,
+) -> Result<()> {
+    let root = Path::new(project_path);
+    if let Some(framework) = templates::frameworks::find(template_id) {
+        return apply_framework(root, project_name, framework, options);
+    }
+    match templates::lessons::find(template_id) {
+        Some(lesson) => apply_lesson(root, lesson),
+        None => Err(InstallerError::Custom(format!("Unknown project template: {template_id}"))),
+    }
+}
+
+/// Writes a framework preset's folders and bootstraps, and remaps default.project.json.
+fn apply_framework(
+    root: &Path,
+    project_name: &str,
+    framework: &templates::frameworks::Framework,
+    options: &ProjectJsonOptions,
+) -> Result<()> {
+    for (rel_path, content) in framework.files {
+        let path = root.join(rel_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+
+    fs::write(root.join("default.project.json"), (framework.project_json)(project_name, options))?;
+
+    Ok(())
+}
+
+/// Writes a lesson's game files, `EXERCISES.md`, and the `lesson.md` context
+/// pack that tells the AI to tutor rather than solve.
+fn apply_lesson(root: &Path, lesson: &templates::lessons::Lesson) -> Result<()> {
+    for (rel_path, content) in lesson.files {
+        let path = root.join(rel_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+
+    fs::write(root.join("EXERCISES.md"), lesson.exercises)?;
+
+    let context_dir = root.join(".roxlit").join("context");
+    fs::create_dir_all(&context_dir)?;
+    fs::write(context_dir.join("lesson.md"), templates::lessons::hints(lesson))?;
+
+    Ok(())
+}
+
+/// Creates the standard Rojo project structure at the given path, with
+/// default.project.json generated from `options`.
+pub fn create_project(project_path: &str, project_name: &str, options: &ProjectJsonOptions) -> Result<()> {
     let root = Path::new(project_path);
 
     // Create directory tree (all services where Roblox allows scripts and instances)
@@ -19,15 +149,12 @@ pub fn create_project(project_path: &str, project_name: &str) -> Result<()> {
     fs::create_dir_all(root.join("src").join("StarterPack"))?;
 
     // Aftman tool manifest (tells aftman which rojo version to use)
-    fs::write(
-        root.join("aftman.toml"),
-        "[tools]\nrojo = \"rojo-rbx/rojo@7.4.4\"\n",
-    )?;
+    fs::write(root.join("aftman.toml"), templates::aftman_toml())?;
 
     // Rojo project config
     fs::write(
         root.join("default.project.json"),
-        templates::project_json(project_name),
+        templates::project_json(project_name, options),
     )?;
 
     // Luau strict-mode config
@@ -62,10 +189,10 @@ pub fn create_project(project_path: &str, project_name: &str) -> Result<()> {
     )?;
 
     // Roxlit MCP ignore file
-    fs::write(
-        root.join(".roxlit-mcp-ignore"),
-        ".git/\n.roxlit/\n.claude/\n.cursor/\n.vscode/\n.windsurf/\n.github/\nnode_modules/\nsrc/\n",
-    )?;
+    fs::write(root.join(".roxlit-mcp-ignore"), templates::roxlit_mcp_ignore())?;
+
+    // Editor workspace (project + AI context folder)
+    write_workspace(root, project_name)?;
 
     // Local tracking directories for AI coaching (IDEA-033)
     fs::create_dir_all(root.join("docs").join("ideas"))?;
@@ -74,25 +201,105 @@ pub fn create_project(project_path: &str, project_name: &str) -> Result<()> {
     // Persistent memory across AI chat sessions (works with any AI tool)
     let memory_dir = root.join(".roxlit").join("memory");
     fs::create_dir_all(&memory_dir)?;
-    fs::write(
-        memory_dir.join("MEMORY.md"),
-        r#"# Project Memory
+    fs::write(memory_dir.join("MEMORY.md"), templates::memory_md())?;
 
-> This file persists across AI chat sessions. The AI updates it with key decisions, patterns, and project state.
+    Ok(())
+}
+
+/// A system scaffold that `generate_scaffold` can write.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaffoldInfo {
+    pub kind: String,
+    pub description: String,
+}
+
+/// Path of the project's `<folder name>.code-workspace`.
+pub fn workspace_path(root: &Path) -> std::path::PathBuf {
+    let name = root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".into());
+    root.join(format!("{name}.code-workspace"))
+}
 
-## Architecture Decisions
+/// Writes the editor workspace file unless one exists (it may be customized).
+fn write_workspace(root: &Path, project_name: &str) -> Result<std::path::PathBuf> {
+    let path = workspace_path(root);
+    if !path.exists() {
+        fs::create_dir_all(root.join(".roxlit").join("context"))?;
+        fs::write(&path, templates::code_workspace(project_name))?;
+    }
+    Ok(path)
+}
 
-(AI will document key decisions here as the project evolves)
+/// Creates the project's `.code-workspace` (if missing) and returns its path,
+/// for projects created before workspaces were generated.
+#[tauri::command]
+pub async fn generate_workspace(project_path: String) -> Result<String> {
+    let project_path = crate::util::expand_tilde(&project_path);
+    let root = Path::new(&project_path);
+    let name = crate::commands::config::find_project(&project_path)
+        .map(|p| p.name)
+        .or_else(|| root.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "project".into());
+    Ok(write_workspace(root, &name)?.to_string_lossy().to_string())
+}
 
-## Known Patterns
+/// Lists the scaffold kinds accepted by `generate_scaffold`.
+#[tauri::command]
+pub async fn list_scaffolds() -> Vec<ScaffoldInfo> {
+    templates::scaffolds::SCAFFOLDS
+        .iter()
+        .map(|s| ScaffoldInfo {
+            kind: s.kind.into(),
+            description: s.description.into(),
+        })
+        .collect()
+}
 
-(AI will document coding patterns and conventions established in this project)
+/// Writes a system scaffold (e.g. "shop", "round-manager", "daily-rewards")
+/// named `name` into the project's src/ folders. Touches no other files, and
+/// refuses to overwrite existing ones. Returns the created paths.
+#[tauri::command]
+pub async fn generate_scaffold(project_path: String, kind: String, name: String) -> Result<Vec<String>> {
+    let scaffold = templates::scaffolds::find(&kind)
+        .ok_or_else(|| InstallerError::Custom(format!("Unknown scaffold kind: {kind}")))?;
+    crate::commands::analytics::track_feature("generate_scaffold");
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(InstallerError::Custom(format!(
+            "Scaffold name must be letters, digits or underscores: {name}"
+        )));
+    }
 
-## Current State
+    let project_path = crate::util::expand_tilde(&project_path);
+    let root = Path::new(&project_path);
+    let files: Vec<_> = scaffold
+        .files
+        .iter()
+        .map(|(rel_path, content)| {
+            (
+                root.join(rel_path.replace(templates::scaffolds::NAME_PLACEHOLDER, name)),
+                content.replace(templates::scaffolds::NAME_PLACEHOLDER, name),
+            )
+        })
+        .collect();
 
-(AI will update this with what's in progress, what's blocked, etc.)
-"#,
-    )?;
+    if let Some((existing, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(InstallerError::Custom(format!(
+            "{} already exists — pick another name",
+            existing.display()
+        )));
+    }
 
-    Ok(())
+    let mut created = Vec::new();
+    for (path, content) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        created.push(path.to_string_lossy().to_string());
+    }
+    Ok(created)
 }
@@ -0,0 +1,253 @@
+//! Checks default.project.json before Rojo is started, so a hand-editing
+//! mistake (a stray trailing comma, a `$path` that isn't a string) is
+//! reported with its location instead of as a cryptic Rojo error. The last
+//! copy that passed is kept so it can be restored.
+
+use regex::Regex;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// What's wrong with a project file, and where (1-based, for JSON syntax errors).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectJsonError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl std::fmt::Display for ProjectJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: {}", self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+fn project_file(project_dir: &Path) -> PathBuf {
+    project_dir.join("default.project.json")
+}
+
+/// The last project file that passed validation.
+fn known_good_file(project_dir: &Path) -> PathBuf {
+    project_dir.join(".roxlit").join("project.json.last-good")
+}
+
+/// True if a last-known-good copy exists to restore.
+pub(crate) fn has_known_good(project_dir: &Path) -> bool {
+    known_good_file(project_dir).exists()
+}
+
+/// Copy of the project file taken before `migrate_legacy_paths` edits it.
+fn pre_migration_file(project_dir: &Path) -> PathBuf {
+    project_dir.join(".roxlit").join("project.json.pre-migration")
+}
+
+/// Points `$path`s of the old `scripts/<Service>` layout at `src/<Service>`,
+/// leaving everything else in the file (custom mappings, unknown keys,
+/// formatting) as it is. The original is backed up first; returns the backup
+/// path, or `None` when nothing needed migrating.
+pub(crate) fn migrate_legacy_paths(project_dir: &Path) -> Result<Option<PathBuf>> {
+    let file = project_file(project_dir);
+    let Ok(content) = std::fs::read_to_string(&file) else {
+        return Ok(None);
+    };
+    let Some(migrated) = rewrite_legacy_paths(&content) else {
+        return Ok(None);
+    };
+
+    let backup = pre_migration_file(project_dir);
+    if let Some(dir) = backup.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&backup, &content)?;
+    std::fs::write(&file, migrated)?;
+    Ok(Some(backup))
+}
+
+/// `content` with legacy `scripts/<Service>` `$path` values (plain or
+/// `{ "optional": ... }`) moved under `src/`, or `None` if there are none.
+fn rewrite_legacy_paths(content: &str) -> Option<String> {
+    let services = crate::templates::STANDARD_SERVICES.join("|");
+    let pattern = Regex::new(&format!(
+        r#"("\$path"\s*:\s*(?:\{{\s*"optional"\s*:\s*)?")scripts/((?:{services})(?:/[^"]*)?")"#
+    ))
+    .ok()?;
+    if !pattern.is_match(content) {
+        return None;
+    }
+    Some(pattern.replace_all(content, "${1}src/${2}").into_owned())
+}
+
+/// Validates default.project.json. On success, keeps a copy as the
+/// last-known-good version.
+pub(crate) fn validate(project_dir: &Path) -> std::result::Result<(), ProjectJsonError> {
+    let content = std::fs::read_to_string(project_file(project_dir)).map_err(|e| ProjectJsonError {
+        message: format!("could not read the file: {e}"),
+        line: None,
+        column: None,
+    })?;
+    let value: Value = serde_json::from_str(&content).map_err(|e| parse_error(&e))?;
+    if let Some(message) = check_structure(&value) {
+        return Err(ProjectJsonError { message, line: None, column: None });
+    }
+
+    let good = known_good_file(project_dir);
+    if let Some(dir) = good.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    let _ = std::fs::write(good, content);
+    Ok(())
+}
+
+/// serde_json's message without its trailing "at line X column Y".
+fn parse_error(e: &serde_json::Error) -> ProjectJsonError {
+    let full = e.to_string();
+    let message = full
+        .rsplit_once(" at line ")
+        .map(|(message, _)| message.to_string())
+        .unwrap_or(full);
+    ProjectJsonError { message, line: Some(e.line()), column: Some(e.column()) }
+}
+
+/// The first structural problem Rojo would reject, if any.
+fn check_structure(value: &Value) -> Option<String> {
+    let Some(root) = value.as_object() else {
+        return Some("the file must contain a JSON object".into());
+    };
+    if !root.get("name").is_some_and(Value::is_string) {
+        return Some("\"name\" must be a string".into());
+    }
+    if let Some(port) = root.get("servePort") {
+        if port.as_u64().and_then(|p| u16::try_from(p).ok()).is_none() {
+            return Some("\"servePort\" must be a port number".into());
+        }
+    }
+    if let Some(globs) = root.get("globIgnorePaths") {
+        if !globs.as_array().is_some_and(|g| g.iter().all(Value::is_string)) {
+            return Some("\"globIgnorePaths\" must be a list of strings".into());
+        }
+    }
+    match root.get("tree") {
+        Some(tree) => check_node("tree", tree),
+        None => Some("\"tree\" is missing".into()),
+    }
+}
+
+fn check_node(path: &str, node: &Value) -> Option<String> {
+    let Some(obj) = node.as_object() else {
+        return Some(format!("{path} must be an object"));
+    };
+    for (key, value) in obj {
+        let ok = match key.as_str() {
+            "$className" => value.is_string(),
+            // A plain path, or `{ "optional": "path" }`
+            "$path" => value.is_string() || value["optional"].is_string(),
+            "$ignoreUnknownInstances" => value.is_boolean(),
+            "$properties" | "$attributes" => value.is_object(),
+            _ if key.starts_with('$') => true,
+            _ => {
+                if let Some(problem) = check_node(&format!("{path}.{key}"), value) {
+                    return Some(problem);
+                }
+                true
+            }
+        };
+        if !ok {
+            return Some(format!("{path}.{key} has the wrong type"));
+        }
+    }
+    None
+}
+
+/// Replaces default.project.json with the last copy that passed validation.
+/// The broken file is kept next to it as `default.project.json.broken`.
+#[tauri::command]
+pub async fn restore_project_json(project_path: String) -> Result<()> {
+    let project_dir = PathBuf::from(expand_tilde(&project_path));
+    let good = known_good_file(&project_dir);
+    if !good.exists() {
+        return Err(InstallerError::Custom(
+            "No working copy of default.project.json has been saved for this project yet".into(),
+        ));
+    }
+    let current = project_file(&project_dir);
+    if current.exists() {
+        std::fs::copy(&current, project_dir.join("default.project.json.broken"))?;
+    }
+    std::fs::copy(&good, &current)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_errors() {
+        let err = serde_json::from_str::<Value>("{\n  \"name\": \"game\",\n}").unwrap_err();
+        let err = parse_error(&err);
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.message, "trailing comma");
+
+        let ok = serde_json::json!({ "name": "game", "tree": { "$className": "DataModel", "Workspace": { "$path": "src" } } });
+        assert_eq!(check_structure(&ok), None);
+
+        let bad = serde_json::json!({ "name": "game", "tree": { "Workspace": { "$path": 3 } } });
+        assert_eq!(check_structure(&bad), Some("tree.Workspace.$path has the wrong type".into()));
+    }
+
+    #[test]
+    fn test_rewrite_legacy_paths() {
+        let legacy = r#"{
+  "name": "game",
+  "tree": {
+    "ServerScriptService": { "$path": "scripts/ServerScriptService" },
+    "StarterPlayer": {
+      "StarterPlayerScripts": { "$path": { "optional": "scripts/StarterPlayer/StarterPlayerScripts" } }
+    },
+    "ReplicatedStorage": {
+      "$path": "scripts/ReplicatedStorage",
+      "Packages": { "$path": "Packages" },
+      "Tools": { "$path": "scripts/tools" }
+    }
+  },
+  "emitLegacyScripts": false
+}"#;
+        let migrated = rewrite_legacy_paths(legacy).unwrap();
+        assert!(migrated.contains(r#"{ "$path": "src/ServerScriptService" }"#));
+        assert!(migrated.contains(r#""optional": "src/StarterPlayer/StarterPlayerScripts""#));
+        assert!(migrated.contains(r#""$path": "src/ReplicatedStorage","#));
+        // Custom mappings and unknown keys are untouched
+        assert!(migrated.contains(r#""Packages": { "$path": "Packages" }"#));
+        assert!(migrated.contains(r#""$path": "scripts/tools""#));
+        assert!(migrated.contains(r#""emitLegacyScripts": false"#));
+
+        assert_eq!(rewrite_legacy_paths(&migrated), None);
+    }
+
+    #[test]
+    fn test_generated_options() {
+        let options = crate::templates::ProjectJsonOptions {
+            services: vec!["ServerScriptService".into(), "StarterPlayer".into()],
+            ignore_unknown_instances: false,
+            glob_ignore_paths: vec!["**/*.spec.luau".into()],
+        };
+        let value: Value = serde_json::from_str(&crate::templates::project_json("game", &options)).unwrap();
+        assert_eq!(check_structure(&value), None);
+        assert_eq!(value["globIgnorePaths"][0], "**/*.spec.luau");
+        assert!(value["tree"]["Workspace"].is_null());
+        assert!(value["tree"]["ServerScriptService"]["$ignoreUnknownInstances"].is_null());
+        assert_eq!(
+            value["tree"]["StarterPlayer"]["StarterPlayerScripts"]["$path"],
+            "src/StarterPlayer/StarterPlayerScripts"
+        );
+
+        let default: Value =
+            serde_json::from_str(&crate::templates::project_json("game", &Default::default())).unwrap();
+        assert_eq!(default["tree"]["Workspace"]["$ignoreUnknownInstances"], true);
+    }
+}
@@ -0,0 +1,97 @@
+//! Structural check of binary Roblox model files (.rbxm), used before a
+//! plugin is left in Studio's plugins folder. Studio silently skips a plugin
+//! it can't load, so an error page saved as `Roxlit.rbxm` or a truncated
+//! download would otherwise look installed.
+//!
+//! Only the framing is checked (header, chunk lengths, the closing `END`
+//! chunk); chunk contents stay compressed.
+
+use std::path::Path;
+
+use crate::error::{InstallerError, Result};
+
+/// Magic header at the start of every binary Roblox model file.
+pub(crate) const MAGIC: &[u8] = b"<roblox!";
+/// Follows the magic, to catch files mangled by text-mode transfers.
+const SIGNATURE: &[u8] = b"\x89\xff\r\n\x1a\n";
+/// Magic, signature, version (u16), class count, instance count, reserved.
+const HEADER_LEN: usize = 8 + 6 + 2 + 4 + 4 + 8;
+/// Chunk name, compressed length, uncompressed length, reserved.
+const CHUNK_HEADER_LEN: usize = 4 + 4 + 4 + 4;
+
+/// Checks that `bytes` is a complete binary model file. The error says
+/// what's wrong, for messages like "Roxlit.rbxm is not a valid plugin: ...".
+pub(crate) fn check(bytes: &[u8]) -> std::result::Result<(), String> {
+    if bytes.is_empty() {
+        return Err("the file is empty".into());
+    }
+    if !bytes.starts_with(MAGIC) {
+        return Err("not a Roblox model file (the download may have been an error page)".into());
+    }
+    if bytes.len() < HEADER_LEN || &bytes[8..14] != SIGNATURE {
+        return Err("the file header is damaged".into());
+    }
+    if u16::from_le_bytes([bytes[14], bytes[15]]) != 0 {
+        return Err("unsupported model format version".into());
+    }
+
+    let mut offset = HEADER_LEN;
+    while offset + CHUNK_HEADER_LEN <= bytes.len() {
+        let header = &bytes[offset..offset + CHUNK_HEADER_LEN];
+        let name = &header[0..4];
+        let compressed = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let uncompressed = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        // Uncompressed chunks store `uncompressed` bytes as-is
+        let len = if compressed == 0 { uncompressed } else { compressed };
+        offset += CHUNK_HEADER_LEN;
+        if offset + len > bytes.len() {
+            return Err("the file is truncated".into());
+        }
+        if name == b"END\0" {
+            return Ok(());
+        }
+        offset += len;
+    }
+    Err("the file is truncated".into())
+}
+
+/// Reads and checks a model file on disk.
+pub(crate) async fn check_file(path: &Path) -> Result<()> {
+    let bytes = tokio::fs::read(path).await?;
+    check(&bytes).map_err(|problem| {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        InstallerError::Custom(format!("{name} is not a valid plugin: {problem}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(name: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut out = name.to_vec();
+        out.extend(0u32.to_le_bytes());
+        out.extend((data.len() as u32).to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.extend(data);
+        out
+    }
+
+    #[test]
+    fn test_check() {
+        let mut model = MAGIC.to_vec();
+        model.extend(SIGNATURE);
+        model.extend([0u8; 18]);
+        model.extend(chunk(b"INST", &[1, 2, 3]));
+        let mut complete = model.clone();
+        complete.extend(chunk(b"END\0", b"</roblox>"));
+        assert_eq!(check(&complete), Ok(()));
+
+        // Cut off before the END chunk, or partway through it
+        assert_eq!(check(&model), Err("the file is truncated".into()));
+        assert!(check(&complete[..complete.len() - 4]).is_err());
+
+        assert!(check(b"<!DOCTYPE html><html>Not Found</html>").is_err());
+        assert!(check(b"").is_err());
+    }
+}
@@ -4,7 +4,10 @@ use tauri::ipc::Channel;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::sync::Mutex;
 
-use crate::commands::logs::{send_log, LauncherStatus, LogServerState, LoggerState, SessionLogger};
+use crate::commands::config::ProjectSettings;
+use crate::commands::logs::{send_log, LauncherStatus, LogLevel, LogLine, LogServerState, LoggerState, SessionLogger};
+use crate::commands::{notifications, ports};
+use crate::commands::sync_history::SyncTracker;
 use crate::error::{InstallerError, Result};
 use crate::util::expand_tilde;
 
@@ -19,6 +22,64 @@ pub enum RojoEvent {
     Stopped { code: Option<i32> },
     #[allow(dead_code)]
     Error { message: String },
+    /// The Studio plugin hasn't contacted the launcher within the grace period.
+    #[serde(rename_all = "camelCase")]
+    PluginNotConnected { waited_secs: u64, message: String },
+    /// A default port was taken by another program.
+    #[serde(rename_all = "camelCase")]
+    PortConflict {
+        service: String,
+        port: u16,
+        /// The program holding the port, when it could be identified.
+        owner: Option<String>,
+        /// The port used instead, `None` if no free port was found.
+        fallback: Option<u16>,
+    },
+    /// The Studio plugin started polling the launcher.
+    #[serde(rename_all = "camelCase")]
+    StudioSessionStarted { place_name: Option<String> },
+    /// The Studio plugin stopped polling, or the session ended.
+    #[serde(rename_all = "camelCase")]
+    StudioSessionEnded { place_name: Option<String> },
+    /// The plugin went silent and Studio is no longer running (closed or crashed).
+    #[serde(rename_all = "camelCase")]
+    StudioLost { message: String, stop_session: bool },
+    /// default.project.json failed validation; Rojo was not started.
+    #[serde(rename_all = "camelCase")]
+    ProjectJsonInvalid {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+        /// A last-known-good copy exists (see `restore_project_json`).
+        can_restore: bool,
+    },
+    /// The project's disk is nearly full; auto-backups are skipped until
+    /// space is freed.
+    #[serde(rename_all = "camelCase")]
+    DiskLow { message: String, free_bytes: u64 },
+    /// The project was adopted and hasn't synced yet; Rojo was not started
+    /// (see `preview_first_sync` and `confirm_first_sync`).
+    #[serde(rename_all = "camelCase")]
+    FirstSyncPending { message: String },
+    /// A scheduled playtest has errors and the previous playtest had none.
+    #[serde(rename_all = "camelCase")]
+    PlaytestRegression { errors: usize, scripts: Vec<String>, message: String },
+}
+
+/// Timing of the session's background sync jobs, adjustable while it runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SyncSettings {
+    pub backup_interval: std::time::Duration,
+    pub backup_limit_bytes: u64,
+}
+
+impl From<&ProjectSettings> for SyncSettings {
+    fn from(settings: &ProjectSettings) -> Self {
+        Self {
+            backup_interval: std::time::Duration::from_secs(60 * u64::from(settings.backup_interval_mins.unwrap_or(10))),
+            backup_limit_bytes: settings.backup_limit_mb.unwrap_or(100).saturating_mul(1024 * 1024),
+        }
+    }
 }
 
 /// Managed state holding the rojo child process.
@@ -26,6 +87,12 @@ pub struct RojoProcess {
     pub child: Arc<Mutex<Option<tokio::process::Child>>>,
     pub abort_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
     pub backup_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Scheduled playtests (see `playtest::spawn_scheduler`).
+    pub playtest_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Refreshes the project's session lock (see `session_lock::acquire`).
+    pub lock_heartbeat: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Watched by the auto-backup timer, so changes apply without a restart.
+    pub sync_settings: tokio::sync::watch::Sender<SyncSettings>,
 }
 
 impl Default for RojoProcess {
@@ -34,6 +101,9 @@ impl Default for RojoProcess {
             child: Arc::new(Mutex::new(None)),
             abort_handle: Arc::new(Mutex::new(None)),
             backup_handle: Arc::new(Mutex::new(None)),
+            playtest_handle: Arc::new(Mutex::new(None)),
+            lock_heartbeat: Arc::new(Mutex::new(None)),
+            sync_settings: tokio::sync::watch::channel(SyncSettings::from(&ProjectSettings::default())).0,
         }
     }
 }
@@ -48,29 +118,32 @@ impl RojoProcess {
             }
             *guard = None;
         }
+        crate::commands::cleanup::record_rojo_pid(None);
         // Abort the reader task
         if let Ok(mut guard) = self.abort_handle.try_lock() {
             if let Some(handle) = guard.take() {
                 handle.abort();
             }
         }
-        // Abort the auto-backup timer
-        if let Ok(mut guard) = self.backup_handle.try_lock() {
-            if let Some(handle) = guard.take() {
-                handle.abort();
+        // Abort the auto-backup timer, scheduled playtests and lock heartbeat
+        for handle in [&self.backup_handle, &self.playtest_handle, &self.lock_heartbeat] {
+            if let Ok(mut guard) = handle.try_lock() {
+                if let Some(handle) = guard.take() {
+                    handle.abort();
+                }
             }
         }
     }
 }
 
 
-/// Resolve the rojo binary path (aftman installs to ~/.aftman/bin/).
-fn rojo_bin_path() -> String {
-    if let Some(home) = dirs::home_dir() {
+/// Resolve the rojo binary path (aftman installs to ~/.aftman/bin/, or the toolDir override).
+pub(crate) fn rojo_bin_path() -> String {
+    if let Some(bin_dir) = crate::commands::config::aftman_bin_dir() {
         let aftman_rojo = if cfg!(target_os = "windows") {
-            home.join(".aftman").join("bin").join("rojo.exe")
+            bin_dir.join("rojo.exe")
         } else {
-            home.join(".aftman").join("bin").join("rojo")
+            bin_dir.join("rojo")
         };
         if aftman_rojo.exists() {
             return aftman_rojo.to_string_lossy().to_string();
@@ -83,6 +156,7 @@ fn rojo_bin_path() -> String {
 /// Start `rojo serve` in the given project directory and stream output.
 #[tauri::command]
 pub async fn start_rojo(
+    app: tauri::AppHandle,
     project_path: String,
     on_event: Channel<RojoEvent>,
     state: tauri::State<'_, RojoProcess>,
@@ -91,7 +165,46 @@ pub async fn start_rojo(
     launcher_status: tauri::State<'_, LauncherStatus>,
     mcp_state: tauri::State<'_, crate::commands::logs::McpState>,
     telemetry_state: tauri::State<'_, crate::commands::logs::TelemetryState>,
+    preview: Option<bool>,
 ) -> Result<()> {
+    start_session(
+        app,
+        project_path,
+        on_event,
+        state,
+        logger_state,
+        log_server_state,
+        launcher_status,
+        mcp_state,
+        telemetry_state,
+        preview,
+    )
+    .await
+    .map(|_| ())
+}
+
+/// What `start_session` did beyond starting the session, for
+/// `resume_last_session`'s report.
+struct SessionStart {
+    /// Outcome of opening Studio; `None` when auto-open is off.
+    studio: Option<std::result::Result<String, String>>,
+    /// Rojo was held back until the first sync is reviewed.
+    first_sync_pending: bool,
+}
+
+#[allow(clippy::too_many_arguments)] // start_rojo's arguments
+async fn start_session(
+    app: tauri::AppHandle,
+    project_path: String,
+    on_event: Channel<RojoEvent>,
+    state: tauri::State<'_, RojoProcess>,
+    logger_state: tauri::State<'_, LoggerState>,
+    log_server_state: tauri::State<'_, LogServerState>,
+    launcher_status: tauri::State<'_, LauncherStatus>,
+    mcp_state: tauri::State<'_, crate::commands::logs::McpState>,
+    telemetry_state: tauri::State<'_, crate::commands::logs::TelemetryState>,
+    preview: Option<bool>,
+) -> Result<SessionStart> {
     // Check if already running
     {
         let guard = state.child.lock().await;
@@ -104,21 +217,28 @@ pub async fn start_rojo(
 
     let rojo = rojo_bin_path();
     let project_path = expand_tilde(&project_path);
-
-    // Kill any orphaned rojo process holding the port from a previous session
-    kill_orphaned_rojo().await;
+    crate::commands::analytics::track_feature("start_development");
 
     // Ensure project directory and essential config files exist
-    let project_dir = std::path::Path::new(&project_path);
-    if !project_dir.exists() {
-        std::fs::create_dir_all(project_dir).map_err(|e| {
+    if !std::path::Path::new(&project_path).exists() {
+        std::fs::create_dir_all(&project_path).map_err(|e| {
             InstallerError::Custom(format!("Failed to create project directory: {e}"))
         })?;
     }
 
+    // Preview: prepare and serve a temporary copy, so neither the setup below
+    // nor Studio writes to the real files
+    let preview = preview.unwrap_or(false);
+    let serve_dir = if preview {
+        crate::commands::preview::prepare_preview(&project_path)?
+    } else {
+        std::path::PathBuf::from(&project_path)
+    };
+    let project_dir = serve_dir.as_path();
+
     let aftman_toml = project_dir.join("aftman.toml");
     if !aftman_toml.exists() {
-        std::fs::write(&aftman_toml, "[tools]\nrojo = \"rojo-rbx/rojo@7.4.4\"\n")
+        std::fs::write(&aftman_toml, crate::templates::aftman_toml())
             .map_err(|e| InstallerError::Custom(format!(
                 "Failed to write aftman.toml at {}: {e}", aftman_toml.display()
             )))?;
@@ -133,31 +253,59 @@ pub async fn start_rojo(
     }
 
     let project_json = project_dir.join("default.project.json");
-    // Rewrite project.json if it still references scripts/ (old layout)
+    let project_json_options = crate::commands::config::find_project(&project_path)
+        .and_then(|p| p.settings.project_json)
+        .unwrap_or_default();
+    // Point project.json paths that still reference scripts/ (old layout) at src/
+    let mut migration_note = None;
     if project_json.exists() {
-        if let Ok(content) = std::fs::read_to_string(&project_json) {
-            if content.contains("\"scripts/ServerScriptService\"")
-                || content.contains("\"scripts/StarterPlayer")
-                || content.contains("\"scripts/ReplicatedStorage\"")
-            {
-                let name = project_dir
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("my-game");
-                let _ = std::fs::write(&project_json, crate::templates::project_json(name));
-            }
-        }
+        migration_note = match crate::commands::project_json::migrate_legacy_paths(project_dir) {
+            Ok(Some(backup)) => Some(format!(
+                "Updated default.project.json from the old scripts/ layout to src/. The original was saved to {}",
+                backup.display()
+            )),
+            Ok(None) => None,
+            Err(e) => Some(format!("Could not update default.project.json from the old scripts/ layout: {e}")),
+        };
     } else {
         let name = project_dir
             .file_name()
             .and_then(|n| n.to_str())
             .unwrap_or("my-game");
-        std::fs::write(&project_json, crate::templates::project_json(name))
+        std::fs::write(&project_json, crate::templates::project_json(name, &project_json_options))
             .map_err(|e| InstallerError::Custom(format!(
                 "Failed to write default.project.json at {}: {e}", project_json.display()
             )))?;
     }
 
+    // Catch hand-editing mistakes before Rojo turns them into a cryptic error
+    if let Err(problem) = crate::commands::project_json::validate(project_dir) {
+        let can_restore = crate::commands::project_json::has_known_good(project_dir);
+        let _ = on_event.send(RojoEvent::ProjectJsonInvalid {
+            message: problem.message.clone(),
+            line: problem.line,
+            column: problem.column,
+            can_restore,
+        });
+        let hint = if can_restore { " Fix it, or restore the last working copy." } else { "" };
+        return Err(InstallerError::Custom(format!("default.project.json is invalid: {problem}.{hint}")));
+    }
+
+    // The selected place's project file, for multi-place projects. Checked
+    // before the session is marked active, so a missing one changes nothing
+    let project_file = crate::commands::config::project_file(&project_path);
+    if project_file != "default.project.json" && !project_dir.join(&project_file).exists() {
+        return Err(InstallerError::Custom(format!(
+            "The selected place's project file {project_file} does not exist"
+        )));
+    }
+
+    // Two sessions serving the same project (e.g. over a synced folder) overwrite each other's files
+    // Released again by `lock` if the start fails before the end. Taken on the real project
+    // even in preview, where `project_dir` is the temporary copy
+    let (lock, lock_note) =
+        crate::commands::session_lock::acquire(std::path::Path::new(&project_path))?;
+
     // Ensure .luaurc exists
     let luaurc = project_dir.join(".luaurc");
     if !luaurc.exists() {
@@ -171,11 +319,18 @@ pub async fn start_rojo(
     // Ensure MCP binary exists (download if missing)
     ensure_mcp_binary().await;
 
-    // Ensure unified Roxlit plugin is installed in Studio
-    ensure_roxlit_plugin();
+    // Ensure unified Roxlit plugin is installed in Studio (reported once the log is up)
+    let plugin_check = ensure_roxlit_plugin().await;
+
+    // roxlit.toml (if any) overrides the project's settings in config.json.
+    // A broken one is reported and the config.json settings are used as they are.
+    let manifest_note = crate::commands::manifest::sync_to_config(&project_path)
+        .await
+        .err()
+        .map(|e| format!("Ignoring roxlit.toml, using the launcher settings: {e}"));
 
     // Ensure AI context file exists (or regenerate if stale)
-    ensure_ai_context(project_dir, &project_path);
+    let context_note = ensure_ai_context(project_dir, &project_path);
 
     // Ensure Debug.luau exists (added in v0.7.0, older projects don't have it)
     ensure_debug_module(project_dir);
@@ -208,7 +363,8 @@ pub async fn start_rojo(
     let (system_sender, output_sender) = {
         let mut guard = logger_state.logger.lock().await;
         if guard.is_none() {
-            *guard = SessionLogger::new(&project_path, project_name).await;
+            *guard =
+                SessionLogger::new(&project_path, project_name, logger_state.buffer.clone()).await;
         }
         match guard.as_ref() {
             Some(l) => (Some(l.system_sender()), Some(l.output_sender())),
@@ -216,6 +372,24 @@ pub async fn start_rojo(
         }
     };
 
+    crate::commands::health::reset();
+
+    if let (Some(tx), Some(message)) = (&system_sender, plugin_check.message()) {
+        send_log(tx, "roxlit", &message);
+    }
+    if let (Some(tx), Some(message)) = (&system_sender, migration_note) {
+        send_log(tx, "roxlit", &message);
+    }
+    if let (Some(tx), Some(message)) = (&system_sender, manifest_note) {
+        send_log(tx, "roxlit", &message);
+    }
+    if let (Some(tx), Some(message)) = (&system_sender, context_note) {
+        send_log(tx, "roxlit", &message);
+    }
+    if let (Some(tx), Some(message)) = (&system_sender, lock_note) {
+        send_log(tx, "roxlit", &message);
+    }
+
     // Mark launcher as active so the Studio plugin can auto-connect
     launcher_status.set_active(&project_path, project_name).await;
 
@@ -239,34 +413,129 @@ pub async fn start_rojo(
                 tg.project_path = project_path.clone();
             }
         }
-        if let Some(handle) = crate::commands::logs::start_log_server(sys_tx.clone(), out_tx.clone(), shared_status, shared_mcp, shared_telemetry).await {
-            log_server_state.set_handle(handle).await;
-            send_log(sys_tx, "roxlit", "Studio log server started on 127.0.0.1:19556");
+        match crate::commands::logs::start_log_server(sys_tx.clone(), out_tx.clone(), shared_status, shared_mcp, shared_telemetry).await {
+            Some(handle) => {
+                log_server_state.set_handle(handle).await;
+                send_log(
+                    sys_tx,
+                    "roxlit",
+                    &format!("Studio log server started on 127.0.0.1:{}", ports::LOG_SERVER_PORT),
+                );
+            }
+            None => {
+                report_port_conflict(&on_event, Some(sys_tx), "Studio log server", ports::LOG_SERVER_PORT, None).await;
+            }
         }
     }
 
-    // Kill any orphaned roxlit-mcp/rbxsync process from a previous version that used external binary
-    kill_orphaned_roxlit_mcp().await;
+    // Warn when the Studio plugin never checks in — nothing syncs or logs without it
+    let grace = crate::commands::config::read_config()
+        .and_then(|c| c.plugin_grace_secs)
+        .unwrap_or(DEFAULT_PLUGIN_GRACE_SECS);
+    if grace > 0 {
+        watch_plugin_connection(launcher_status.shared(), on_event.clone(), system_sender.clone(), grace);
+    }
+
+    // Keep the Rojo Studio plugin on the same release as the project's Rojo CLI
+    if let Some(tx) = system_sender.clone() {
+        let path = project_path.clone();
+        tokio::spawn(async move {
+            match crate::commands::install::refresh_rojo_plugin(Some(path)).await {
+                Ok(Some(version)) => send_log(&tx, "roxlit", &format!("Rojo Studio plugin updated to {version} to match the Rojo CLI. Restart Studio to load it.")),
+                Ok(None) => {}
+                Err(e) => send_log(&tx, "roxlit", &format!("Could not update the Rojo Studio plugin: {e}")),
+            }
+        });
+    }
 
     // Auto-open Studio if a placeId is linked to this project
-    auto_open_studio(&project_path, system_sender.as_ref()).await;
+    let settings = crate::commands::config::find_project(&project_path)
+        .map(|p| p.settings)
+        .unwrap_or_default();
+    let studio = if settings.auto_open_studio() {
+        Some(auto_open_studio(&project_path, system_sender.as_ref()).await)
+    } else {
+        None
+    };
 
-    // Start rojo serve
+    // Notice Studio closing or crashing, which would otherwise leave everything looking green
+    watch_studio_presence(
+        app.clone(),
+        launcher_status.shared(),
+        on_event.clone(),
+        system_sender.clone(),
+        settings.stop_on_studio_lost.unwrap_or(false),
+    );
+
+    // An adopted project's first sync waits until the user has reviewed what it changes
+    if crate::commands::config::find_project(&project_path).is_some_and(|p| p.first_sync_pending) {
+        let message = "Rojo is on hold until you review what the first sync will change in Studio".to_string();
+        if let Some(ref tx) = system_sender {
+            send_log(tx, "roxlit", &message);
+        }
+        let _ = on_event.send(RojoEvent::FirstSyncPending { message });
+        return Ok(SessionStart { studio, first_sync_pending: true });
+    }
+
+    // Start rojo serve, moving to a free port if another program holds Rojo's
     let mut cmd = tokio::process::Command::new(&rojo);
-    cmd.arg("serve")
-        .current_dir(&project_path)
+    cmd.arg("serve");
+    if project_file != "default.project.json" {
+        cmd.arg(&project_file);
+        if let Some(ref tx) = system_sender {
+            send_log(tx, "roxlit", &format!("Serving {project_file} for the selected place"));
+        }
+    }
+    let serve_port = project_serve_port(&project_path, &project_file).unwrap_or(ports::ROJO_PORT);
+    if !ports::is_free(serve_port) {
+        let fallback = ports::next_free(serve_port);
+        report_port_conflict(&on_event, system_sender.as_ref(), "Rojo", serve_port, fallback).await;
+        match fallback {
+            Some(port) => {
+                cmd.args(["--port", &port.to_string()]);
+            }
+            None => {
+                abort_start(&launcher_status, &log_server_state).await;
+                return Err(InstallerError::Custom(format!(
+                    "Port {serve_port} and the next {} ports are in use; close the program holding them and try again",
+                    ports::FALLBACK_RANGE
+                )));
+            }
+        }
+    }
+    if let (true, Some(tx)) = (preview, &system_sender) {
+        send_log(
+            tx,
+            "roxlit",
+            &format!(
+                "Preview mode: serving a copy of the project at {}. Changes made in Studio will not reach your files.",
+                serve_dir.display()
+            ),
+        );
+    }
+    settings.apply_env(&mut cmd);
+    if let (Some(tx), Some(env)) = (&system_sender, settings.env.as_ref().filter(|e| !e.is_empty())) {
+        let names: Vec<&str> = env.keys().map(String::as_str).collect();
+        send_log(tx, "roxlit", &format!("Starting Rojo with project environment variables: {}", names.join(", ")));
+    }
+    cmd.current_dir(&serve_dir)
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .kill_on_drop(true);
     #[cfg(target_os = "windows")]
     cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
 
-    let mut child = cmd.spawn().map_err(|e| {
-        InstallerError::Custom(format!("Failed to start rojo: {e}"))
-    })?;
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            abort_start(&launcher_status, &log_server_state).await;
+            return Err(InstallerError::Custom(format!("Failed to start rojo: {e}")));
+        }
+    };
 
     let stdout = child.stdout.take();
     let stderr = child.stderr.take();
+    crate::commands::cleanup::record_rojo_pid(child.id());
 
     // Store the child process
     {
@@ -278,6 +547,11 @@ pub async fn start_rojo(
     let event_clone = on_event.clone();
     let launcher_status_shared = launcher_status.shared();
 
+    // Count what Rojo syncs and tell the UI when Studio connects
+    let sync_count = Arc::new(std::sync::atomic::AtomicU64::new(0));
+    watch_project_changes(launcher_status.shared(), serve_dir.to_string_lossy().to_string(), sync_count.clone());
+    watch_studio_session(launcher_status.shared(), on_event.clone());
+
     // Read stdout and stream events
     let stdout_log_tx = system_sender.clone();
     let reader_handle = tokio::spawn(async move {
@@ -304,10 +578,12 @@ pub async fn start_rojo(
                                 let _ = event_clone.send(RojoEvent::Started { port });
                             }
                         }
-                        let _ = event_clone.send(RojoEvent::Output {
-                            line,
-                            stream: "stdout".into(),
-                        });
+                        if crate::commands::logs::log_allowed("rojo", LogLevel::infer(&line)) {
+                            let _ = event_clone.send(RojoEvent::Output {
+                                line,
+                                stream: "stdout".into(),
+                            });
+                        }
                     }
                     Ok(None) => break,
                     Err(_) => break,
@@ -328,12 +604,17 @@ pub async fn start_rojo(
             let mut guard = child_arc.lock().await;
             *guard = None;
         }
+        crate::commands::cleanup::record_rojo_pid(None);
 
         let _ = event_clone.send(RojoEvent::Stopped { code });
     });
 
     // Stderr reader
+    let event_backup = on_event.clone();
+    let event_playtest = on_event.clone();
     let event_stderr = on_event;
+    let health_log_tx = system_sender.clone();
+    let playtest_log_tx = system_sender.clone();
     let stderr_log_tx = system_sender;
     if let Some(stderr) = stderr {
         tokio::spawn(async move {
@@ -344,10 +625,12 @@ pub async fn start_rojo(
                 if let Some(ref tx) = stderr_log_tx {
                     send_log(tx, "rojo-err", &line);
                 }
-                let _ = event_stderr.send(RojoEvent::Output {
-                    line,
-                    stream: "stderr".into(),
-                });
+                if crate::commands::logs::log_allowed("rojo-err", LogLevel::infer(&line)) {
+                    let _ = event_stderr.send(RojoEvent::Output {
+                        line,
+                        stream: "stderr".into(),
+                    });
+                }
             }
         });
     }
@@ -358,33 +641,67 @@ pub async fn start_rojo(
         *guard = Some(reader_handle);
     }
 
-    // Start auto-backup timer (every 10 minutes)
+    // Start auto-backup timer (every 10 minutes unless configured otherwise)
+    state.sync_settings.send_replace(SyncSettings::from(&settings));
+    let mut sync_settings = state.sync_settings.subscribe();
     let backup_project_path = project_path.clone();
     let backup_handle = tokio::spawn(async move {
         use crate::commands::backup;
+        use crate::commands::health::{self, Metric};
 
         // Wait 2 minutes before first backup (let user start working)
         tokio::time::sleep(std::time::Duration::from_secs(120)).await;
 
-        let interval = std::time::Duration::from_secs(600); // 10 minutes
-        let max_backup_bytes: u64 = 100 * 1024 * 1024; // 100 MB default limit
-
+        let mut disk_low = false;
         loop {
+            let max_backup_bytes = sync_settings.borrow().backup_limit_bytes;
+            // Skip while the disk is nearly full, warning once
+            let low = crate::commands::disk::low_space(
+                std::path::Path::new(&backup_project_path),
+                0,
+                "the auto-backup",
+            )
+            .await;
+            if let (Some((free_bytes, message)), false) = (&low, disk_low) {
+                if let Some(ref tx) = health_log_tx {
+                    send_log(tx, "roxlit", message);
+                }
+                let _ = event_backup.send(RojoEvent::DiskLow { message: message.clone(), free_bytes: *free_bytes });
+            }
+            disk_low = low.is_some();
+
             // Create auto-backup (blocking git ops in spawn_blocking)
             let path = backup_project_path.clone();
-            let _ = tokio::task::spawn_blocking(move || {
-                let name = format!("auto-{}", backup::now_timestamp());
-                match backup::create_backup(&path, &name) {
-                    Ok(_) => {
-                        // Cleanup old auto-backups if over size limit
-                        backup::cleanup_by_size(&path, max_backup_bytes);
+            if !disk_low {
+                let _ = tokio::task::spawn_blocking(move || {
+                    let name = format!("auto-{}", backup::now_timestamp());
+                    let started = std::time::Instant::now();
+                    match backup::create_backup(&path, &name) {
+                        Ok(_) => {
+                            health::record(Metric::BackupDuration, started.elapsed().as_millis() as u64);
+                            // Cleanup old auto-backups if over size limit
+                            backup::cleanup_by_size(&path, max_backup_bytes);
+                        }
+                        Err(_) => {} // No changes or git not available — skip silently
                     }
-                    Err(_) => {} // No changes or git not available — skip silently
-                }
-            })
-            .await;
+                })
+                .await;
+            }
+
+            // Periodic health summary so slow-sync reports can be read from the log
+            if let (Some(ref tx), Some(line)) = (&health_log_tx, health::summary_line()) {
+                send_log(tx, "health", &line);
+            }
 
-            tokio::time::sleep(interval).await;
+            // Sleep until the next backup, re-timing if the interval changes
+            let last_backup = tokio::time::Instant::now();
+            loop {
+                let interval = sync_settings.borrow_and_update().backup_interval;
+                tokio::select! {
+                    _ = tokio::time::sleep_until(last_backup + interval) => break,
+                    Ok(()) = sync_settings.changed() => {}
+                }
+            }
         }
     });
     {
@@ -392,13 +709,31 @@ pub async fn start_rojo(
         *guard = Some(backup_handle);
     }
 
-    Ok(())
+    if let Some(schedule) = &settings.playtest_schedule {
+        let handle = crate::commands::playtest::spawn_scheduler(
+            app,
+            schedule,
+            launcher_status.shared(),
+            logger_state.buffer.clone(),
+            sync_count,
+            event_playtest,
+            playtest_log_tx,
+        );
+        *state.playtest_handle.lock().await = handle;
+    }
+
+    if let Some(previous) = std::mem::replace(&mut *state.lock_heartbeat.lock().await, lock.keep()) {
+        previous.abort();
+    }
+
+    Ok(SessionStart { studio, first_sync_pending: false })
 }
 
 /// Stop the running rojo serve process.
 #[tauri::command]
 pub async fn stop_rojo(
     state: tauri::State<'_, RojoProcess>,
+    logger_state: tauri::State<'_, LoggerState>,
     log_server_state: tauri::State<'_, LogServerState>,
     launcher_status: tauri::State<'_, LauncherStatus>,
 ) -> Result<()> {
@@ -406,6 +741,9 @@ pub async fn stop_rojo(
     {
         let shared = launcher_status.shared();
         let guard = shared.lock().await;
+        if !guard.project_path.is_empty() {
+            crate::commands::session_lock::release(std::path::Path::new(&guard.project_path));
+        }
         if let Some(place_id) = guard.linked_place_id {
             if !guard.project_path.is_empty() {
                 crate::commands::config::save_place_id(
@@ -430,6 +768,7 @@ pub async fn stop_rojo(
         }
         *guard = None;
     }
+    crate::commands::cleanup::record_rojo_pid(None);
 
     // Abort the reader task
     {
@@ -447,12 +786,174 @@ pub async fn stop_rojo(
         }
     }
 
-    // Stop the Studio log HTTP server
+    // Stop scheduled playtests and the session lock's heartbeat
+    for handle in [&state.playtest_handle, &state.lock_heartbeat] {
+        if let Some(handle) = handle.lock().await.take() {
+            handle.abort();
+        }
+    }
+
+    // Stop the Studio log HTTP server, then end the session log with its footer
     log_server_state.stop().await;
+    logger_state.close().await;
 
     Ok(())
 }
 
+/// Changes the auto-backup interval (minutes) and size limit (MB) for a
+/// project. Saved to its settings, and applied immediately if its session is
+/// running.
+#[tauri::command]
+pub async fn update_sync_settings(
+    project_path: String,
+    backup_interval_mins: Option<u32>,
+    backup_limit_mb: Option<u64>,
+    state: tauri::State<'_, RojoProcess>,
+    launcher_status: tauri::State<'_, LauncherStatus>,
+) -> Result<()> {
+    if backup_interval_mins == Some(0) {
+        return Err(InstallerError::Custom("The backup interval must be at least 1 minute".into()));
+    }
+    if backup_limit_mb == Some(0) {
+        return Err(InstallerError::Custom("The backup size limit must be at least 1 MB".into()));
+    }
+    let project_path = expand_tilde(&project_path);
+    let mut settings = crate::commands::config::find_project(&project_path)
+        .map(|p| p.settings)
+        .ok_or_else(|| InstallerError::Custom(format!("Project not found: {project_path}")))?;
+    if backup_interval_mins.is_some() {
+        settings.backup_interval_mins = backup_interval_mins;
+    }
+    if backup_limit_mb.is_some() {
+        settings.backup_limit_mb = backup_limit_mb;
+    }
+    let sync = SyncSettings::from(&settings);
+    crate::commands::config::save_project_settings(project_path.clone(), settings).await?;
+
+    let active = launcher_status.shared().lock().await.project_path == project_path;
+    if active && state.backup_handle.lock().await.is_some() {
+        state.sync_settings.send_replace(sync);
+    }
+    Ok(())
+}
+
+/// One step of `resume_last_session`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeStep {
+    /// "project", "rojo", "backups" or "studio".
+    pub step: String,
+    pub ok: bool,
+    pub detail: String,
+}
+
+/// What `resume_last_session` restored.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeReport {
+    /// The project resumed; `None` if there was none to resume.
+    pub project_path: Option<String>,
+    pub steps: Vec<ResumeStep>,
+}
+
+impl ResumeReport {
+    fn push(&mut self, step: &str, ok: bool, detail: impl Into<String>) {
+        self.steps.push(ResumeStep { step: step.into(), ok, detail: detail.into() });
+    }
+}
+
+/// Restores the previous session in one call: makes the last active project
+/// (or the first one still on disk) active again, starts Rojo with its saved
+/// settings, which starts the auto-backups and reopens Studio on the linked
+/// place. A failed step is reported rather than returned as an error.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)] // the states start_rojo needs
+pub async fn resume_last_session(
+    app: tauri::AppHandle,
+    on_event: Channel<RojoEvent>,
+    state: tauri::State<'_, RojoProcess>,
+    logger_state: tauri::State<'_, LoggerState>,
+    log_server_state: tauri::State<'_, LogServerState>,
+    launcher_status: tauri::State<'_, LauncherStatus>,
+    mcp_state: tauri::State<'_, crate::commands::logs::McpState>,
+    telemetry_state: tauri::State<'_, crate::commands::logs::TelemetryState>,
+) -> Result<ResumeReport> {
+    let mut report = ResumeReport { project_path: None, steps: Vec::new() };
+    let config = crate::commands::config::load_config().await.unwrap_or_default();
+    let existing = |path: &String| crate::commands::config::project_exists(path);
+    let project = config
+        .last_active_project
+        .as_ref()
+        .filter(|path| existing(path))
+        .and_then(|path| config.projects.iter().find(|p| &p.path == path))
+        .or_else(|| config.projects.iter().find(|p| existing(&p.path)));
+    let Some(project) = project.cloned() else {
+        report.push("project", false, "No project to resume");
+        return Ok(report);
+    };
+    report.project_path = Some(project.path.clone());
+
+    if let Err(e) = crate::commands::config::set_active_project(project.path.clone()).await {
+        report.push("project", false, format!("Could not make {} the active project: {e}", project.name));
+    } else {
+        report.push("project", true, format!("Resumed {}", project.name));
+    }
+
+    let process = state.inner();
+    let started = match start_session(
+        app,
+        project.path.clone(),
+        on_event,
+        state,
+        logger_state,
+        log_server_state,
+        launcher_status,
+        mcp_state,
+        telemetry_state,
+        None,
+    )
+    .await
+    {
+        Ok(started) => started,
+        Err(e) => {
+            report.push("rojo", false, e.to_string());
+            return Ok(report);
+        }
+    };
+
+    if started.first_sync_pending {
+        report.push("rojo", false, "Rojo is on hold until the first sync is reviewed");
+    } else if process.child.lock().await.is_some() {
+        report.push("rojo", true, "Rojo is serving the project");
+    } else {
+        report.push("rojo", false, "Rojo exited right after starting; see the session log");
+    }
+
+    let backups_running = process.backup_handle.lock().await.as_ref().is_some_and(|h| !h.is_finished());
+    if backups_running {
+        let sync = *process.sync_settings.borrow();
+        report.push(
+            "backups",
+            true,
+            format!(
+                "Auto-backups every {} min, up to {} MB",
+                sync.backup_interval.as_secs() / 60,
+                sync.backup_limit_bytes / (1024 * 1024)
+            ),
+        );
+    } else {
+        report.push("backups", false, "Auto-backups start once Rojo is serving the project");
+    }
+
+    match started.studio {
+        Some(Ok(detail)) => report.push("studio", true, detail),
+        Some(Err(detail)) => report.push("studio", false, detail),
+        None => report.push("studio", false, "Opening Studio automatically is turned off for this project"),
+    }
+
+    Ok(report)
+}
+
 /// Check if rojo is currently running.
 #[tauri::command]
 pub async fn get_rojo_status(state: tauri::State<'_, RojoProcess>) -> Result<bool> {
@@ -517,6 +1018,221 @@ fn parse_rojo_port(line: &str) -> Option<u16> {
     None
 }
 
+/// Default seconds to wait for the Studio plugin before warning.
+const DEFAULT_PLUGIN_GRACE_SECS: u64 = 90;
+
+/// After `grace` seconds, reports a session in which the Studio plugin never
+/// contacted the launcher, in the session log (for the AI) and as an event.
+fn watch_plugin_connection(
+    status: Arc<Mutex<crate::commands::logs::LauncherStatusInner>>,
+    on_event: Channel<RojoEvent>,
+    log_tx: Option<tokio::sync::mpsc::UnboundedSender<LogLine>>,
+    grace: u64,
+) {
+    tokio::spawn(async move {
+        let session = status.lock().await.started_at;
+        tokio::time::sleep(std::time::Duration::from_secs(grace)).await;
+        {
+            let guard = status.lock().await;
+            // Stopped, restarted, or the plugin showed up
+            if !guard.active || guard.started_at != session || guard.last_plugin_seen.is_some() {
+                return;
+            }
+        }
+        let message = format!(
+            "The Roxlit Studio plugin has not connected after {grace}s. Open your place in Roblox Studio; \
+             if Studio is already open, restart it so it loads the plugin, and check that the plugin \
+             is enabled under Plugins > Manage Plugins."
+        );
+        if let Some(ref tx) = log_tx {
+            send_log(tx, "roxlit", &message);
+        }
+        let _ = on_event.send(RojoEvent::PluginNotConnected { waited_secs: grace, message });
+    });
+}
+
+/// How often the project's files are checked for changes Rojo syncs.
+const SYNC_POLL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Watches the files Rojo maps while the session runs. Rojo syncs every
+/// change but doesn't print its patches at the default log level, so each
+/// batch of changed files counts as one sync: it's added to the sync history
+/// and to `syncs`, which the playtest scheduler reads.
+fn watch_project_changes(
+    status: Arc<Mutex<crate::commands::logs::LauncherStatusInner>>,
+    project_path: String,
+    syncs: Arc<std::sync::atomic::AtomicU64>,
+) {
+    tokio::spawn(async move {
+        let session = status.lock().await.started_at;
+        let root = std::path::PathBuf::from(&project_path);
+        let Ok(mut tracker) = tokio::task::spawn_blocking(move || SyncTracker::new(&root)).await else {
+            return;
+        };
+        loop {
+            tokio::time::sleep(SYNC_POLL).await;
+            {
+                let guard = status.lock().await;
+                if !guard.active || guard.started_at != session {
+                    return;
+                }
+            }
+            let Ok((returned, changed)) = tokio::task::spawn_blocking(move || {
+                let changed = tracker.record_changes();
+                (tracker, changed)
+            })
+            .await
+            else {
+                return;
+            };
+            tracker = returned;
+            if changed > 0 {
+                syncs.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+    });
+}
+
+/// Reports the Studio plugin connecting (it polls `/status` every few
+/// seconds) and going silent, with the place it linked, as
+/// `StudioSessionStarted` / `StudioSessionEnded`.
+fn watch_studio_session(status: Arc<Mutex<crate::commands::logs::LauncherStatusInner>>, on_event: Channel<RojoEvent>) {
+    tokio::spawn(async move {
+        let session = status.lock().await.started_at;
+        let mut connected_place: Option<Option<String>> = None;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            let (running, connected, place) = {
+                let guard = status.lock().await;
+                (
+                    guard.active && guard.started_at == session,
+                    guard.last_plugin_seen.is_some_and(|t| t.elapsed() < STUDIO_HEARTBEAT_TIMEOUT),
+                    guard.linked_place_name.clone(),
+                )
+            };
+            if !running {
+                if let Some(place_name) = connected_place.take() {
+                    let _ = on_event.send(RojoEvent::StudioSessionEnded { place_name });
+                }
+                return;
+            }
+            match (connected, connected_place.is_some()) {
+                (true, false) => {
+                    connected_place = Some(place.clone());
+                    let _ = on_event.send(RojoEvent::StudioSessionStarted { place_name: place });
+                }
+                (false, true) => {
+                    if let Some(place_name) = connected_place.take() {
+                        let _ = on_event.send(RojoEvent::StudioSessionEnded { place_name });
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
+/// Plugin silence after which Studio is checked for having exited.
+const STUDIO_HEARTBEAT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Once the plugin has connected, reports a session whose plugin stops
+/// polling while no Studio process is left. Reported once per loss; re-armed
+/// when the plugin comes back. With `stop_session` the frontend stops the
+/// session on the event (stopping here would look like a Rojo crash to it).
+fn watch_studio_presence(
+    app: tauri::AppHandle,
+    status: Arc<Mutex<crate::commands::logs::LauncherStatusInner>>,
+    on_event: Channel<RojoEvent>,
+    log_tx: Option<tokio::sync::mpsc::UnboundedSender<LogLine>>,
+    stop_session: bool,
+) {
+    tokio::spawn(async move {
+        let session = status.lock().await.started_at;
+        let mut lost = false;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            let silent_for = {
+                let guard = status.lock().await;
+                if !guard.active || guard.started_at != session {
+                    return;
+                }
+                guard.last_plugin_seen.map(|t| t.elapsed())
+            };
+            match silent_for {
+                // Never connected: watch_plugin_connection reports that
+                None => continue,
+                Some(silence) if silence < STUDIO_HEARTBEAT_TIMEOUT => {
+                    lost = false;
+                    continue;
+                }
+                Some(_) if lost => continue,
+                Some(_) => {}
+            }
+            if is_studio_running(None).await {
+                continue;
+            }
+
+            lost = true;
+            let message = if stop_session {
+                "Roblox Studio closed or crashed, so the dev session was stopped. \
+                 Press Start Development to pick up where you left off."
+                    .to_string()
+            } else {
+                "Roblox Studio closed or crashed. Rojo is still serving; reopen your place in Studio \
+                 to reconnect, or stop the session."
+                    .to_string()
+            };
+            if let Some(ref tx) = log_tx {
+                send_log(tx, "roxlit", &message);
+            }
+            notifications::notify(&app, notifications::STUDIO_DISCONNECTED, "Roblox Studio disconnected", &message);
+            let _ = on_event.send(RojoEvent::StudioLost { message, stop_session });
+            if stop_session {
+                return;
+            }
+        }
+    });
+}
+
+/// `servePort` from the served project file, which Rojo uses instead of its default.
+fn project_serve_port(project_path: &str, project_file: &str) -> Option<u16> {
+    let content = std::fs::read_to_string(std::path::Path::new(project_path).join(project_file)).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+    value["servePort"].as_u64().and_then(|p| u16::try_from(p).ok())
+}
+
+/// Undoes marking the launcher active and starting the log server, for a
+/// session that fails after both.
+async fn abort_start(launcher_status: &LauncherStatus, log_server_state: &LogServerState) {
+    launcher_status.set_inactive().await;
+    log_server_state.stop().await;
+}
+
+/// Logs and emits a port conflict, naming the program holding the port.
+async fn report_port_conflict(
+    on_event: &Channel<RojoEvent>,
+    log_tx: Option<&tokio::sync::mpsc::UnboundedSender<LogLine>>,
+    service: &str,
+    port: u16,
+    fallback: Option<u16>,
+) {
+    let owner = ports::port_owner(port).await;
+    let held_by = owner.as_deref().unwrap_or("another program");
+    let message = match fallback {
+        Some(p) => format!("Port {port} is in use by {held_by}; {service} is using port {p} instead"),
+        None => format!("Port {port} is in use by {held_by}; {service} is unavailable until it is freed"),
+    };
+    if let Some(tx) = log_tx {
+        send_log(tx, "roxlit", &message);
+    }
+    let _ = on_event.send(RojoEvent::PortConflict {
+        service: service.to_string(),
+        port,
+        owner,
+        fallback,
+    });
+}
+
 /// Check recursively if a directory contains any .luau files.
 fn has_luau_files(dir: &std::path::Path) -> bool {
     if let Ok(entries) = std::fs::read_dir(dir) {
@@ -561,7 +1277,8 @@ fn move_luau_tree(src: &std::path::Path, dest: &std::path::Path) {
 }
 
 /// Download or update roxlit-mcp binary.
-/// Re-downloads when the launcher version changes (version tracked in .roxlit/bin/mcp.version).
+/// Re-downloads when the launcher version changes (version recorded in config,
+/// or .roxlit/bin/mcp.version for older installs).
 async fn ensure_mcp_binary() {
     let mcp_bin_name = if cfg!(target_os = "windows") {
         "roxlit-mcp.exe"
@@ -569,8 +1286,8 @@ async fn ensure_mcp_binary() {
         "roxlit-mcp"
     };
 
-    let bin_dir = match dirs::home_dir() {
-        Some(h) => h.join(".roxlit").join("bin"),
+    let bin_dir = match crate::commands::config::roxlit_bin_dir() {
+        Some(d) => d,
         None => return,
     };
 
@@ -580,40 +1297,32 @@ async fn ensure_mcp_binary() {
 
     // Check if binary exists AND version matches
     if mcp_path.exists() {
-        if let Ok(stored) = tokio::fs::read_to_string(&version_file).await {
-            if stored.trim() == current_version {
-                return; // Up to date
-            }
+        let recorded = crate::commands::config::load_config()
+            .await
+            .and_then(|c| c.tool_version("roxlit-mcp").map(String::from));
+        let stored = match recorded {
+            Some(v) => Some(v),
+            None => tokio::fs::read_to_string(&version_file).await.ok(),
+        };
+        if stored.is_some_and(|v| v.trim() == current_version) {
+            return; // Up to date
         }
         // Version mismatch or no version file — re-download
     }
 
     // Determine download URL
-    let url = if cfg!(target_os = "windows") && cfg!(target_arch = "x86_64") {
-        "https://github.com/Roxlit/installer/releases/latest/download/roxlit-mcp.exe".to_string()
-    } else {
+    let Some(url) = crate::commands::install::roxlit_mcp_download_url() else {
         return; // No MCP for this platform yet
     };
 
     // Best-effort download — don't block launcher startup if it fails
     let _ = tokio::fs::create_dir_all(&bin_dir).await;
-    if let Ok(response) = reqwest::get(&url).await {
-        if response.status().is_success() {
-            if let Ok(bytes) = response.bytes().await {
-                let _ = tokio::fs::write(&mcp_path, &bytes).await;
-                // Track which version this binary belongs to
-                let _ = tokio::fs::write(&version_file, current_version).await;
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let _ = tokio::fs::set_permissions(
-                        &mcp_path,
-                        std::fs::Permissions::from_mode(0o755),
-                    )
-                    .await;
-                }
-            }
-        }
+    // Conditional: a launcher update that didn't change the MCP binary only
+    // revalidates its ETag
+    if crate::commands::downloader::download_binary(&url, &mcp_path, |_| {}).await.is_ok() {
+        // Track which version this binary belongs to
+        let _ = tokio::fs::write(&version_file, current_version).await;
+        let _ = crate::commands::config::save_tool_version("roxlit-mcp", current_version);
     }
 
     // Clean up old rbxsync-mcp binary if it exists
@@ -625,9 +1334,12 @@ async fn ensure_mcp_binary() {
 ///
 /// Checks for a version marker in the existing context file. If the marker is missing
 /// (pre-versioning file) or the version is older than the current CONTEXT_VERSION,
-/// the file is regenerated. User notes (everything after "## Your Notes") are preserved.
+/// the file is regenerated. User notes (everything after "## Your Notes") are preserved,
+/// and hand edits above them are saved to a local file first; the returned message
+/// says where, for the session log. A context file the user wrote by hand is never
+/// regenerated: the context goes to ROXLIT.md instead, which that file links to.
 /// Also ensures MCP config exists if the MCP binary is available.
-fn ensure_ai_context(project_dir: &std::path::Path, project_path: &str) {
+fn ensure_ai_context(project_dir: &std::path::Path, project_path: &str) -> Option<String> {
     use crate::templates;
 
     let context_files = [
@@ -638,17 +1350,24 @@ fn ensure_ai_context(project_dir: &std::path::Path, project_path: &str) {
         "AI-CONTEXT.md",
     ];
 
-    // Find the existing context file (if any)
+    // Find the existing context file (if any). A hand-written one is left alone;
+    // the generated context lives in ROXLIT.md next to it.
     let existing_file = context_files
         .iter()
         .map(|f| project_dir.join(f))
-        .find(|p| p.exists());
+        .find(|p| p.exists())
+        .map(|path| match std::fs::read_to_string(&path) {
+            Ok(content) if !crate::commands::context::is_generated_context(&content) => {
+                project_dir.join(crate::commands::context::ROXLIT_CONTEXT_FILE)
+            }
+            _ => path,
+        });
 
-    // Check if MCP binary is available (for context variant detection)
-    let mcp_bin_name = if cfg!(target_os = "windows") { "roxlit-mcp.exe" } else { "roxlit-mcp" };
-    let mcp_available = dirs::home_dir()
-        .map(|h| h.join(".roxlit").join("bin").join(mcp_bin_name).exists())
-        .unwrap_or(false);
+    // MCP installed and enabled for this project (for context variant detection)
+    let mcp_available = crate::commands::context::mcp_enabled(project_path);
+
+    let project = crate::commands::config::find_project(project_path);
+    let compact = project.as_ref().is_some_and(|p| p.settings.compact_context);
 
     // Check if regeneration is needed
     let needs_regen = match &existing_file {
@@ -668,34 +1387,46 @@ fn ensure_ai_context(project_dir: &std::path::Path, project_path: &str) {
                 None => true, // No version marker → pre-versioning file, always regenerate
                 Some(v) => v != templates::CONTEXT_VERSION,
             };
-            // Also regenerate if MCP is now available but context was generated without it
-            let mcp_missing_from_context = mcp_available && !content.contains("Roxlit MCP server");
+            // Also regenerate if MCP was installed, enabled or disabled since the last generation
+            let mcp_variant_changed = mcp_available != content.contains("## MCP Tools");
             // Also regenerate if still referencing old rbxsync names
             let has_old_rbxsync = content.contains("RbxSync MCP server") || content.contains("rbxsync");
-            version_stale || mcp_missing_from_context || has_old_rbxsync
+            // Also regenerate if the project switched between full and compact context
+            let variant_changed = content.contains(templates::COMPACT_MARKER) != compact;
+            version_stale || mcp_variant_changed || has_old_rbxsync || variant_changed
         }
     };
 
     // Read config to find ai_tool for this project
-    let ai_tool = dirs::home_dir()
-        .and_then(|h| std::fs::read_to_string(h.join(".roxlit").join("config.json")).ok())
-        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
-        .and_then(|config| {
-            config["projects"]
-                .as_array()?
-                .iter()
-                .find(|p| p["path"].as_str() == Some(project_path))
-                .and_then(|p| p["aiTool"].as_str().map(String::from))
-        })
+    let ai_tool = project
+        .map(|p| p.ai_tool)
         .unwrap_or_else(|| "claude".to_string());
 
-    // Always ensure MCP config exists if binary is available (even if CLAUDE.md is up to date)
-    ensure_mcp_config(project_dir, &ai_tool);
+    // Always ensure MCP config exists if MCP is enabled (even if CLAUDE.md is up to date)
+    if mcp_available {
+        ensure_mcp_config(project_dir, &ai_tool);
+    }
 
     if !needs_regen {
-        return;
+        // Pick up custom packs added or removed since the last generation
+        let _ = crate::commands::context::sync_custom_packs(project_dir);
+        if let Some(ref path) = existing_file {
+            let _ = crate::commands::context::update_project_state(project_dir, path);
+        }
+        return None;
     }
 
+    // Keep hand edits to the generated part before it's replaced
+    let saved_edits = existing_file.as_ref().and_then(|path| {
+        let saved = crate::commands::context::save_user_edits(project_dir, path).ok()??;
+        Some(format!(
+            "{} was updated, but it had been edited by hand. Your edited copy was saved to {} — move what you still need into \"{}\".",
+            path.file_name().unwrap_or_default().to_string_lossy(),
+            saved.strip_prefix(project_dir).unwrap_or(&saved).display(),
+            templates::USER_NOTES_MARKER.trim_start_matches("## "),
+        ))
+    });
+
     // Extract user notes from existing file before regenerating
     let user_notes = existing_file.as_ref().and_then(|path| {
         let content = std::fs::read_to_string(path).ok()?;
@@ -723,19 +1454,14 @@ fn ensure_ai_context(project_dir: &std::path::Path, project_path: &str) {
             }
         }
     }
+    saved_edits
 }
 
 /// Ensure MCP config file exists if the MCP binary is available.
 /// This handles the case where a user upgrades Roxlit and gets MCP for the first time.
 fn ensure_mcp_config(project_dir: &std::path::Path, ai_tool: &str) {
-    let mcp_bin_name = if cfg!(target_os = "windows") {
-        "roxlit-mcp.exe"
-    } else {
-        "roxlit-mcp"
-    };
-
-    let mcp_available = dirs::home_dir()
-        .map(|h| h.join(".roxlit").join("bin").join(mcp_bin_name).exists())
+    let mcp_available = crate::commands::config::mcp_binary_path()
+        .map(|p| p.exists())
         .unwrap_or(false);
 
     if !mcp_available {
@@ -743,14 +1469,7 @@ fn ensure_mcp_config(project_dir: &std::path::Path, ai_tool: &str) {
     }
 
     // Check if MCP config already exists for this AI tool
-    let config_path = match ai_tool {
-        "claude" => Some(project_dir.join(".mcp.json")),
-        "cursor" => Some(project_dir.join(".cursor").join("mcp.json")),
-        "vscode" => Some(project_dir.join(".vscode").join("mcp.json")),
-        "windsurf" => dirs::home_dir()
-            .map(|h| h.join(".codeium").join("windsurf").join("mcp_config.json")),
-        _ => Some(project_dir.join(".mcp.json")),
-    };
+    let config_path = crate::commands::context::mcp_config_path(project_dir, ai_tool);
 
     if let Some(ref path) = config_path {
         if path.exists() {
@@ -784,85 +1503,104 @@ fn ensure_debug_module(project_dir: &std::path::Path) {
     }
 }
 
+/// What `ensure_roxlit_plugin` found in the Studio plugins folder.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum PluginCheck {
+    /// `Roxlit.rbxm` is installed and parses.
+    Installed,
+    /// No Studio plugins folder on this OS, and none configured.
+    Unsupported,
+    /// `Roxlit.rbxm` isn't installed (deleted, or setup skipped it).
+    Missing,
+    /// `Roxlit.rbxm` was corrupt and has been downloaded again.
+    Repaired,
+    /// `Roxlit.rbxm` was corrupt and has been removed; re-downloading failed.
+    Removed(String),
+}
+
+impl PluginCheck {
+    /// Line for the session log, if there's anything worth telling.
+    fn message(&self) -> Option<String> {
+        match self {
+            PluginCheck::Installed | PluginCheck::Unsupported => None,
+            PluginCheck::Missing => {
+                Some("The Roxlit Studio plugin is not installed. Run the installer again to add it.".into())
+            }
+            PluginCheck::Repaired => {
+                Some("The Roxlit Studio plugin was damaged and has been reinstalled. Restart Studio to load it.".into())
+            }
+            PluginCheck::Removed(e) => Some(format!(
+                "The Roxlit Studio plugin was damaged and has been removed ({e}). Run the installer again to reinstall it."
+            )),
+        }
+    }
+}
+
 /// Ensure the unified Roxlit Studio plugin is installed.
 ///
-/// Checks if `Roxlit.rbxm` exists in the Studio plugins folder. If not, it was
-/// either never installed or was deleted — the installer downloads it during setup,
-/// and this function just verifies it's present.
+/// The installer downloads it during setup; this verifies `Roxlit.rbxm` is
+/// present and parses as a model file. A corrupt copy is removed and
+/// downloaded again, so it never sits in the plugins folder failing to load.
+/// Copies valid plugins from a machine-wide install into this account's folder.
 /// Also cleans up old plugins (RoxlitDebug, RbxSync) that the unified plugin replaces.
-/// Non-critical — silently ignores errors.
-fn ensure_roxlit_plugin() {
-    let plugins_dir = if cfg!(target_os = "windows") {
-        dirs::data_local_dir().map(|d| d.join("Roblox").join("Plugins"))
-    } else if cfg!(target_os = "macos") {
-        dirs::home_dir().map(|d| d.join("Library").join("Roblox").join("Plugins"))
-    } else {
-        None
-    };
-
-    let plugins_dir = match plugins_dir {
+async fn ensure_roxlit_plugin() -> PluginCheck {
+    let plugins_dir = match crate::commands::config::plugins_dir() {
         Some(d) => d,
-        None => return,
+        None => return PluginCheck::Unsupported,
     };
 
-    let _ = std::fs::create_dir_all(&plugins_dir);
+    let _ = tokio::fs::create_dir_all(&plugins_dir).await;
+
+    // Seed this account from a machine-wide install, if there is one
+    if let Some(system_dir) = crate::commands::config::system_plugins_dir() {
+        if let Ok(mut entries) = tokio::fs::read_dir(&system_dir).await {
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let source = entry.path();
+                let target = plugins_dir.join(entry.file_name());
+                let is_model = source.extension().is_some_and(|e| e == "rbxm");
+                if tokio::fs::try_exists(&target).await.unwrap_or(true)
+                    || (is_model && crate::commands::rbxm::check_file(&source).await.is_err())
+                {
+                    continue;
+                }
+                let _ = tokio::fs::copy(&source, &target).await;
+            }
+        }
+    }
 
     // Clean up old plugins that the unified Roxlit plugin replaces
     for old_name in &["RoxlitDebug.rbxm", "RoxlitDebug.rbxmx", "RbxSync.rbxm", "rbxsync.rbxm"] {
-        let old_path = plugins_dir.join(old_name);
-        if old_path.exists() {
-            let _ = std::fs::remove_file(&old_path);
-        }
+        let _ = tokio::fs::remove_file(plugins_dir.join(old_name)).await;
     }
-}
-
-/// Kill orphaned roxlit-mcp/rbxsync processes from a previous session that may still hold port 44755.
-/// Users upgrading from versions that used the external binary may have a leftover process.
-async fn kill_orphaned_roxlit_mcp() {
-    #[cfg(target_os = "windows")]
-    {
-        // Kill old rbxsync processes (legacy)
-        let mut cmd = tokio::process::Command::new("taskkill");
-        cmd.args(["/F", "/IM", "rbxsync.exe"])
-            .creation_flags(0x08000000); // CREATE_NO_WINDOW
-        let _ = cmd.output().await;
 
-        // Kill roxlit-mcp processes
-        let mut cmd = tokio::process::Command::new("taskkill");
-        cmd.args(["/F", "/IM", "roxlit-mcp.exe"])
-            .creation_flags(0x08000000); // CREATE_NO_WINDOW
-        let _ = cmd.output().await;
+    let plugin_path = plugins_dir.join("Roxlit.rbxm");
+    if !tokio::fs::try_exists(&plugin_path).await.unwrap_or(false) {
+        return PluginCheck::Missing;
     }
-
-    #[cfg(not(target_os = "windows"))]
-    {
-        // Kill old rbxsync processes (legacy)
-        let mut cmd = tokio::process::Command::new("pkill");
-        cmd.args(["-f", "rbxsync serve"]);
-        let _ = cmd.output().await;
-
-        // Kill roxlit-mcp processes
-        let mut cmd = tokio::process::Command::new("pkill");
-        cmd.args(["-f", "roxlit-mcp serve"]);
-        let _ = cmd.output().await;
+    if crate::commands::install::discard_if_invalid(&plugin_path).await.is_ok() {
+        return PluginCheck::Installed;
+    }
+    match crate::commands::install::update_roxlit_plugin().await {
+        Ok(_) => PluginCheck::Repaired,
+        Err(e) => PluginCheck::Removed(e.to_string()),
     }
-
-    // Give the OS time to release the port
-    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
 }
 
 /// Auto-open Roblox Studio if the project has a linked placeId
 /// and Studio is not already running.
-async fn auto_open_studio(project_path: &str, log_tx: Option<&tokio::sync::mpsc::UnboundedSender<String>>) {
+async fn auto_open_studio(
+    project_path: &str,
+    log_tx: Option<&tokio::sync::mpsc::UnboundedSender<LogLine>>,
+) -> std::result::Result<String, String> {
     // Skip if Studio is already running — the plugin will auto-connect
     if is_studio_running(log_tx).await {
-        return;
+        return Ok("Studio was already open".into());
     }
 
     // Read the config to find the placeId/universeId for this project
     let config = match crate::commands::config::load_config().await {
         Some(c) => c,
-        None => return,
+        None => return Err("The launcher config could not be read".into()),
     };
 
     let project = config.projects.iter().find(|p| p.path == project_path);
@@ -872,11 +1610,30 @@ async fn auto_open_studio(project_path: &str, log_tx: Option<&tokio::sync::mpsc:
 
     let place_id = match place_id {
         Some(id) if id > 0 => id,
+        _ if project.and_then(|p| p.settings.open_local_place).unwrap_or(false) => {
+            if let Some(tx) = log_tx {
+                send_log(tx, "roxlit", "No linked placeId — opening the local place file...");
+            }
+            let result = match crate::commands::studio::existing_or_built_place(project_path).await {
+                Ok(place) => crate::commands::studio::open_place_file(&place).await.map(|_| place),
+                Err(e) => Err(e),
+            };
+            return match result {
+                Ok(place) => Ok(format!("Opened the local place {} in Studio", place.display())),
+                Err(e) => {
+                    let message = format!("Could not open a local place: {e}");
+                    if let Some(tx) = log_tx {
+                        send_log(tx, "roxlit", &message);
+                    }
+                    Err(message)
+                }
+            };
+        }
         _ => {
             if let Some(tx) = log_tx {
                 send_log(tx, "roxlit", "No linked placeId — open Studio manually. It will link automatically on first connect.");
             }
-            return;
+            return Err("No place is linked to this project; open it in Studio once to link it".into());
         }
     };
 
@@ -885,10 +1642,11 @@ async fn auto_open_studio(project_path: &str, log_tx: Option<&tokio::sync::mpsc:
     }
 
     open_studio_url(place_id, universe_id.unwrap_or(0)).await;
+    Ok(format!("Opening place {place_id} in Studio"))
 }
 
 /// Check if Roblox Studio is already running.
-async fn is_studio_running(log_tx: Option<&tokio::sync::mpsc::UnboundedSender<String>>) -> bool {
+pub(crate) async fn is_studio_running(log_tx: Option<&tokio::sync::mpsc::UnboundedSender<LogLine>>) -> bool {
     #[cfg(target_os = "windows")]
     {
         // Check both possible process names
@@ -952,24 +1710,3 @@ async fn open_studio_url(place_id: u64, universe_id: u64) {
             .await;
     }
 }
-
-/// Kill orphaned rojo processes from a previous session that may still hold the port.
-async fn kill_orphaned_rojo() {
-    #[cfg(target_os = "windows")]
-    {
-        let mut cmd = tokio::process::Command::new("taskkill");
-        cmd.args(["/F", "/IM", "rojo.exe"])
-            .creation_flags(0x08000000); // CREATE_NO_WINDOW
-        let _ = cmd.output().await;
-    }
-
-    #[cfg(not(target_os = "windows"))]
-    {
-        let mut cmd = tokio::process::Command::new("pkill");
-        cmd.args(["-f", "rojo serve"]);
-        let _ = cmd.output().await;
-    }
-
-    // Give the OS time to release the port
-    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-}
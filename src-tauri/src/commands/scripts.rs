@@ -0,0 +1,1050 @@
+//! Project-wide operations on the Luau scripts under a project, done in Rust
+//! so neither the frontend nor the AI flows depend on tools like ripgrep
+//! being installed.
+
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::commands::instances::{self, InstanceNode};
+use crate::commands::logs::LoggerState;
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// Directories never searched.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "Packages", "DevPackages"];
+/// Most matches `search_scripts` returns.
+const MATCH_LIMIT: usize = 500;
+/// Longest snippet returned per match.
+const SNIPPET_CHARS: usize = 200;
+
+/// One line matching a `search_scripts` query.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptMatch {
+    /// Relative to the project root, with forward slashes.
+    pub file: String,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    pub snippet: String,
+}
+
+/// Searches every .luau/.lua file in the project for `query`: a
+/// case-insensitive plain string, or a regular expression with `regex`.
+/// Files are searched in parallel; results are ordered by file and line.
+#[tauri::command]
+pub async fn search_scripts(project_path: String, query: String, regex: Option<bool>) -> Result<Vec<ScriptMatch>> {
+    let root = PathBuf::from(expand_tilde(&project_path));
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let pattern = if regex.unwrap_or(false) {
+        Regex::new(&query)
+    } else {
+        Regex::new(&format!("(?i){}", regex::escape(&query)))
+    }
+    .map_err(|e| InstallerError::Custom(format!("Invalid search pattern: {e}")))?;
+
+    tokio::task::spawn_blocking(move || {
+        let files = script_files(&root);
+        let mut matches = search_files(&root, &files, &pattern);
+        matches.truncate(MATCH_LIMIT);
+        matches
+    })
+    .await
+    .map_err(|e| InstallerError::Custom(format!("Search failed: {e}")))
+}
+
+/// Every .luau/.lua file under `root`, skipping hidden and package directories.
+pub(crate) fn script_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_scripts(root, &mut files);
+    files.sort();
+    files
+}
+
+fn collect_scripts(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            if !SKIPPED_DIRS.contains(&name.as_str()) {
+                collect_scripts(&path, out);
+            }
+        } else if name.ends_with(".luau") || name.ends_with(".lua") {
+            out.push(path);
+        }
+    }
+}
+
+/// `path` relative to `root`, with forward slashes.
+pub(crate) fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Splits the files across threads and merges their matches in file order.
+fn search_files(root: &Path, files: &[PathBuf], pattern: &Regex) -> Vec<ScriptMatch> {
+    if files.is_empty() {
+        return Vec::new();
+    }
+    let threads = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let chunk_size = files.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = files
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().flat_map(|f| search_file(root, f, pattern)).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap_or_default())
+            .collect()
+    })
+}
+
+fn search_file(root: &Path, path: &Path, pattern: &Regex) -> Vec<ScriptMatch> {
+    let Ok(content) = std::fs::read_to_string(path) else { return Vec::new() };
+    let file = relative_path(root, path);
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let found = pattern.find(line)?;
+            Some(ScriptMatch {
+                file: file.clone(),
+                line: i + 1,
+                column: line[..found.start()].chars().count() + 1,
+                snippet: line.trim().chars().take(SNIPPET_CHARS).collect(),
+            })
+        })
+        .collect()
+}
+
+// ─── Require graph ──────────────────────────────────────────────────────────
+
+/// A script in the require graph.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptNode {
+    pub file: String,
+    /// Slash-separated instance path, e.g. "ReplicatedStorage/Shared/Util".
+    pub instance_path: String,
+    pub class_name: String,
+}
+
+/// `from` requires `to` (both files relative to the project root).
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequireEdge {
+    pub from: String,
+    pub to: String,
+    pub line: usize,
+}
+
+/// A require whose target couldn't be determined or doesn't exist.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnresolvedRequire {
+    pub from: String,
+    pub line: usize,
+    pub expression: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequireGraph {
+    pub scripts: Vec<ScriptNode>,
+    pub edges: Vec<RequireEdge>,
+    pub unresolved: Vec<UnresolvedRequire>,
+    /// Groups of files that require each other in a loop.
+    pub cycles: Vec<Vec<String>>,
+}
+
+/// Parses every `require(...)` in the project's scripts into a dependency
+/// graph. Instance paths (`script.Parent.Util`, `ReplicatedStorage.Shared`
+/// through `local` service aliases, `:WaitForChild("X")`) are resolved
+/// through the Rojo project; string requires (`"./Util"`, `"@alias/Util"`)
+/// relative to the file and .luaurc aliases.
+#[tauri::command]
+pub async fn get_require_graph(project_path: String) -> Result<RequireGraph> {
+    let root = PathBuf::from(expand_tilde(&project_path));
+    tokio::task::spawn_blocking(move || require_graph(&root))
+        .await
+        .map_err(|e| InstallerError::Custom(format!("Building the require graph failed: {e}")))?
+}
+
+pub(crate) fn require_graph(root: &Path) -> Result<RequireGraph> {
+    let resolver = Resolver::new(root)?;
+    let mut edges: Vec<RequireEdge> = Vec::new();
+    let mut unresolved = Vec::new();
+    for path in script_files(root) {
+        let file = relative_path(root, &path);
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        for (line, expression, target) in resolver.requires(&file, &content) {
+            match target {
+                Some(to) if !edges.iter().any(|e| e.from == file && e.to == to) => {
+                    edges.push(RequireEdge { from: file.clone(), to, line });
+                }
+                Some(_) => {}
+                None => unresolved.push(UnresolvedRequire { from: file.clone(), line, expression }),
+            }
+        }
+    }
+
+    let cycles = find_cycles(&edges);
+    let scripts = resolver
+        .by_file
+        .into_iter()
+        .map(|(file, (instance_path, class_name))| ScriptNode { file, instance_path, class_name })
+        .collect();
+    Ok(RequireGraph { scripts, edges, unresolved, cycles })
+}
+
+/// Resolves the requires in a project's scripts to the files they load.
+struct Resolver<'a> {
+    root: &'a Path,
+    /// Script file -> (instance path, class name).
+    by_file: BTreeMap<String, (String, String)>,
+    /// Instance path -> script file.
+    by_instance: HashMap<String, String>,
+    aliases: HashMap<String, String>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(root: &'a Path) -> Result<Self> {
+        let tree = instances::project_tree(root)?;
+        let mut by_file = BTreeMap::new();
+        script_instances(root, &tree, &mut by_file);
+        let by_instance = by_file
+            .iter()
+            .map(|(file, (path, _))| (path.clone(), file.clone()))
+            .collect();
+        Ok(Self { root, by_file, by_instance, aliases: luaurc_aliases(root) })
+    }
+
+    /// Instance path of a script file, as segments.
+    fn script_path(&self, file: &str) -> Vec<String> {
+        self.by_file
+            .get(file)
+            .map(|(p, _)| p.split('/').filter(|s| !s.is_empty()).map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every require in the script at `file`: line, expression and the file
+    /// it loads (None if unresolved).
+    fn requires(&self, file: &str, content: &str) -> Vec<(usize, String, Option<String>)> {
+        let script_path = self.script_path(file);
+        let locals = local_aliases(content, &script_path);
+        find_requires(content)
+            .into_iter()
+            .map(|(line, expression)| {
+                let target = self.resolve(file, &expression, &script_path, &locals);
+                (line, expression, target)
+            })
+            .collect()
+    }
+
+    fn resolve(
+        &self,
+        file: &str,
+        expression: &str,
+        script_path: &[String],
+        locals: &HashMap<String, Vec<String>>,
+    ) -> Option<String> {
+        match string_literal(expression) {
+            Some(spec) => resolve_string_require(self.root, file, &spec, &self.aliases),
+            None => parse_chain(expression)
+                .and_then(|(base, steps)| resolve_chain(&base, &steps, script_path, locals))
+                .and_then(|target| self.by_instance.get(&target.join("/")).cloned()),
+        }
+    }
+}
+
+/// Maps each script file to its instance path and class.
+fn script_instances(root: &Path, node: &InstanceNode, out: &mut BTreeMap<String, (String, String)>) {
+    if matches!(node.class_name.as_str(), "Script" | "LocalScript" | "ModuleScript") {
+        if let Some(file) = &node.file {
+            let file = if root.join(file).is_dir() { init_file(root, file) } else { Some(file.clone()) };
+            if let Some(file) = file {
+                out.insert(file, (node.path.clone(), node.class_name.clone()));
+            }
+        }
+    }
+    for child in &node.children {
+        script_instances(root, child, out);
+    }
+}
+
+/// The init script that makes a folder a script.
+fn init_file(root: &Path, dir: &str) -> Option<String> {
+    ["init.luau", "init.lua", "init.server.luau", "init.server.lua", "init.client.luau", "init.client.lua"]
+        .iter()
+        .map(|name| format!("{dir}/{name}"))
+        .find(|file| root.join(file).exists())
+}
+
+/// Every `require(...)` argument in a script, with its 1-based line.
+fn find_requires(content: &str) -> Vec<(usize, String)> {
+    let mut found = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let code = line.split("--").next().unwrap_or_default();
+        let mut rest = code;
+        while let Some(pos) = rest.find("require") {
+            let before_ok = rest[..pos].chars().last().is_none_or(|c| !(c.is_alphanumeric() || c == '_'));
+            rest = &rest[pos + "require".len()..];
+            let Some(args) = rest.trim_start().strip_prefix('(') else { continue };
+            if !before_ok {
+                continue;
+            }
+            let mut depth = 1;
+            let end = args.char_indices().find_map(|(idx, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(idx)
+            });
+            if let Some(end) = end {
+                found.push((i + 1, args[..end].trim().to_string()));
+                rest = &args[end + 1..];
+            }
+        }
+    }
+    found
+}
+
+/// The string a require argument is made of, if it's a plain string literal.
+fn string_literal(expression: &str) -> Option<String> {
+    let mut rest = expression.trim();
+    let value = take_string(&mut rest)?;
+    rest.trim().is_empty().then_some(value)
+}
+
+/// One step along an instance path expression.
+#[derive(Debug, PartialEq)]
+enum Step {
+    Parent,
+    Child(String),
+}
+
+/// Splits `script.Parent:WaitForChild("Util")` into its base identifier and
+/// steps. None for anything that isn't a plain instance path.
+fn parse_chain(expression: &str) -> Option<(String, Vec<Step>)> {
+    let mut rest = expression.trim();
+    let base = take_ident(&mut rest)?;
+    let mut steps = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return Some((base, steps));
+        }
+        if let Some(after) = rest.strip_prefix('.') {
+            rest = after.trim_start();
+            let name = take_ident(&mut rest)?;
+            steps.push(if name == "Parent" { Step::Parent } else { Step::Child(name) });
+        } else if let Some(after) = rest.strip_prefix(':') {
+            rest = after.trim_start();
+            let method = take_ident(&mut rest)?;
+            if !matches!(method.as_str(), "WaitForChild" | "FindFirstChild" | "GetService") {
+                return None;
+            }
+            rest = rest.trim_start().strip_prefix('(')?.trim_start();
+            let name = take_string(&mut rest)?;
+            // Skip a timeout or `recursive` argument
+            let close = rest.find(')')?;
+            let extra = rest[..close].trim();
+            if !extra.is_empty() && !extra.starts_with(',') {
+                return None;
+            }
+            rest = &rest[close + 1..];
+            steps.push(Step::Child(name));
+        } else if let Some(after) = rest.strip_prefix('[') {
+            rest = after.trim_start();
+            let name = take_string(&mut rest)?;
+            rest = rest.trim_start().strip_prefix(']')?;
+            steps.push(Step::Child(name));
+        } else {
+            return None;
+        }
+    }
+}
+
+fn take_ident(rest: &mut &str) -> Option<String> {
+    let end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    if end == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let (ident, after) = rest.split_at(end);
+    *rest = after;
+    Some(ident.to_string())
+}
+
+fn take_string(rest: &mut &str) -> Option<String> {
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let body = &rest[1..];
+    let end = body.find(quote)?;
+    let value = body[..end].to_string();
+    *rest = &body[end + 1..];
+    Some(value)
+}
+
+/// Instance path (from the DataModel) an expression points at.
+fn resolve_chain(
+    base: &str,
+    steps: &[Step],
+    script_path: &[String],
+    locals: &HashMap<String, Vec<String>>,
+) -> Option<Vec<String>> {
+    let mut path = match base {
+        "script" if !script_path.is_empty() => script_path.to_vec(),
+        "game" => Vec::new(),
+        other => locals.get(other)?.clone(),
+    };
+    for step in steps {
+        match step {
+            Step::Parent => {
+                path.pop()?;
+            }
+            Step::Child(name) => path.push(name.clone()),
+        }
+    }
+    Some(path)
+}
+
+/// `local X = <instance path>` declarations, e.g. service aliases.
+fn local_aliases(content: &str, script_path: &[String]) -> HashMap<String, Vec<String>> {
+    let mut locals = HashMap::new();
+    for line in content.lines() {
+        let Some(decl) = line.trim_start().strip_prefix("local ") else { continue };
+        let Some((name, value)) = decl.split_once('=') else { continue };
+        let name = name.trim();
+        if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let value = value.split("--").next().unwrap_or_default();
+        if let Some(path) = parse_chain(value).and_then(|(base, steps)| resolve_chain(&base, &steps, script_path, &locals)) {
+            locals.insert(name.to_string(), path);
+        }
+    }
+    locals
+}
+
+/// `aliases` from the project's .luaurc.
+fn luaurc_aliases(root: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(root.join(".luaurc"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|value| value["aliases"].as_object().cloned())
+        .map(|aliases| {
+            aliases
+                .into_iter()
+                .filter_map(|(k, v)| v.as_str().map(|v| (k.to_lowercase(), v.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolves `"./Util"`, `"../Shared/Util"` or `"@alias/Util"` to a script file.
+fn resolve_string_require(root: &Path, from: &str, spec: &str, aliases: &HashMap<String, String>) -> Option<String> {
+    let (mut parts, rest): (Vec<&str>, &str) = if let Some(aliased) = spec.strip_prefix('@') {
+        let (alias, rest) = aliased.split_once('/').unwrap_or((aliased, ""));
+        (aliases.get(&alias.to_lowercase())?.split('/').collect(), rest)
+    } else if spec.starts_with("./") || spec.starts_with("../") {
+        let mut dir: Vec<&str> = from.split('/').collect();
+        dir.pop();
+        (dir, spec)
+    } else {
+        return None;
+    };
+    for part in rest.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            name => parts.push(name),
+        }
+    }
+    let base = parts.into_iter().filter(|p| !p.is_empty() && *p != ".").collect::<Vec<_>>().join("/");
+    [".luau", ".lua", "/init.luau", "/init.lua"]
+        .iter()
+        .map(|ext| format!("{base}{ext}"))
+        .find(|file| root.join(file).is_file())
+}
+
+/// Strongly connected groups of two or more files, plus self-requires.
+fn find_cycles(edges: &[RequireEdge]) -> Vec<Vec<String>> {
+    let mut graph: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for edge in edges {
+        graph.entry(&edge.from).or_default().push(&edge.to);
+        graph.entry(&edge.to).or_default();
+    }
+
+    // Tarjan's algorithm
+    struct State<'a> {
+        index: HashMap<&'a str, usize>,
+        low: HashMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        groups: Vec<Vec<String>>,
+    }
+    fn visit<'a>(node: &'a str, graph: &BTreeMap<&'a str, Vec<&'a str>>, s: &mut State<'a>) {
+        let i = s.index.len();
+        s.index.insert(node, i);
+        s.low.insert(node, i);
+        s.stack.push(node);
+        s.on_stack.insert(node);
+        for &next in &graph[node] {
+            if !s.index.contains_key(next) {
+                visit(next, graph, s);
+                let low = s.low[node].min(s.low[next]);
+                s.low.insert(node, low);
+            } else if s.on_stack.contains(next) {
+                let low = s.low[node].min(s.index[next]);
+                s.low.insert(node, low);
+            }
+        }
+        if s.low[node] == s.index[node] {
+            let mut group = Vec::new();
+            while let Some(member) = s.stack.pop() {
+                s.on_stack.remove(member);
+                group.push(member.to_string());
+                if member == node {
+                    break;
+                }
+            }
+            if group.len() > 1 || graph[node].contains(&node) {
+                group.sort();
+                s.groups.push(group);
+            }
+        }
+    }
+
+    let mut state = State {
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: BTreeSet::new(),
+        groups: Vec::new(),
+    };
+    for &node in graph.keys() {
+        if !state.index.contains_key(node) {
+            visit(node, &graph, &mut state);
+        }
+    }
+    state.groups
+}
+
+// ─── Moving scripts ─────────────────────────────────────────────────────────
+
+/// A require rewritten by `move_script`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequireUpdate {
+    pub file: String,
+    pub line: usize,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveReport {
+    pub from: String,
+    pub to: String,
+    pub updated: Vec<RequireUpdate>,
+    /// Requires that may load the script but couldn't be followed or
+    /// rewritten; these need checking by hand.
+    pub ambiguous: Vec<UnresolvedRequire>,
+}
+
+/// Moves a script (paths relative to the project) and rewrites the requires
+/// that load it, plus its own relative requires, to match the new location.
+/// Best-effort: requires built at runtime can't be followed and are reported
+/// as ambiguous. The move is written to the session log.
+#[tauri::command]
+pub async fn move_script(
+    project_path: String,
+    old_path: String,
+    new_path: String,
+    logger_state: tauri::State<'_, LoggerState>,
+) -> Result<MoveReport> {
+    let root = PathBuf::from(expand_tilde(&project_path));
+    let normalize = |p: &str| p.replace('\\', "/").trim_start_matches("./").to_string();
+    let (from, to) = (normalize(&old_path), normalize(&new_path));
+    let report = tokio::task::spawn_blocking(move || move_file(&root, &from, &to))
+        .await
+        .map_err(|e| InstallerError::Custom(format!("Moving the script failed: {e}")))??;
+
+    let message = format!(
+        "Moved {} to {}: updated {} require(s), {} to check by hand",
+        report.from,
+        report.to,
+        report.updated.len(),
+        report.ambiguous.len()
+    );
+    logger_state.log_system("roxlit", &[message]).await;
+    Ok(report)
+}
+
+/// A relative path that stays inside the project: no root, drive prefix or `..`.
+fn is_project_relative(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_) | std::path::Component::CurDir))
+}
+
+fn move_file(root: &Path, from: &str, to: &str) -> Result<MoveReport> {
+    for path in [from, to] {
+        if !is_project_relative(path) {
+            return Err(InstallerError::Custom(format!("{path} is not a path inside the project")));
+        }
+    }
+    let (source, dest) = (root.join(from), root.join(to));
+    if !source.starts_with(root) || !dest.starts_with(root) {
+        return Err(InstallerError::Custom("Scripts can only be moved within the project".into()));
+    }
+    if !source.is_file() {
+        return Err(InstallerError::Custom(format!("{from} doesn't exist")));
+    }
+    if dest.exists() {
+        return Err(InstallerError::Custom(format!("{to} already exists")));
+    }
+    if !matches!(dest.extension().and_then(|e| e.to_str()), Some("luau" | "lua")) {
+        return Err(InstallerError::Custom("The new path must end in .luau or .lua".into()));
+    }
+    let file_name = |file: &str| file.rsplit('/').next().unwrap_or_default().split('.').next().unwrap_or_default().to_string();
+    if file_name(from) == "init" || file_name(to) == "init" {
+        return Err(InstallerError::Custom("Init scripts belong to their folder; move the folder instead".into()));
+    }
+
+    // Requires that load the script, and the script's own, before the move
+    let before = Resolver::new(root)?;
+    let name = before
+        .by_file
+        .get(from)
+        .and_then(|(path, _)| path.rsplit('/').next().map(String::from))
+        .unwrap_or_else(|| file_name(from));
+    let mut pending = Vec::new();
+    let mut ambiguous = Vec::new();
+    for path in script_files(root) {
+        let file = relative_path(root, &path);
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        for (line, expression, target) in before.requires(&file, &content) {
+            match target {
+                Some(target) if target == from || file == from => pending.push((file.clone(), line, expression, target)),
+                None if expression.contains(&name) => {
+                    ambiguous.push(UnresolvedRequire { from: file.clone(), line, expression });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(dir) = dest.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::rename(&source, &dest)?;
+
+    let after = Resolver::new(root)?;
+    let renamed = |file: &str| if file == from { to.to_string() } else { file.to_string() };
+    let mut by_file: BTreeMap<String, Vec<(usize, String, String)>> = BTreeMap::new();
+    for (file, line, expression, target) in pending {
+        by_file.entry(renamed(&file)).or_default().push((line, expression, renamed(&target)));
+    }
+
+    let mut updated = Vec::new();
+    for (file, requires) in by_file {
+        let path = root.join(&file);
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        let script_path = after.script_path(&file);
+        let locals = local_aliases(&content, &script_path);
+        let resolves_to = |expression: &str, target: &str| {
+            after.resolve(&file, expression, &script_path, &locals).as_deref() == Some(target)
+        };
+        let mut lines: Vec<String> = content.split('\n').map(String::from).collect();
+        let mut changed = false;
+        for (line, expression, target) in requires {
+            if resolves_to(&expression, &target) {
+                continue;
+            }
+            let rewritten = match string_literal(&expression) {
+                Some(_) => Some(string_expression(&expression, &file, &target, &after.aliases)),
+                None => after.by_file.get(&target).and_then(|(instance, _)| {
+                    let instance: Vec<String> = instance.split('/').map(String::from).collect();
+                    chain_expression(&expression, &instance, &script_path, &locals)
+                }),
+            }
+            .filter(|new| resolves_to(new, &target));
+            match (rewritten, lines.get_mut(line - 1)) {
+                (Some(new), Some(text)) if text.contains(&expression) => {
+                    *text = text.replacen(&expression, &new, 1);
+                    changed = true;
+                    updated.push(RequireUpdate { file: file.clone(), line, old: expression, new });
+                }
+                _ => ambiguous.push(UnresolvedRequire { from: file.clone(), line, expression }),
+            }
+        }
+        if changed {
+            std::fs::write(&path, lines.join("\n"))?;
+        }
+    }
+
+    Ok(MoveReport { from: from.to_string(), to: to.to_string(), updated, ambiguous })
+}
+
+/// A string require from the script `from` to the file `target`, keeping
+/// `original`'s quotes and its use of an alias.
+fn string_expression(original: &str, from: &str, target: &str, aliases: &HashMap<String, String>) -> String {
+    let quote = original.trim().chars().next().unwrap_or('"');
+    let module = target
+        .trim_end_matches(".luau")
+        .trim_end_matches(".lua")
+        .trim_end_matches("/init");
+
+    let aliased = string_literal(original)
+        .is_some_and(|spec| spec.starts_with('@'))
+        .then(|| {
+            aliases
+                .iter()
+                .filter_map(|(alias, dir)| {
+                    let dir = dir.trim_start_matches("./").trim_end_matches('/');
+                    let rest = module.strip_prefix(dir)?;
+                    (rest.is_empty() || rest.starts_with('/')).then(|| (dir.len(), format!("@{alias}{rest}")))
+                })
+                .max()
+                .map(|(_, spec)| spec)
+        })
+        .flatten();
+
+    let spec = aliased.unwrap_or_else(|| {
+        let dir: Vec<&str> = from.split('/').collect();
+        let dir = &dir[..dir.len() - 1];
+        let parts: Vec<&str> = module.split('/').collect();
+        let common = dir.iter().zip(&parts).take_while(|(a, b)| a == b).count();
+        let up = dir.len() - common;
+        let rest = parts[common..].join("/");
+        if up == 0 {
+            format!("./{rest}")
+        } else {
+            format!("{}{rest}", "../".repeat(up))
+        }
+    });
+    format!("{quote}{spec}{quote}")
+}
+
+/// An instance path expression from the script at `script_path` to
+/// `target`, in the style of `original`: relative to `script` if it was,
+/// else from a `local` alias or `game:GetService`. `:WaitForChild` is kept
+/// if `original` used it.
+fn chain_expression(
+    original: &str,
+    target: &[String],
+    script_path: &[String],
+    locals: &HashMap<String, Vec<String>>,
+) -> Option<String> {
+    let (base, _) = parse_chain(original)?;
+    let wait = original.contains(":WaitForChild(");
+    let child = |name: &str| {
+        let is_ident = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if wait {
+            format!(":WaitForChild(\"{name}\")")
+        } else if is_ident {
+            format!(".{name}")
+        } else {
+            format!("[\"{name}\"]")
+        }
+    };
+
+    let (mut expression, rest) = if base == "script" {
+        let common = script_path.iter().zip(target).take_while(|(a, b)| a == b).count();
+        ("script".to_string() + &".Parent".repeat(script_path.len() - common), &target[common..])
+    } else if let Some((alias, path)) = locals
+        .iter()
+        .filter(|(_, path)| !path.is_empty() && target.starts_with(path))
+        .max_by_key(|(alias, path)| (path.len(), **alias == base))
+    {
+        (alias.clone(), &target[path.len()..])
+    } else {
+        let (service, rest) = target.split_first()?;
+        (format!("game:GetService(\"{service}\")"), rest)
+    };
+    for name in rest {
+        expression.push_str(&child(name));
+    }
+    Some(expression)
+}
+
+// ─── Unused code ────────────────────────────────────────────────────────────
+
+/// Remote APIs only called from server code.
+const SERVER_REMOTE_APIS: &[&str] = &["FireClient", "FireAllClients", "OnServerEvent", "InvokeClient", "OnServerInvoke"];
+/// Remote APIs only called from client code.
+const CLIENT_REMOTE_APIS: &[&str] = &["FireServer", "OnClientEvent", "InvokeServer", "OnClientInvoke"];
+/// Classes whose instances are remotes.
+const REMOTE_CLASSES: &[&str] = &["RemoteEvent", "UnreliableRemoteEvent", "RemoteFunction"];
+
+/// Where a remote is used. One side being empty means the other side's
+/// fires or listeners go nowhere.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteUsage {
+    pub name: String,
+    /// Files using it through server-side APIs (`OnServerEvent`, `FireClient`, ...).
+    pub server: Vec<String>,
+    /// Files using it through client-side APIs (`FireServer`, `OnClientEvent`, ...).
+    pub client: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedReport {
+    /// ModuleScripts no script requires.
+    pub unused_modules: Vec<ScriptNode>,
+    /// Remotes used on only one side, or not at all.
+    pub one_sided_remotes: Vec<RemoteUsage>,
+    /// Requires that couldn't be resolved; any of them may load a module
+    /// listed as unused.
+    pub unresolved_requires: usize,
+}
+
+/// Reports ModuleScripts that are never required and remotes referenced on
+/// only one side of the client/server boundary, from the require graph and
+/// a scan of remote API calls.
+#[tauri::command]
+pub async fn find_unused_modules(project_path: String) -> Result<UnusedReport> {
+    let root = PathBuf::from(expand_tilde(&project_path));
+    tokio::task::spawn_blocking(move || unused_report(&root))
+        .await
+        .map_err(|e| InstallerError::Custom(format!("Scanning for unused code failed: {e}")))?
+}
+
+fn unused_report(root: &Path) -> Result<UnusedReport> {
+    let graph = require_graph(root)?;
+    let required: BTreeSet<&str> = graph.edges.iter().map(|e| e.to.as_str()).collect();
+    let unused_modules = graph
+        .scripts
+        .iter()
+        .filter(|s| s.class_name == "ModuleScript" && !required.contains(s.file.as_str()))
+        .cloned()
+        .collect();
+
+    let mut remotes: BTreeMap<String, RemoteUsage> = BTreeMap::new();
+    let mut add_remote = |name: &str| {
+        remotes.entry(name.to_string()).or_insert_with(|| RemoteUsage {
+            name: name.to_string(),
+            server: Vec::new(),
+            client: Vec::new(),
+        });
+    };
+    let tree = instances::project_tree(root)?;
+    for name in remote_instances(&tree) {
+        add_remote(&name);
+    }
+    let files: Vec<(String, String)> = script_files(root)
+        .into_iter()
+        .filter_map(|path| Some((relative_path(root, &path), std::fs::read_to_string(&path).ok()?)))
+        .collect();
+    for (_, content) in &files {
+        for name in created_remotes(content) {
+            add_remote(&name);
+        }
+    }
+
+    for (file, content) in &files {
+        for (name, server_side) in remote_calls(content, &remotes) {
+            if let Some(usage) = remotes.get_mut(&name) {
+                let side = if server_side { &mut usage.server } else { &mut usage.client };
+                if !side.contains(file) {
+                    side.push(file.clone());
+                }
+            }
+        }
+    }
+
+    Ok(UnusedReport {
+        unused_modules,
+        one_sided_remotes: remotes
+            .into_values()
+            .filter(|r| r.server.is_empty() || r.client.is_empty())
+            .collect(),
+        unresolved_requires: graph.unresolved.len(),
+    })
+}
+
+/// Names of remotes defined in the project tree (.model.json, project.json).
+fn remote_instances(node: &InstanceNode) -> Vec<String> {
+    let mut names = Vec::new();
+    if REMOTE_CLASSES.contains(&node.class_name.as_str()) {
+        names.push(node.name.clone());
+    }
+    for child in &node.children {
+        names.extend(remote_instances(child));
+    }
+    names
+}
+
+/// Names of remotes a script creates: `local r = Instance.new("RemoteEvent")`
+/// followed by `r.Name = "Damage"`.
+fn created_remotes(content: &str) -> Vec<String> {
+    let created = Regex::new(r#"local\s+(\w+)\s*=\s*Instance\.new\(\s*["'](\w+)["']"#).expect("valid regex");
+    let named = Regex::new(r#"(\w+)\.Name\s*=\s*["']([^"']+)["']"#).expect("valid regex");
+    let vars: BTreeSet<&str> = created
+        .captures_iter(content)
+        .filter(|c| REMOTE_CLASSES.contains(&&c[2]))
+        .map(|c| c.get(1).map_or("", |m| m.as_str()))
+        .collect();
+    named
+        .captures_iter(content)
+        .filter(|c| vars.contains(&c[1]))
+        .map(|c| c[2].to_string())
+        .collect()
+}
+
+/// Remote API calls in a script: (remote name, is a server-side API).
+/// The receiver is matched by name, through a `local` bound to the remote,
+/// or a `WaitForChild("Name")`/`FindFirstChild("Name")` right before the call.
+fn remote_calls(content: &str, remotes: &BTreeMap<String, RemoteUsage>) -> Vec<(String, bool)> {
+    let apis = [SERVER_REMOTE_APIS, CLIENT_REMOTE_APIS].concat().join("|");
+    let by_ident = Regex::new(&format!(r"(\w+)\s*[:.]\s*({apis})\b")).expect("valid regex");
+    let by_string = Regex::new(&format!(r#"["'](\w+)["']\s*\)\s*[:.]\s*({apis})\b"#)).expect("valid regex");
+
+    // local DamageEvent = Remotes:WaitForChild("Damage")
+    let mut bindings: HashMap<String, String> = HashMap::new();
+    for line in content.lines() {
+        let Some(decl) = line.trim_start().strip_prefix("local ") else { continue };
+        let Some((var, value)) = decl.split_once('=') else { continue };
+        let last = parse_chain(value.split("--").next().unwrap_or_default()).and_then(|(base, steps)| match steps.last() {
+            Some(Step::Child(name)) => Some(name.clone()),
+            Some(Step::Parent) => None,
+            None => Some(base),
+        });
+        if let Some(name) = last.filter(|n| remotes.contains_key(n)) {
+            bindings.insert(var.trim().to_string(), name);
+        }
+    }
+
+    let mut calls = Vec::new();
+    for caps in by_ident.captures_iter(content).chain(by_string.captures_iter(content)) {
+        let receiver = &caps[1];
+        let name = bindings
+            .get(receiver)
+            .cloned()
+            .or_else(|| remotes.contains_key(receiver).then(|| receiver.to_string()));
+        if let Some(name) = name {
+            calls.push((name, SERVER_REMOTE_APIS.contains(&&caps[2])));
+        }
+    }
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_project_relative() {
+        assert!(is_project_relative("src/shared/Util.luau"));
+        assert!(!is_project_relative("../outside.luau"));
+        assert!(!is_project_relative("src/../../outside.luau"));
+        assert!(!is_project_relative("/etc/passwd"));
+        assert!(!is_project_relative(""));
+    }
+
+    #[test]
+    fn test_search_file() {
+        let root = std::env::temp_dir().join(format!("roxlit-search-test-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        let file = root.join("src").join("Main.server.luau");
+        std::fs::write(&file, "local x = 1\n\tprint(\"Hello\")\n").unwrap();
+
+        let pattern = Regex::new(&format!("(?i){}", regex::escape("hello"))).unwrap();
+        let matches = search_file(&root, &file, &pattern);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].file, "src/Main.server.luau");
+        assert_eq!((matches[0].line, matches[0].column), (2, 9));
+        assert_eq!(matches[0].snippet, "print(\"Hello\")");
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_resolve_require_chain() {
+        let script: Vec<String> = ["ServerScriptService", "Main"].iter().map(|s| s.to_string()).collect();
+        let locals = local_aliases(
+            "local ReplicatedStorage = game:GetService(\"ReplicatedStorage\")\nlocal Shared = ReplicatedStorage.Shared",
+            &script,
+        );
+        let resolve = |expr: &str| {
+            let (base, steps) = parse_chain(expr)?;
+            resolve_chain(&base, &steps, &script, &locals).map(|p| p.join("/"))
+        };
+        assert_eq!(resolve("script.Parent.Util").as_deref(), Some("ServerScriptService/Util"));
+        assert_eq!(resolve("Shared:WaitForChild(\"Net\", 5)").as_deref(), Some("ReplicatedStorage/Shared/Net"));
+        assert_eq!(resolve("game.ReplicatedStorage[\"Config\"]").as_deref(), Some("ReplicatedStorage/Config"));
+        assert_eq!(resolve("getModule()"), None);
+    }
+
+    #[test]
+    fn test_move_expressions() {
+        let path = |p: &str| p.split('/').map(String::from).collect::<Vec<_>>();
+        let script = path("ServerScriptService/Combat/Main");
+        let locals = local_aliases("local ReplicatedStorage = game:GetService(\"ReplicatedStorage\")", &script);
+
+        let to_util = path("ServerScriptService/Shared/Util");
+        assert_eq!(
+            chain_expression("script.Parent.Util", &to_util, &script, &locals).as_deref(),
+            Some("script.Parent.Parent.Shared.Util")
+        );
+        let to_net = path("ReplicatedStorage/Modules/Net");
+        assert_eq!(
+            chain_expression("ReplicatedStorage:WaitForChild(\"Net\")", &to_net, &script, &locals).as_deref(),
+            Some("ReplicatedStorage:WaitForChild(\"Modules\"):WaitForChild(\"Net\")")
+        );
+
+        let aliases = HashMap::from([("shared".to_string(), "src/shared".to_string())]);
+        assert_eq!(
+            string_expression("'./Util'", "src/server/Main.server.luau", "src/shared/Util.luau", &aliases),
+            "'../shared/Util'"
+        );
+        assert_eq!(
+            string_expression("\"@shared/Util\"", "src/server/Main.server.luau", "src/shared/lib/Util/init.luau", &aliases),
+            "\"@shared/lib/Util\""
+        );
+    }
+
+    #[test]
+    fn test_remote_calls() {
+        let mut remotes = BTreeMap::new();
+        for name in ["Damage", "Ping"] {
+            remotes.insert(name.to_string(), RemoteUsage { name: name.into(), server: vec![], client: vec![] });
+        }
+        let client = "local DamageEvent = Remotes:WaitForChild(\"Damage\")\nDamageEvent:FireServer(10)\nRemotes:WaitForChild(\"Ping\").OnClientEvent:Connect(print)";
+        assert_eq!(remote_calls(client, &remotes), vec![("Damage".into(), false), ("Ping".into(), false)]);
+
+        let server = "local r = Instance.new(\"RemoteEvent\")\nr.Name = \"Shoot\"";
+        assert_eq!(created_remotes(server), vec!["Shoot".to_string()]);
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let edge = |from: &str, to: &str| RequireEdge { from: from.into(), to: to.into(), line: 1 };
+        let cycles = find_cycles(&[edge("a", "b"), edge("b", "a"), edge("b", "c")]);
+        assert_eq!(cycles, vec![vec!["a".to_string(), "b".to_string()]]);
+    }
+}
@@ -0,0 +1,214 @@
+//! Advisory lock against serving one project from two places at once (two
+//! machines sharing a synced folder, or two Roxlit instances), which makes
+//! both write synced changes and backups into the same files. start_rojo writes
+//! `.roxlit/session.lock` with who holds it and refreshes it while the
+//! session runs; a lock that stops being refreshed, or whose process is gone,
+//! is stale and taken over. A start that fails after taking the lock
+//! releases it again (see `LockGuard`).
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::commands::logs::{format_timestamp, unix_millis};
+use crate::error::{InstallerError, Result};
+use crate::util::expand_tilde;
+
+/// How often a running session refreshes its lock.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(60);
+/// A lock not refreshed for this long belongs to a session that's gone.
+const STALE_AFTER_SECS: u64 = 3 * 60;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionLock {
+    pub host: String,
+    pub pid: u32,
+    /// When the session started (ISO 8601).
+    pub started_at: String,
+    /// Unix seconds of the last refresh.
+    pub heartbeat: u64,
+}
+
+impl SessionLock {
+    fn is_ours(&self) -> bool {
+        self.pid == std::process::id() && self.host == host_name()
+    }
+
+    /// The session that wrote it is still running, as far as can be told:
+    /// on this machine its process must exist, elsewhere it must have
+    /// refreshed the lock recently.
+    fn is_active(&self, now: u64) -> bool {
+        if now.saturating_sub(self.heartbeat) > STALE_AFTER_SECS {
+            return false;
+        }
+        self.host != host_name() || process_alive(self.pid)
+    }
+}
+
+fn lock_path(project_root: &Path) -> PathBuf {
+    project_root.join(".roxlit").join("session.lock")
+}
+
+fn read_lock(project_root: &Path) -> Option<SessionLock> {
+    let content = std::fs::read_to_string(lock_path(project_root)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_lock(project_root: &Path, lock: &SessionLock) -> Result<()> {
+    let path = lock_path(project_root);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(lock).map_err(|e| InstallerError::Custom(e.to_string()))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Name of this machine, to tell sessions on other machines apart.
+fn host_name() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+fn process_alive(pid: u32) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {pid}"), "/FO", "CSV", "/NH"])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .is_ok_and(|o| crate::util::tasklist_image(&String::from_utf8_lossy(&o.stdout), pid).is_some())
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+}
+
+/// The lock taken by `acquire`, with the task refreshing it. Dropped without
+/// `keep` (a start that failed part-way), it stops the heartbeat and releases
+/// the lock, so other machines aren't locked out of a project nobody serves.
+pub(crate) struct LockGuard {
+    root: PathBuf,
+    heartbeat: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl LockGuard {
+    /// The session is up: keeps the lock, returning the heartbeat task for
+    /// stop_rojo to abort.
+    pub(crate) fn keep(mut self) -> Option<tokio::task::JoinHandle<()>> {
+        self.heartbeat.take()
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.abort();
+            release(&self.root);
+        }
+    }
+}
+
+/// Takes the project's lock for this session and starts refreshing it. Fails
+/// if another session holds it; a stale lock is replaced, and the returned
+/// note says whose it was.
+pub(crate) fn acquire(project_root: &Path) -> Result<(LockGuard, Option<String>)> {
+    let now = unix_millis() / 1000;
+    let previous = read_lock(project_root).filter(|lock| !lock.is_ours());
+    if let Some(lock) = previous.as_ref().filter(|lock| lock.is_active(now)) {
+        return Err(InstallerError::Custom(format!(
+            "This project is already being served by Roxlit on {} (pid {}, since {}). \
+             Stop that session first. If it's no longer running, unlock the project and try again.",
+            lock.host, lock.pid, lock.started_at
+        )));
+    }
+    write_lock(
+        project_root,
+        &SessionLock {
+            host: host_name(),
+            pid: std::process::id(),
+            started_at: format_timestamp(now),
+            heartbeat: now,
+        },
+    )?;
+    let guard = LockGuard {
+        root: project_root.to_path_buf(),
+        heartbeat: Some(spawn_heartbeat(project_root.to_path_buf())),
+    };
+    let note = previous.map(|lock| {
+        format!(
+            "Took over a stale session lock from {} (pid {}, started {})",
+            lock.host, lock.pid, lock.started_at
+        )
+    });
+    Ok((guard, note))
+}
+
+/// Refreshes the lock while this session holds it. Ends once the lock is
+/// released or taken over.
+fn spawn_heartbeat(project_root: PathBuf) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+            let Some(mut lock) = read_lock(&project_root).filter(SessionLock::is_ours) else {
+                break;
+            };
+            lock.heartbeat = unix_millis() / 1000;
+            if write_lock(&project_root, &lock).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// Removes the lock if this session holds it.
+pub(crate) fn release(project_root: &Path) {
+    if read_lock(project_root).is_some_and(|lock| lock.is_ours()) {
+        let _ = std::fs::remove_file(lock_path(project_root));
+    }
+}
+
+/// Removes a project's session lock whoever holds it, for a lock left by a
+/// session that can't be stopped normally (e.g. a machine that's offline).
+#[tauri::command]
+pub async fn force_unlock_project(project_path: String) -> Result<Option<SessionLock>> {
+    let root = PathBuf::from(expand_tilde(&project_path));
+    let lock = read_lock(&root);
+    if lock.is_some() {
+        std::fs::remove_file(lock_path(&root))?;
+    }
+    Ok(lock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_is_active() {
+        let now = 1_000_000;
+        let remote = SessionLock {
+            host: format!("{}-other", host_name()),
+            pid: 1,
+            started_at: String::new(),
+            heartbeat: now - 30,
+        };
+        assert!(remote.is_active(now));
+        assert!(!SessionLock { heartbeat: now - STALE_AFTER_SECS - 1, ..remote.clone() }.is_active(now));
+
+        let local = SessionLock { host: host_name(), pid: std::process::id(), ..remote };
+        assert!(local.is_active(now));
+        assert!(local.is_ours());
+    }
+}
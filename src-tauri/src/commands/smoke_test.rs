@@ -0,0 +1,175 @@
+//! First-run smoke test: exercises the whole chain (Rojo, Studio plugin,
+//! MCP relay, log capture) once, so a broken setup shows up before the user
+//! writes any code.
+
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::logs::{LauncherStatus, LogServerState, LoggerState, McpState, TelemetryState};
+use crate::commands::rojo::{self, RojoEvent, RojoProcess};
+use crate::commands::studio;
+use crate::error::Result;
+use crate::util::expand_tilde;
+
+/// How long to wait for Rojo to report its port.
+const ROJO_TIMEOUT: Duration = Duration::from_secs(20);
+/// How long to wait for the Studio plugin (the user may still be opening Studio).
+const PLUGIN_TIMEOUT: Duration = Duration::from_secs(120);
+/// How long to wait for the printed marker to reach output.log.
+const LOG_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// One line of the smoke test checklist.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmokeCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl SmokeCheck {
+    fn new(name: &str, passed: bool, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Starts development (if not already running), waits for the Studio plugin,
+/// runs a trivial `run_code` through the launcher's MCP relay and checks the
+/// printed line arrives in output.log. Stops Rojo again if it started it.
+/// Later checks are skipped (and fail) once an earlier one fails.
+#[tauri::command]
+pub async fn run_smoke_test(
+    app: AppHandle,
+    project_path: String,
+    on_event: Channel<RojoEvent>,
+) -> Result<Vec<SmokeCheck>> {
+    let project_path = expand_tilde(&project_path);
+    let mut checks = Vec::new();
+
+    let already_running = rojo::get_rojo_status(app.state::<RojoProcess>()).await?;
+    if !already_running {
+        if let Err(e) = rojo::start_rojo(
+            app.clone(),
+            project_path.clone(),
+            on_event,
+            app.state::<RojoProcess>(),
+            app.state::<LoggerState>(),
+            app.state::<LogServerState>(),
+            app.state::<LauncherStatus>(),
+            app.state::<McpState>(),
+            app.state::<TelemetryState>(),
+            None,
+        )
+        .await
+        {
+            checks.push(SmokeCheck::new("Rojo starts", false, e.to_string()));
+            return Ok(checks);
+        }
+    }
+
+    let result = run_checks(&app, &project_path, &mut checks).await;
+    crate::commands::analytics::track(
+        "smoke_test",
+        serde_json::json!({ "passed": checks.iter().filter(|c| c.passed).count(), "total": checks.len() }),
+    );
+
+    if !already_running {
+        let _ = rojo::stop_rojo(
+            app.state::<RojoProcess>(),
+            app.state::<LoggerState>(),
+            app.state::<LogServerState>(),
+            app.state::<LauncherStatus>(),
+        )
+        .await;
+    }
+
+    result.map(|_| checks)
+}
+
+async fn run_checks(app: &AppHandle, project_path: &str, checks: &mut Vec<SmokeCheck>) -> Result<()> {
+    let status = app.state::<LauncherStatus>().shared();
+
+    // 1. Rojo is serving
+    let port = wait_for(ROJO_TIMEOUT, || async { status.lock().await.rojo_port }).await;
+    match port {
+        Some(port) => checks.push(SmokeCheck::new("Rojo starts", true, format!("Serving on port {port}"))),
+        None => {
+            checks.push(SmokeCheck::new("Rojo starts", false, "Rojo did not report a port within 20s"));
+            return Ok(());
+        }
+    }
+
+    // 2. The Studio plugin polls the launcher
+    let seen = wait_for(PLUGIN_TIMEOUT, || async {
+        status.lock().await.last_plugin_seen.map(|_| ())
+    })
+    .await;
+    if seen.is_none() {
+        checks.push(SmokeCheck::new(
+            "Studio plugin connects",
+            false,
+            "No contact from the Roxlit plugin. Open the place in Roblox Studio (restart Studio if it was already open).",
+        ));
+        return Ok(());
+    }
+    let version = status.lock().await.plugin_version.clone();
+    checks.push(SmokeCheck::new(
+        "Studio plugin connects",
+        true,
+        version.map(|v| format!("Plugin v{v}")).unwrap_or_default(),
+    ));
+
+    // 3. run_code round trip through the MCP relay
+    // Unique per run, so an earlier run's line in output.log can't match
+    let marker = format!("roxlit-smoke-{}-{}", std::process::id(), crate::commands::logs::unix_millis());
+    match studio::run_in_studio(&format!("print(\"{marker}\") return 1 + 1")).await {
+        Ok(result) => checks.push(SmokeCheck::new("Code runs in Studio", true, format!("Returned {result}"))),
+        Err(e) => {
+            checks.push(SmokeCheck::new("Code runs in Studio", false, e.to_string()));
+            return Ok(());
+        }
+    }
+
+    // 4. The print is captured into output.log
+    let output_log = Path::new(project_path).join(".roxlit").join("logs").join("output.log");
+    let captured = wait_for(LOG_TIMEOUT, || async {
+        let content = tokio::fs::read_to_string(&output_log).await.ok()?;
+        content.contains(&marker).then_some(())
+    })
+    .await;
+    checks.push(match captured {
+        Some(()) => SmokeCheck::new("Studio output is captured", true, "Marker found in output.log"),
+        None => SmokeCheck::new(
+            "Studio output is captured",
+            false,
+            "The printed marker did not reach output.log (is log capture turned off for this project?)",
+        ),
+    });
+
+    Ok(())
+}
+
+/// Polls `probe` every 500ms until it returns `Some` or `timeout` passes.
+async fn wait_for<T, F, Fut>(timeout: Duration, mut probe: F) -> Option<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Option<T>>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if let Some(value) = probe().await {
+            return Some(value);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return None;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
@@ -0,0 +1,219 @@
+//! Controlling Roblox Studio: bring it to the front after auto-open, close it
+//! when a dev session ends so no Studio window is left pointing at a server
+//! that no longer exists, and open a locally built place file.
+
+use std::path::{Path, PathBuf};
+
+use crate::commands::rojo::{is_studio_running, rojo_bin_path};
+use crate::error::Result;
+use crate::util::expand_tilde;
+
+/// Studio process names on Windows.
+#[cfg(target_os = "windows")]
+const STUDIO_PROCESSES: &[&str] = &["RobloxStudioBeta.exe", "RobloxStudioDesktop.exe"];
+
+/// Brings the Studio window to the front. Returns false if Studio isn't running.
+#[tauri::command]
+pub async fn focus_studio() -> Result<bool> {
+    if !is_studio_running(None).await {
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // AppActivate by process id focuses the main window of that process
+        let script = "$p = Get-Process RobloxStudioBeta, RobloxStudioDesktop -ErrorAction SilentlyContinue | \
+                      Where-Object { $_.MainWindowHandle -ne 0 } | Select-Object -First 1; \
+                      if ($p) { (New-Object -ComObject WScript.Shell).AppActivate($p.Id) | Out-Null }";
+        let mut cmd = tokio::process::Command::new("powershell.exe");
+        cmd.args(["-NoProfile", "-Command", script]);
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        cmd.output()
+            .await
+            .map_err(|e| crate::error::InstallerError::Custom(format!("Failed to focus Studio: {e}")))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        tokio::process::Command::new("osascript")
+            .args(["-e", "tell application \"RobloxStudio\" to activate"])
+            .output()
+            .await
+            .map_err(|e| crate::error::InstallerError::Custom(format!("Failed to focus Studio: {e}")))?;
+    }
+
+    Ok(true)
+}
+
+/// Closes Studio. Without `force` Studio is asked to quit (and may prompt to
+/// save); with `force` the process is killed. Returns false if it wasn't running.
+#[tauri::command]
+pub async fn close_studio(force: bool) -> Result<bool> {
+    if !is_studio_running(None).await {
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        for process in STUDIO_PROCESSES {
+            let mut cmd = tokio::process::Command::new("taskkill");
+            if force {
+                cmd.arg("/F");
+            }
+            cmd.args(["/IM", process]);
+            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+            let _ = cmd.output().await;
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let result = if force {
+            tokio::process::Command::new("pkill").args(["-9", "-x", "RobloxStudio"]).output().await
+        } else {
+            tokio::process::Command::new("osascript")
+                .args(["-e", "tell application \"RobloxStudio\" to quit"])
+                .output()
+                .await
+        };
+        result.map_err(|e| crate::error::InstallerError::Custom(format!("Failed to close Studio: {e}")))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    let _ = force;
+
+    Ok(true)
+}
+
+/// Local place file of the project's active place:
+/// `.roxlit/build/<folder>.rbxl`, or `<folder>-<place>.rbxl` in a
+/// multi-place project so each place keeps its own file.
+pub(crate) fn place_file(project_path: &str) -> PathBuf {
+    let root = Path::new(project_path);
+    let mut name = root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "place".into());
+    let place = crate::commands::config::find_project(project_path)
+        .and_then(|p| p.active_place().map(|place| crate::commands::baseline::slug(&place.name)))
+        .filter(|place| !place.is_empty());
+    if let Some(place) = place {
+        name = format!("{name}-{place}");
+    }
+    root.join(".roxlit").join("build").join(format!("{name}.rbxl"))
+}
+
+/// Builds the project with `rojo build` into its `place_file`.
+pub(crate) async fn build_place(project_path: &str) -> Result<PathBuf> {
+    let output_path = place_file(project_path);
+    build_place_to(project_path, &output_path).await?;
+    Ok(output_path)
+}
+
+/// The project's place file, built only if it doesn't exist yet. Studio saves
+/// into this file, so an existing one is kept as it is.
+pub(crate) async fn existing_or_built_place(project_path: &str) -> Result<PathBuf> {
+    let place = place_file(project_path);
+    if tokio::fs::try_exists(&place).await.unwrap_or(false) {
+        return Ok(place);
+    }
+    build_place(project_path).await
+}
+
+/// Builds the project with `rojo build` into `output_path`.
+pub(crate) async fn build_place_to(project_path: &str, output_path: &Path) -> Result<()> {
+    let root = Path::new(project_path);
+    crate::commands::disk::ensure_space(root, 0, "building the place").await?;
+    if let Some(dir) = output_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut cmd = tokio::process::Command::new(rojo_bin_path());
+    cmd.arg("build")
+        .arg(crate::commands::config::project_file(project_path))
+        .arg("-o")
+        .arg(output_path)
+        .current_dir(root);
+    if let Some(project) = crate::commands::config::find_project(project_path) {
+        project.settings.apply_env(&mut cmd);
+    }
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    let output = cmd
+        .output()
+        .await
+        .map_err(|e| crate::error::InstallerError::Custom(format!("Failed to run rojo build: {e}")))?;
+    if !output.status.success() {
+        return Err(crate::error::InstallerError::Custom(format!(
+            "rojo build failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// Opens a place file in Studio (the `.rbxl` file association on Windows).
+pub(crate) async fn open_place_file(path: &Path) -> Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        let mut cmd = tokio::process::Command::new("powershell.exe");
+        let escaped = path.to_string_lossy().replace('\'', "''");
+        cmd.args(["-NoProfile", "-Command", &format!("Start-Process -FilePath '{escaped}'")]);
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        cmd.output()
+            .await
+            .map_err(|e| crate::error::InstallerError::Custom(format!("Failed to open Studio: {e}")))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        tokio::process::Command::new("open")
+            .args(["-a", "RobloxStudio"])
+            .arg(path)
+            .output()
+            .await
+            .map_err(|e| crate::error::InstallerError::Custom(format!("Failed to open Studio: {e}")))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        let _ = path;
+        Err(crate::error::InstallerError::Custom(
+            "Roblox Studio is not available on this OS".into(),
+        ))
+    }
+}
+
+/// Builds the project into a local `.rbxl` and opens it in Studio, for
+/// working offline or on prototypes not linked to a published place.
+/// Returns the place file path.
+#[tauri::command]
+pub async fn open_local_place(project_path: String) -> Result<String> {
+    let project_path = expand_tilde(&project_path);
+    let place = build_place(&project_path).await?;
+    open_place_file(&place).await?;
+    Ok(place.to_string_lossy().to_string())
+}
+
+/// Runs Luau in Studio through the launcher's MCP relay (the same path the
+/// AI's `run_code` takes) and returns the plugin's result string.
+pub(crate) async fn run_in_studio(code: &str) -> Result<String> {
+    let url = format!("http://127.0.0.1:{}/mcp/run-code", crate::commands::ports::LOG_SERVER_PORT);
+    let value = reqwest::Client::new()
+        .post(&url)
+        .json(&serde_json::json!({ "code": code }))
+        .timeout(std::time::Duration::from_secs(35))
+        .send()
+        .await
+        .map_err(|e| crate::error::InstallerError::Custom(format!("Launcher did not respond: {e}")))?
+        .json::<serde_json::Value>()
+        .await
+        .unwrap_or_default();
+    if value["success"].as_bool() == Some(true) {
+        return Ok(value["result"].as_str().unwrap_or_default().to_string());
+    }
+    let error = value["error"].as_str().or(value["result"].as_str()).unwrap_or("No result");
+    Err(crate::error::InstallerError::Custom(error.to_string()))
+}
@@ -0,0 +1,247 @@
+//! History of what Rojo synced. `rojo serve` syncs every change to the files
+//! it maps but doesn't report patches at its default log level, so the
+//! session watches those files itself: each batch of changes is written to
+//! `.roxlit/logs/sync-history.jsonl` with the instance each file maps to and
+//! the SHA-256 of the content before and after, so "when did this script
+//! last sync, and what did it replace?" can be answered later.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::commands::instances::{self, InstanceNode};
+use crate::commands::logs::{format_timestamp, unix_millis};
+use crate::error::Result;
+use crate::util::expand_tilde;
+
+/// The history is cut to its newer half when it grows past this.
+const MAX_HISTORY_BYTES: u64 = 2 * 1024 * 1024;
+/// Entries `get_sync_history` returns when no limit is given.
+const DEFAULT_LIMIT: usize = 100;
+
+/// One project file changed by a sync.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncedFile {
+    /// Relative to the project root, with forward slashes.
+    pub file: String,
+    /// Instance path the file maps to, e.g. "ServerScriptService/Main".
+    pub instance: String,
+    pub class_name: String,
+    /// "added", "changed" or "removed".
+    pub change: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// Content hash before this sync (None for added files).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_sha256: Option<String>,
+}
+
+/// One batch of changes synced by Rojo.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncEntry {
+    /// Unix milliseconds.
+    pub timestamp: u64,
+    /// ISO 8601, for reading the file directly.
+    pub time: String,
+    pub files: Vec<SyncedFile>,
+}
+
+/// A mapped project file as last seen.
+#[derive(Clone)]
+struct FileState {
+    instance: String,
+    class_name: String,
+    modified: Option<SystemTime>,
+    sha256: String,
+}
+
+/// Tracks the project's files during a session to tell what each sync changed.
+pub(crate) struct SyncTracker {
+    root: PathBuf,
+    files: BTreeMap<String, FileState>,
+}
+
+impl SyncTracker {
+    /// Takes the starting state of the project's files. Hashes every mapped
+    /// file, so call it off the async runtime.
+    pub fn new(project_root: &Path) -> Self {
+        let files = snapshot(project_root, &BTreeMap::new());
+        Self { root: project_root.to_path_buf(), files }
+    }
+
+    /// Records the files changed since the previous call, if any. Returns
+    /// how many changed. Blocking: hashes the files whose modification time
+    /// moved.
+    pub fn record_changes(&mut self) -> usize {
+        let current = snapshot(&self.root, &self.files);
+        let files = changes(&self.files, &current);
+        self.files = current;
+        if files.is_empty() {
+            return 0;
+        }
+        let count = files.len();
+        let timestamp = unix_millis();
+        let entry = SyncEntry {
+            timestamp,
+            time: format_timestamp(timestamp / 1000),
+            files,
+        };
+        append(&history_path(&self.root), &entry);
+        count
+    }
+}
+
+fn history_path(project_root: &Path) -> PathBuf {
+    project_root.join(".roxlit").join("logs").join("sync-history.jsonl")
+}
+
+/// Current state of every file Rojo maps, by relative path. Files whose
+/// modification time matches `previous` aren't hashed again.
+fn snapshot(project_root: &Path, previous: &BTreeMap<String, FileState>) -> BTreeMap<String, FileState> {
+    let mut files = BTreeMap::new();
+    if let Ok(tree) = instances::project_tree(project_root) {
+        collect_files(project_root, &tree, previous, &mut files);
+    }
+    files
+}
+
+fn collect_files(
+    project_root: &Path,
+    node: &InstanceNode,
+    previous: &BTreeMap<String, FileState>,
+    out: &mut BTreeMap<String, FileState>,
+) {
+    if let Some(file) = &node.file {
+        let path = project_root.join(file);
+        if path.is_file() {
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+            let sha256 = match previous.get(file) {
+                Some(prev) if prev.modified == modified => Some(prev.sha256.clone()),
+                _ => crate::commands::downloader::sha256_file(&path).ok(),
+            };
+            if let Some(sha256) = sha256 {
+                out.insert(
+                    file.clone(),
+                    FileState { instance: node.path.clone(), class_name: node.class_name.clone(), modified, sha256 },
+                );
+            }
+        }
+    }
+    for child in &node.children {
+        collect_files(project_root, child, previous, out);
+    }
+}
+
+/// Files added, changed or removed between two snapshots.
+fn changes(before: &BTreeMap<String, FileState>, after: &BTreeMap<String, FileState>) -> Vec<SyncedFile> {
+    let mut files = Vec::new();
+    for (file, now) in after {
+        let previous = before.get(file);
+        if previous.is_some_and(|p| p.sha256 == now.sha256) {
+            continue;
+        }
+        files.push(SyncedFile {
+            file: file.clone(),
+            instance: now.instance.clone(),
+            class_name: now.class_name.clone(),
+            change: if previous.is_some() { "changed" } else { "added" }.into(),
+            sha256: Some(now.sha256.clone()),
+            previous_sha256: previous.map(|p| p.sha256.clone()),
+        });
+    }
+    for (file, old) in before.iter().filter(|(file, _)| !after.contains_key(*file)) {
+        files.push(SyncedFile {
+            file: file.clone(),
+            instance: old.instance.clone(),
+            class_name: old.class_name.clone(),
+            change: "removed".into(),
+            sha256: None,
+            previous_sha256: Some(old.sha256.clone()),
+        });
+    }
+    files
+}
+
+fn append(path: &Path, entry: &SyncEntry) {
+    let Ok(line) = serde_json::to_string(entry) else { return };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{line}");
+    }
+    if std::fs::metadata(path).is_ok_and(|m| m.len() > MAX_HISTORY_BYTES) {
+        if let Ok(content) = std::fs::read_to_string(path) {
+            let lines: Vec<&str> = content.lines().collect();
+            let kept = lines[lines.len() / 2..].join("\n");
+            let _ = std::fs::write(path, format!("{kept}\n"));
+        }
+    }
+}
+
+/// Sync history of a project, newest first. `query` keeps the entries that
+/// touched a file or instance path containing it (case-insensitive), and
+/// trims their file lists to the matching files.
+#[tauri::command]
+pub async fn get_sync_history(
+    project_path: String,
+    query: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<SyncEntry>> {
+    let project_path = expand_tilde(&project_path);
+    let content = std::fs::read_to_string(history_path(Path::new(&project_path))).unwrap_or_default();
+    let query = query.map(|q| q.to_lowercase()).filter(|q| !q.is_empty());
+
+    let entries = content
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str::<SyncEntry>(line).ok())
+        .filter_map(|mut entry| {
+            if let Some(query) = &query {
+                entry.files.retain(|f| {
+                    f.file.to_lowercase().contains(query) || f.instance.to_lowercase().contains(query)
+                });
+            }
+            (!entry.files.is_empty()).then_some(entry)
+        })
+        .take(limit.unwrap_or(DEFAULT_LIMIT))
+        .collect();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(instance: &str, sha256: &str) -> FileState {
+        FileState { instance: instance.into(), class_name: "Script".into(), modified: None, sha256: sha256.into() }
+    }
+
+    #[test]
+    fn test_changes() {
+        let before = BTreeMap::from([
+            ("src/a.server.luau".to_string(), state("ServerScriptService/a", "1")),
+            ("src/b.server.luau".to_string(), state("ServerScriptService/b", "2")),
+            ("src/c.server.luau".to_string(), state("ServerScriptService/c", "3")),
+        ]);
+        let after = BTreeMap::from([
+            ("src/a.server.luau".to_string(), state("ServerScriptService/a", "1")),
+            ("src/b.server.luau".to_string(), state("ServerScriptService/b", "22")),
+            ("src/d.server.luau".to_string(), state("ServerScriptService/d", "4")),
+        ]);
+        let files = changes(&before, &after);
+        let summary: Vec<(&str, &str, Option<&str>)> = files
+            .iter()
+            .map(|f| (f.file.as_str(), f.change.as_str(), f.previous_sha256.as_deref()))
+            .collect();
+        assert_eq!(summary, [
+            ("src/b.server.luau", "changed", Some("2")),
+            ("src/d.server.luau", "added", None),
+            ("src/c.server.luau", "removed", Some("3")),
+        ]);
+    }
+}
@@ -0,0 +1,176 @@
+//! Opening a terminal window in a project, for running Claude Code or tools
+//! like wally, stylua and git by hand. The `terminal` setting picks which
+//! terminal to use; without it (or if it can't be started) the usual ones for
+//! the OS are tried in order.
+
+use std::path::Path;
+
+use crate::error::{InstallerError, Result};
+#[cfg(target_os = "windows")]
+use crate::util::cmd_line;
+#[cfg(target_os = "macos")]
+use crate::util::sh_quote;
+use crate::util::expand_tilde;
+
+/// Terminals tried when no preference is set or the preferred one fails.
+#[cfg(target_os = "windows")]
+const DEFAULT_TERMINALS: &[&str] = &["wt", "cmd"];
+#[cfg(target_os = "macos")]
+const DEFAULT_TERMINALS: &[&str] = &["Terminal"];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const DEFAULT_TERMINALS: &[&str] = &[
+    "x-terminal-emulator",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "kitty",
+    "alacritty",
+    "wezterm",
+    "xterm",
+];
+
+/// Opens a terminal in `path` and, if given, runs `command` in it, leaving
+/// the shell open afterwards.
+pub(crate) fn launch(path: &str, command: Option<&str>) -> Result<()> {
+    let preferred = crate::commands::config::read_config().and_then(|c| c.terminal);
+    let terminals = preferred.iter().map(String::as_str).chain(DEFAULT_TERMINALS.iter().copied());
+
+    let mut last_error = None;
+    for terminal in terminals {
+        match spawn(terminal, Path::new(path), command) {
+            Ok(()) => return Ok(()),
+            Err(e) => last_error = Some(format!("{terminal}: {e}")),
+        }
+    }
+    Err(InstallerError::Custom(format!(
+        "Failed to open a terminal ({})",
+        last_error.unwrap_or_default()
+    )))
+}
+
+#[cfg(target_os = "windows")]
+fn spawn(terminal: &str, path: &Path, command: Option<&str>) -> std::io::Result<()> {
+    use std::os::windows::process::CommandExt;
+
+    let mut cmd = match terminal {
+        "wt" | "wt.exe" => {
+            // wt splits its arguments into several commands at `;`
+            let mut cmd = std::process::Command::new("wt.exe");
+            cmd.arg("-d").arg(path.to_string_lossy().replace(';', "\\;"));
+            if let Some(command) = command {
+                let command = command.replace(';', "\\;");
+                cmd.args(["cmd", "/k", command.as_str()]);
+            }
+            cmd
+        }
+        // Through `start`, which cmd.exe parses: quoted with cmd's rules, not Rust's
+        "powershell" | "pwsh" => {
+            let mut args = vec!["/c", "start", "", terminal, "-NoExit"];
+            if let Some(command) = command {
+                args.extend(["-Command", command]);
+            }
+            let mut cmd = std::process::Command::new("cmd.exe");
+            cmd.raw_arg(cmd_line(&args));
+            cmd
+        }
+        // cmd and anything else `start` can launch
+        other => {
+            let mut args = vec!["/c", "start", "", other];
+            if let Some(command) = command.filter(|_| other.eq_ignore_ascii_case("cmd")) {
+                args.extend(["/k", command]);
+            }
+            let mut cmd = std::process::Command::new("cmd.exe");
+            cmd.raw_arg(cmd_line(&args));
+            cmd
+        }
+    };
+    cmd.current_dir(path).spawn().map(|_| ())
+}
+
+#[cfg(target_os = "macos")]
+fn spawn(terminal: &str, path: &Path, command: Option<&str>) -> std::io::Result<()> {
+    let mut cmd = std::process::Command::new("open");
+    cmd.args(["-a", terminal]);
+    match command {
+        // Terminal apps run `.command` files in a new window
+        Some(command) => {
+            let script = std::env::temp_dir().join("roxlit-terminal.command");
+            let cd = sh_quote(&path.to_string_lossy());
+            std::fs::write(&script, format!("#!/bin/sh\ncd {cd}\n{}\n", shell_command(command)))?;
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+            cmd.arg(script);
+        }
+        None => {
+            cmd.arg(path);
+        }
+    }
+    // `open` fails when the app doesn't exist
+    if cmd.status()?.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(std::io::ErrorKind::NotFound, format!("{terminal} not found")))
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn spawn(terminal: &str, path: &Path, command: Option<&str>) -> std::io::Result<()> {
+    let mut cmd = std::process::Command::new(terminal);
+    let script = command.map(shell_command);
+    match terminal {
+        "gnome-terminal" => {
+            cmd.arg("--working-directory").arg(path);
+            if let Some(script) = &script {
+                cmd.args(["--", "sh", "-c", script]);
+            }
+        }
+        "wezterm" => {
+            cmd.args(["start", "--cwd"]).arg(path);
+            if let Some(script) = &script {
+                cmd.args(["--", "sh", "-c", script]);
+            }
+        }
+        "kitty" => {
+            cmd.arg("--directory").arg(path);
+            if let Some(script) = &script {
+                cmd.args(["sh", "-c", script]);
+            }
+        }
+        "xfce4-terminal" => {
+            cmd.arg("--working-directory").arg(path);
+            if let Some(script) = &script {
+                cmd.args(["-x", "sh", "-c", script]);
+            }
+        }
+        "konsole" => {
+            cmd.arg("--workdir").arg(path);
+            if let Some(script) = &script {
+                cmd.args(["-e", "sh", "-c", script]);
+            }
+        }
+        // xterm, alacritty, x-terminal-emulator and most others accept -e and
+        // start in the current directory
+        _ => {
+            if let Some(script) = &script {
+                cmd.args(["-e", "sh", "-c", script]);
+            }
+        }
+    }
+    cmd.current_dir(path).spawn().map(|_| ())
+}
+
+/// Runs `command`, then keeps the terminal open in the user's shell.
+#[cfg(not(target_os = "windows"))]
+fn shell_command(command: &str) -> String {
+    format!("{command}; exec \"${{SHELL:-sh}}\"")
+}
+
+/// Opens the user's terminal in a project folder.
+#[tauri::command]
+pub async fn open_terminal(path: String) -> Result<()> {
+    let path = expand_tilde(&path);
+    if !Path::new(&path).is_dir() {
+        return Err(InstallerError::Custom(format!("{path} is not a folder")));
+    }
+    launch(&path, None)
+}
@@ -1,8 +1,8 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{InstallerError, Result};
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateInfo {
     pub version: String,
@@ -56,7 +56,7 @@ fn now_unix() -> i64 {
 
 /// Compare two semver strings (e.g. "0.2.0" > "0.1.0").
 /// Returns true if `remote` is newer than `local`.
-fn is_newer_version(local: &str, remote: &str) -> bool {
+pub(crate) fn is_newer_version(local: &str, remote: &str) -> bool {
     let parse = |s: &str| -> (u64, u64, u64) {
         let parts: Vec<u64> = s
             .trim_start_matches('v')
@@ -88,36 +88,117 @@ fn extract_virustotal_url(body: &str) -> Option<String> {
 
 const RATE_LIMIT_SECS: i64 = 24 * 3600; // 24 hours
 
+/// Fallback wait when GitHub reports a rate limit without a reset header.
+const DEFAULT_RATE_LIMIT_WAIT_SECS: i64 = 3600;
+
+/// Response from a GitHub API request.
+pub(crate) enum GithubResponse {
+    Ok(reqwest::Response),
+    /// The API rate limit is exhausted — retry after this Unix timestamp.
+    RateLimited { reset_at: i64 },
+}
+
+/// Detect a GitHub rate-limit response and return the Unix timestamp it resets at.
+/// GitHub answers 403 (primary limit) or 429 (secondary limit) with either
+/// `x-ratelimit-remaining: 0` + `x-ratelimit-reset`, or a `retry-after` in seconds.
+fn rate_limit_reset(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+    now: i64,
+) -> Option<i64> {
+    if status != reqwest::StatusCode::FORBIDDEN && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let header = |name: &str| -> Option<i64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    if let Some(retry_after) = header("retry-after") {
+        return Some(now + retry_after);
+    }
+    if header("x-ratelimit-remaining") == Some(0) {
+        return Some(header("x-ratelimit-reset").unwrap_or(now + DEFAULT_RATE_LIMIT_WAIT_SECS));
+    }
+    // A 429 is always a rate limit, even without headers; a bare 403 is not
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Some(now + DEFAULT_RATE_LIMIT_WAIT_SECS);
+    }
+    None
+}
+
+/// GET a GitHub API endpoint, authenticating with the token from settings (if any)
+/// and detecting rate-limit responses. Shared by all GitHub API checks.
+pub(crate) async fn github_api_get(
+    client: &reqwest::Client,
+    url: &str,
+) -> reqwest::Result<GithubResponse> {
+    let token = crate::commands::config::load_config()
+        .await
+        .and_then(|c| c.github_token);
+
+    let mut request = client
+        .get(url)
+        .header("User-Agent", "Roxlit-Launcher")
+        .header("Accept", "application/vnd.github+json");
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await?;
+    match rate_limit_reset(response.status(), response.headers(), now_unix()) {
+        Some(reset_at) => Ok(GithubResponse::RateLimited { reset_at }),
+        None => Ok(GithubResponse::Ok(response)),
+    }
+}
+
 #[tauri::command]
 pub async fn check_for_update(
+    app: tauri::AppHandle,
     last_check: Option<String>,
     dismissed_version: Option<String>,
     cooling_days: Option<u32>,
 ) -> Result<Option<UpdateInfo>> {
     let cooling_secs = (cooling_days.unwrap_or(7) as i64) * 24 * 3600;
-    // Rate limit: skip if last check was less than 24h ago
-    if let Some(ref ts) = last_check {
-        if let Some(last_unix) = parse_iso8601_to_unix(ts) {
-            if now_unix() - last_unix < RATE_LIMIT_SECS {
-                return Ok(None);
+
+    // A previous check hit the GitHub rate limit: wait for the reset, then retry
+    // immediately instead of waiting out the normal 24h interval.
+    let retry_at = crate::commands::config::load_config()
+        .await
+        .and_then(|c| c.update_retry_at);
+    match retry_at {
+        Some(at) if now_unix() < at => return Ok(None),
+        Some(_) => {}
+        // Rate limit: skip if last check was less than 24h ago
+        None => {
+            if let Some(ref ts) = last_check {
+                if let Some(last_unix) = parse_iso8601_to_unix(ts) {
+                    if now_unix() - last_unix < RATE_LIMIT_SECS {
+                        return Ok(None);
+                    }
+                }
             }
         }
     }
 
     // Fetch latest release from GitHub
     let client = reqwest::Client::new();
-    let response = client
-        .get("https://api.github.com/repos/Roxlit/installer/releases/latest")
-        .header("User-Agent", "Roxlit-Launcher")
-        .header("Accept", "application/vnd.github+json")
-        .send()
-        .await;
+    let response = github_api_get(
+        &client,
+        "https://api.github.com/repos/Roxlit/installer/releases/latest",
+    )
+    .await;
 
     let response = match response {
-        Ok(r) => r,
+        Ok(GithubResponse::Ok(r)) => r,
+        Ok(GithubResponse::RateLimited { reset_at }) => {
+            crate::commands::config::save_update_retry_at(Some(reset_at));
+            return Ok(None);
+        }
         Err(_) => return Ok(None), // Network error — silent failure
     };
 
+    if retry_at.is_some() {
+        crate::commands::config::save_update_retry_at(None);
+    }
+
     // 404 means no releases exist yet
     if response.status() == reqwest::StatusCode::NOT_FOUND {
         return Ok(None);
@@ -182,6 +263,18 @@ pub async fn check_for_update(
 
     let virus_total_url = extract_virustotal_url(&release_body);
 
+    let prefetch = crate::commands::config::load_config()
+        .await
+        .and_then(|c| c.prefetch_updates)
+        .unwrap_or(false);
+    // With prefetching on, the notification waits for the installer
+    match installer_asset(&body["assets"]).filter(|_| prefetch) {
+        Some((name, url, sha256)) => {
+            tokio::spawn(prefetch_installer(app, remote_version.to_string(), name, url, sha256));
+        }
+        None => notify_update(&app, remote_version, "is available"),
+    }
+
     Ok(Some(UpdateInfo {
         version: remote_version.to_string(),
         published_at: published_at.to_string(),
@@ -191,6 +284,216 @@ pub async fn check_for_update(
     }))
 }
 
+/// `~/.roxlit/installers/`: update installers downloaded ahead of time.
+pub(crate) fn installers_dir() -> Option<std::path::PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("installers"))
+}
+
+/// Name, download URL and SHA-256 of this platform's installer among a
+/// release's assets. None without a listed SHA-256: an installer is never
+/// downloaded ahead without something to check it against.
+fn installer_asset(assets: &serde_json::Value) -> Option<(String, String, String)> {
+    let suffix = if cfg!(target_os = "windows") {
+        "-setup.exe"
+    } else if cfg!(target_os = "macos") {
+        ".dmg"
+    } else {
+        return None;
+    };
+    assets.as_array()?.iter().find_map(|asset| {
+        let name = asset["name"].as_str()?;
+        let url = asset["browser_download_url"].as_str()?;
+        let sha256 = asset["digest"].as_str()?.strip_prefix("sha256:")?;
+        name.ends_with(suffix).then(|| (name.to_string(), url.to_string(), sha256.to_string()))
+    })
+}
+
+/// Room for an update installer, with margin (the size isn't known until
+/// the download starts).
+const INSTALLER_BYTES: u64 = 200 * 1024 * 1024;
+
+/// The installer `prefetch_installer` downloaded, in
+/// `~/.roxlit/installers/prefetched.json`. `apply_update` launches only this
+/// file, and only while it still has this checksum.
+#[derive(Serialize, Deserialize)]
+struct PrefetchedInstaller {
+    version: String,
+    name: String,
+    sha256: String,
+}
+
+fn prefetched_path(dir: &std::path::Path) -> std::path::PathBuf {
+    dir.join("prefetched.json")
+}
+
+fn read_prefetched(dir: &std::path::Path) -> Option<PrefetchedInstaller> {
+    let content = std::fs::read_to_string(prefetched_path(dir)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Downloads an update installer to `~/.roxlit/installers/`, replacing older
+/// ones. Best-effort: the release page remains the fallback.
+async fn prefetch_installer(app: tauri::AppHandle, version: String, name: String, url: String, sha256: String) {
+    let Some(dir) = installers_dir() else {
+        return;
+    };
+    let dest = dir.join(&name);
+    if dest.exists() && read_prefetched(&dir).is_some_and(|p| p.name == name && p.sha256 == sha256) {
+        return;
+    }
+    if tokio::fs::create_dir_all(&dir).await.is_err() {
+        return;
+    }
+    if crate::commands::disk::low_space(&dir, INSTALLER_BYTES, "the update download").await.is_some() {
+        return;
+    }
+
+    // Written as `<name>.part` until complete and verified, so a partial
+    // download is never launched
+    let checksum = crate::commands::downloader::Checksum::Sha256(&sha256);
+    let download = crate::commands::downloader::download(&url, &dest, checksum, |_| {});
+    if download.await.is_err() {
+        return;
+    }
+    if let Ok(entries) = std::fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path != dest {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+    let record = PrefetchedInstaller { version, name, sha256 };
+    let Ok(json) = serde_json::to_string_pretty(&record) else {
+        return;
+    };
+    if std::fs::write(prefetched_path(&dir), json).is_err() {
+        return;
+    }
+    notify_update(&app, &record.version, "is ready to install");
+}
+
+fn notify_update(app: &tauri::AppHandle, version: &str, state: &str) {
+    crate::commands::notifications::notify(
+        app,
+        crate::commands::notifications::UPDATE_READY,
+        "Roxlit update",
+        &format!("Roxlit {version} {state}."),
+    );
+}
+
+/// Launches the prefetched installer for `version`, if it has been downloaded
+/// and still matches the release's checksum. Returns false otherwise, so the
+/// UI falls back to the release page.
+#[tauri::command]
+pub async fn apply_update(version: String) -> Result<bool> {
+    let version = version.trim_start_matches('v');
+    let Some(dir) = installers_dir() else {
+        return Ok(false);
+    };
+    let Some(prefetched) = read_prefetched(&dir).filter(|p| p.version == version) else {
+        return Ok(false);
+    };
+    let installer = dir.join(&prefetched.name);
+    let hash_path = installer.clone();
+    let digest = tokio::task::spawn_blocking(move || crate::commands::downloader::sha256_file(&hash_path))
+        .await
+        .map_err(|e| InstallerError::Custom(e.to_string()))?;
+    if !digest.is_ok_and(|d| d.eq_ignore_ascii_case(&prefetched.sha256)) {
+        let _ = std::fs::remove_file(&installer);
+        let _ = std::fs::remove_file(prefetched_path(&dir));
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(&installer).spawn();
+    #[cfg(not(target_os = "macos"))]
+    let result = std::process::Command::new(&installer).spawn();
+    result.map_err(|e| InstallerError::Custom(format!("Failed to start the installer: {e}")))?;
+    Ok(true)
+}
+
+/// How long the cached release list is used before asking GitHub again.
+const RELEASE_CACHE_SECS: i64 = 6 * 3600;
+
+/// Most releases returned by `get_release_history` (one API page).
+const MAX_RELEASE_HISTORY: usize = 100;
+
+/// Cached release list in `~/.roxlit/releases.json`.
+#[derive(Serialize, Deserialize)]
+struct ReleaseCache {
+    fetched_at: i64,
+    releases: Vec<UpdateInfo>,
+}
+
+fn release_cache_path() -> Option<std::path::PathBuf> {
+    dirs::home_dir().map(|h| h.join(".roxlit").join("releases.json"))
+}
+
+/// Published releases (no drafts or pre-releases), newest first.
+async fn fetch_releases() -> Option<Vec<UpdateInfo>> {
+    let client = reqwest::Client::new();
+    let url = format!("https://api.github.com/repos/Roxlit/installer/releases?per_page={MAX_RELEASE_HISTORY}");
+    let response = match github_api_get(&client, &url).await.ok()? {
+        GithubResponse::Ok(r) if r.status().is_success() => r,
+        _ => return None,
+    };
+    let body: Vec<serde_json::Value> = response.json().await.ok()?;
+    let releases = body
+        .iter()
+        .filter(|r| !r["draft"].as_bool().unwrap_or(true) && !r["prerelease"].as_bool().unwrap_or(true))
+        .map(|r| {
+            let release_body = r["body"].as_str().unwrap_or_default().to_string();
+            UpdateInfo {
+                version: r["tag_name"].as_str().unwrap_or_default().trim_start_matches('v').to_string(),
+                published_at: r["published_at"].as_str().unwrap_or_default().to_string(),
+                html_url: r["html_url"].as_str().unwrap_or_default().to_string(),
+                virus_total_url: extract_virustotal_url(&release_body),
+                body: release_body,
+            }
+        })
+        .collect();
+    Some(releases)
+}
+
+/// Recent releases (newest first) with their notes, so the UI can show what
+/// changed across several versions after an upgrade. Served from a 6h cache;
+/// a stale cache is used when GitHub is unreachable or rate-limited.
+#[tauri::command]
+pub async fn get_release_history(limit: Option<usize>) -> Result<Vec<UpdateInfo>> {
+    let limit = limit.unwrap_or(10).min(MAX_RELEASE_HISTORY);
+    let cache_path = release_cache_path();
+    let cached: Option<ReleaseCache> = cache_path
+        .as_ref()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok());
+
+    let releases = match cached {
+        Some(cache) if now_unix() - cache.fetched_at < RELEASE_CACHE_SECS => cache.releases,
+        cached => match fetch_releases().await {
+            Some(releases) => {
+                if let Some(path) = &cache_path {
+                    let cache = ReleaseCache {
+                        fetched_at: now_unix(),
+                        releases,
+                    };
+                    if let Ok(json) = serde_json::to_string(&cache) {
+                        let _ = std::fs::write(path, json);
+                    }
+                    cache.releases
+                } else {
+                    releases
+                }
+            }
+            None => cached
+                .map(|c| c.releases)
+                .ok_or_else(|| InstallerError::Custom("Could not fetch release history".into()))?,
+        },
+    };
+
+    Ok(releases.into_iter().take(limit).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,4 +514,22 @@ mod tests {
         assert!(!is_newer_version("0.1.0", "0.1.0"));
         assert!(is_newer_version("v0.1.0", "v0.2.0"));
     }
+
+    #[test]
+    fn test_rate_limit_reset() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+        use reqwest::StatusCode;
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(rate_limit_reset(StatusCode::FORBIDDEN, &headers, 100), None);
+        assert_eq!(rate_limit_reset(StatusCode::TOO_MANY_REQUESTS, &headers, 100), Some(3700));
+
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1735689600"));
+        assert_eq!(rate_limit_reset(StatusCode::FORBIDDEN, &headers, 100), Some(1735689600));
+        assert_eq!(rate_limit_reset(StatusCode::OK, &headers, 100), None);
+
+        headers.insert("retry-after", HeaderValue::from_static("60"));
+        assert_eq!(rate_limit_reset(StatusCode::FORBIDDEN, &headers, 100), Some(160));
+    }
 }
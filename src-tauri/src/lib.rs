@@ -6,59 +6,40 @@ mod templates;
 pub mod util;
 
 /// Open a folder in the user's code editor (cursor, code, etc.)
-/// For GUI editors (cursor, code, windsurf): passes the path as argument to open the folder.
-/// For Claude Code: opens a terminal in the project directory and runs `claude`.
+/// For GUI editors (cursor, code, windsurf): passes the path as argument to open the folder,
+/// using the `defaultEditor` setting when that editor isn't installed (see `commands::editors`).
+/// For Claude Code: opens a terminal in the project directory and runs `claude`
+/// (the `terminal` setting picks which terminal, see `commands::terminal`).
 #[tauri::command]
-async fn open_in_editor(editor: String, path: String) -> Result<(), String> {
+async fn open_in_editor(editor: String, path: String) -> Result<(), commands::editors::EditorError> {
     let path = util::expand_tilde(&path);
 
     if editor == "claude" {
         // Claude Code is a CLI tool — open a terminal at the project directory
-        #[cfg(target_os = "windows")]
-        {
-            // Try Windows Terminal first, fall back to cmd.exe
-            let result = tokio::process::Command::new("wt.exe")
-                .args(["-d", &path, "cmd", "/k", "claude"])
-                .spawn();
-            if result.is_ok() {
-                return Ok(());
-            }
-            // Fallback: cmd.exe
-            let result = tokio::process::Command::new("cmd.exe")
-                .args(["/c", "start", "cmd.exe", "/k", &format!("cd /d \"{}\" && claude", path)])
-                .spawn();
-            match result {
-                Ok(_) => return Ok(()),
-                Err(e) => return Err(format!("Failed to open terminal: {e}")),
-            }
-        }
+        let opened = commands::terminal::launch(&path, Some("claude"));
         #[cfg(not(target_os = "windows"))]
-        {
-            // On macOS/Linux, just run claude in the project directory
-            let result = tokio::process::Command::new("claude")
-                .current_dir(&path)
-                .spawn();
-            match result {
-                Ok(_) => return Ok(()),
-                Err(e) => return Err(format!("Failed to open claude: {e}")),
-            }
+        if opened.is_err() {
+            // No terminal found: run claude in the project directory directly
+            return match tokio::process::Command::new("claude").current_dir(&path).spawn() {
+                Ok(_) => Ok(()),
+                Err(e) => Err(commands::editors::EditorError::new(format!("Failed to open claude: {e}"))),
+            };
         }
+        return opened.map_err(|e| commands::editors::EditorError::new(e.to_string()));
     }
 
-    // GUI editors: pass path as argument to open the folder
-    let cmd = match editor.as_str() {
-        "cursor" => "cursor",
-        "vscode" | "windsurf" => "code",
-        _ => "code",
-    };
+    // GUI editors: open the project's workspace file if it has one, else the folder
+    let editor = commands::editors::resolve(&editor)?;
+    let workspace = commands::project::workspace_path(std::path::Path::new(&path));
+    let target = if workspace.exists() { workspace.to_string_lossy().to_string() } else { path.clone() };
 
-    let result = tokio::process::Command::new(cmd)
-        .arg(&path)
+    let result = tokio::process::Command::new(&editor.command)
+        .arg(&target)
         .spawn();
 
     match result {
         Ok(_) => Ok(()),
-        Err(e) => Err(format!("Failed to open {cmd}: {e}")),
+        Err(e) => Err(commands::editors::EditorError::new(format!("Failed to open {}: {e}", editor.name))),
     }
 }
 
@@ -74,10 +55,15 @@ async fn open_url_fallback(url: String) -> Result<(), String> {
         return Ok(());
     }
 
-    let result = tokio::process::Command::new("cmd.exe")
-        .args(["/c", "start", &url])
-        .output()
-        .await;
+    // `start` takes its first quoted argument as the window title, hence the
+    // empty one; `&` in the URL must not reach cmd.exe unquoted
+    let mut cmd = tokio::process::Command::new("cmd.exe");
+    #[cfg(target_os = "windows")]
+    cmd.raw_arg(crate::util::cmd_line(&["/c", "start", "", &url]));
+    // Through WSL interop the arguments arrive unquoted (URLs have no spaces)
+    #[cfg(not(target_os = "windows"))]
+    cmd.args(["/c", "start", "\"\"", &url.replace('&', "^&")]);
+    let result = cmd.output().await;
 
     match result {
         Ok(output) if output.status.success() => Ok(()),
@@ -86,30 +72,107 @@ async fn open_url_fallback(url: String) -> Result<(), String> {
 }
 
 pub fn run() {
+    // Before anything reads or rewrites config.json
+    commands::config_backup::snapshot_on_upgrade();
+    let logger_state = commands::logs::LoggerState::default();
+    commands::crash::install_panic_hook(logger_state.buffer.clone());
+    let cleanup_logger = logger_state.clone();
+    let maintenance_logger = logger_state.clone();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_notification::init())
         .manage(commands::rojo::RojoProcess::default())
-        .manage(commands::logs::LoggerState::default())
+        .manage(logger_state)
         .manage(commands::logs::LogServerState::default())
         .manage(commands::logs::LauncherStatus::default())
         .manage(commands::logs::McpState::default())
         .manage(commands::logs::TelemetryState::default())
+        .setup(move |_app| {
+            // Clear leftovers from sessions that crashed or were killed
+            tauri::async_runtime::spawn(commands::cleanup::run_startup_cleanup(cleanup_logger));
+            // Find the installed editors before the first "open in editor"
+            tauri::async_runtime::spawn_blocking(commands::editors::refresh);
+            tauri::async_runtime::spawn(commands::maintenance::run_when_due(maintenance_logger));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::detect::detect_environment,
             commands::install::run_installation,
+            commands::install::refresh_rojo_plugin,
+            commands::aftman::trust_aftman_tools,
+            commands::aftman::list_aftman_tools,
+            commands::aftman::add_aftman_tool,
+            commands::aftman::bump_aftman_tool,
             commands::config::load_config,
             commands::config::save_project,
             commands::config::save_update_state,
             commands::config::save_settings,
+            commands::config::set_plugin_config,
+            commands::config::get_script_filter,
+            commands::config::mute_script,
+            commands::config::solo_script,
+            commands::config::get_tool_versions,
             commands::config::scan_for_projects,
             commands::config::check_project_exists,
+            commands::config::prune_missing_projects,
+            commands::editors::list_editors,
             commands::config::set_active_project,
+            commands::config::save_project_settings,
+            commands::config::save_place,
+            commands::config::remove_place,
+            commands::config::set_active_place,
+            commands::config_backup::list_config_backups,
+            commands::config_backup::restore_config_backup,
+            commands::manifest::init_project_manifest,
+            commands::classroom::provision_classroom,
+            commands::project::list_project_templates,
+            commands::project::list_starter_modules,
+            commands::project::generate_workspace,
+            commands::adopt::adopt_project,
+            commands::bulk::bulk_project_action,
+            commands::first_sync::preview_first_sync,
+            commands::first_sync::confirm_first_sync,
+            commands::session_lock::force_unlock_project,
+            commands::sync_history::get_sync_history,
+            commands::terminal::open_terminal,
+            commands::project_json::restore_project_json,
+            commands::project::list_scaffolds,
+            commands::project::generate_scaffold,
             commands::update::check_for_update,
+            commands::update::get_release_history,
+            commands::update::apply_update,
             commands::rojo::start_rojo,
             commands::rojo::stop_rojo,
             commands::rojo::get_rojo_status,
+            commands::rojo::update_sync_settings,
+            commands::rojo::resume_last_session,
+            commands::studio::focus_studio,
+            commands::studio::close_studio,
+            commands::studio::open_local_place,
+            commands::instances::get_instance_tree,
+            commands::instances::search_instances,
+            commands::scripts::search_scripts,
+            commands::scripts::get_require_graph,
+            commands::scripts::find_unused_modules,
+            commands::scripts::move_script,
+            commands::backup::verify_backups,
+            commands::baseline::mark_baseline,
+            commands::baseline::list_baselines,
+            commands::baseline::revert_to_baseline,
+            commands::logs::get_logs_since,
+            commands::maintenance::run_maintenance,
+            commands::log_export::export_session_log,
+            commands::crash::list_crash_reports,
+            commands::analytics::get_analytics_log,
+            commands::health::get_health_metrics,
+            commands::disk::get_disk_usage,
+            commands::smoke_test::run_smoke_test,
+            commands::playtest::run_playtest,
+            commands::playtest::get_last_playtest,
+            commands::playtest::list_playtest_results,
             open_url_fallback,
             open_in_editor,
         ])
@@ -126,15 +189,28 @@ pub fn run() {
                     if let Some((path, place_id, universe_id)) = save_info {
                         commands::config::save_place_id(&path, place_id, universe_id);
                     }
+                    // Free the project's session lock for other machines
+                    let project_path = shared.try_lock().ok().map(|guard| guard.project_path.clone());
+                    if let Some(path) = project_path.filter(|p| !p.is_empty()) {
+                        commands::session_lock::release(std::path::Path::new(&path));
+                    }
                 }
                 // Kill rojo serve when the window is closed
                 if let Some(state) = _window.try_state::<commands::rojo::RojoProcess>() {
                     state.inner().kill_sync();
                 }
-                // Stop the Studio log HTTP server when the window is closed
-                if let Some(state) = _window.try_state::<commands::logs::LogServerState>() {
-                    state.inner().kill_sync();
-                }
+                // Stop the Studio log HTTP server when the window is closed, letting
+                // in-flight log batches land, then end the session log
+                let log_server = _window.try_state::<commands::logs::LogServerState>();
+                let logger = _window.try_state::<commands::logs::LoggerState>();
+                tauri::async_runtime::block_on(async {
+                    if let Some(state) = log_server {
+                        state.inner().stop().await;
+                    }
+                    if let Some(state) = logger {
+                        state.inner().close().await;
+                    }
+                });
             }
         })
         .run(tauri::generate_context!())
@@ -3,33 +3,99 @@
 /// information about a specific Roblox topic. These are written to
 /// `.roxlit/context/` inside the user's project.
 
-/// Index file — the AI reads this first to know what context packs are available.
-pub fn index() -> &'static str {
-    r#"# Roxlit Context Packs
+/// A built-in context pack and its index metadata.
+pub struct Pack {
+    pub file: &'static str,
+    pub summary: &'static str,
+    /// Month the pack content was last verified against Roblox.
+    pub version: &'static str,
+    pub content: fn() -> &'static str,
+}
+
+/// All built-in packs, in index order.
+pub const PACKS: &[Pack] = &[
+    Pack {
+        file: "datastore.md",
+        summary: "DataStoreService: throttling, session locking, retry patterns",
+        version: "2025-06",
+        content: datastore,
+    },
+    Pack {
+        file: "remote-events.md",
+        summary: "RemoteEvent/RemoteFunction: security, validation, rate limiting",
+        version: "2025-06",
+        content: remote_events,
+    },
+    Pack {
+        file: "player-lifecycle.md",
+        summary: "PlayerAdded, CharacterAdded, respawn, death, BindToClose",
+        version: "2025-06",
+        content: player_lifecycle,
+    },
+    Pack {
+        file: "workspace-physics.md",
+        summary: "Parts, CFrame, terrain, collision groups, physics, vehicles (use community chassis first!)",
+        version: "2025-06",
+        content: workspace_physics,
+    },
+    Pack {
+        file: "replication.md",
+        summary: "What replicates, FilteringEnabled rules, RunContext",
+        version: "2025-06",
+        content: replication,
+    },
+    Pack {
+        file: "services-reference.md",
+        summary: "Service properties, enums, valid ranges",
+        version: "2025-06",
+        content: services_reference,
+    },
+    Pack {
+        file: "studio-ui.md",
+        summary: "Studio UI: panel locations, testing modes, localized names, troubleshooting",
+        version: "2026-02",
+        content: studio_ui,
+    },
+];
+
+/// One row of the generated index.
+pub struct IndexEntry {
+    pub file: String,
+    pub summary: String,
+    pub version: String,
+}
 
-> Last updated: 2025-06 | Roblox Engine v638+
+/// Index file — the AI reads this first to know what context packs are available.
+/// Generated from the packs actually written, so it never lists missing files.
+pub fn index(entries: &[IndexEntry]) -> String {
+    let mut rows = String::new();
+    for entry in entries {
+        rows.push_str(&format!(
+            "| `{}` | {} | {} |\n",
+            entry.file, entry.summary, entry.version
+        ));
+    }
+    format!(
+        r#"# Roxlit Context Packs
+
+> Roblox Engine v638+
 
 These files contain curated Roblox/Luau documentation to help you write correct code. **Read the relevant file before writing code** that touches that system.
 
 ## Available Packs
 
-| File | Topic |
-|------|-------|
-| `datastore.md` | DataStoreService: throttling, session locking, retry patterns |
-| `remote-events.md` | RemoteEvent/RemoteFunction: security, validation, rate limiting |
-| `player-lifecycle.md` | PlayerAdded, CharacterAdded, respawn, death, BindToClose |
-| `workspace-physics.md` | Parts, CFrame, terrain, collision groups, physics, vehicles (use community chassis first!) |
-| `replication.md` | What replicates, FilteringEnabled rules, RunContext |
-| `services-reference.md` | Service properties, enums, valid ranges |
-| `studio-ui.md` | Studio UI: panel locations, testing modes, localized names, troubleshooting |
-
+| File | Topic | Version |
+|------|-------|---------|
+{rows}
 ## How to Use
 
 1. When asked to implement something involving a topic above, read that file first
 2. Follow the patterns shown — they handle edge cases that commonly cause bugs
 3. Pay attention to "NEVER" and "ALWAYS" callouts — these prevent the most common mistakes
 4. When in doubt, prefer the server-authoritative pattern
+5. Packs with version "custom" come from your team — their conventions take priority
 "#
+    )
 }
 
 /// DataStoreService: throttling, patterns, anti-patterns.
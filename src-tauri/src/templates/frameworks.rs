@@ -0,0 +1,183 @@
+//! Framework presets: a service/controller architecture (Knit/Flamework style)
+//! with a loader bootstrap, written over the standard starter project.
+
+/// A selectable framework preset.
+pub struct Framework {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// `(path relative to the project root, content)` pairs.
+    pub files: &'static [(&'static str, &'static str)],
+    /// Replaces default.project.json so the new folders are mapped.
+    pub project_json: fn(&str, &super::ProjectJsonOptions) -> String,
+}
+
+/// All built-in framework presets.
+pub const FRAMEWORKS: &[Framework] = &[Framework {
+    id: "services",
+    title: "Services & Controllers",
+    description: "Server Services and client Controllers loaded by a bootstrap, with Init/Start lifecycle.",
+    files: &[
+        ("src/ServerScriptService/main.server.luau", SERVER_BOOTSTRAP),
+        ("src/StarterPlayer/StarterPlayerScripts/main.client.luau", CLIENT_BOOTSTRAP),
+        ("src/Services/PointsService.luau", POINTS_SERVICE),
+        ("src/Controllers/PointsController.luau", POINTS_CONTROLLER),
+    ],
+    project_json: services_project_json,
+}];
+
+/// Looks up a framework preset by id.
+pub fn find(id: &str) -> Option<&'static Framework> {
+    FRAMEWORKS.iter().find(|f| f.id == id)
+}
+
+/// The standard project.json plus `src/Services` → ServerStorage.Services and
+/// `src/Controllers` → StarterPlayerScripts.Controllers. The services these
+/// live under are mapped even if the options leave them out.
+fn services_project_json(project_name: &str, options: &super::ProjectJsonOptions) -> String {
+    let mut options = options.clone();
+    options.require_services(&["ServerScriptService", "StarterPlayer", "ServerStorage"]);
+    let base = super::project_json(project_name, &options);
+    let mut value: serde_json::Value = match serde_json::from_str(&base) {
+        Ok(v) => v,
+        Err(_) => return base,
+    };
+    let tree = &mut value["tree"];
+    tree["ServerStorage"]["Services"] = serde_json::json!({ "$path": "src/Services" });
+    tree["StarterPlayer"]["StarterPlayerScripts"]["Controllers"] =
+        serde_json::json!({ "$path": "src/Controllers" });
+    serde_json::to_string_pretty(&value).map(|s| s + "\n").unwrap_or(base)
+}
+
+const SERVER_BOOTSTRAP: &str = r#"--!strict
+-- Server bootstrap. Loads every ModuleScript in ServerStorage.Services,
+-- calls Init on all of them, then Start on all of them.
+-- Init: set up state, no cross-service calls. Start: services may use each other.
+
+local ServerStorage = game:GetService("ServerStorage")
+
+local servicesFolder = ServerStorage:WaitForChild("Services")
+
+local services: { [string]: any } = {}
+for _, module in servicesFolder:GetChildren() do
+	if module:IsA("ModuleScript") then
+		services[module.Name] = require(module) :: any
+	end
+end
+
+for name, service in services do
+	if type(service.Init) == "function" then
+		local ok, err = pcall(service.Init, service, services)
+		if not ok then
+			warn(`{name}.Init failed: {err}`)
+		end
+	end
+end
+
+for name, service in services do
+	if type(service.Start) == "function" then
+		task.spawn(function()
+			local ok, err = pcall(service.Start, service)
+			if not ok then
+				warn(`{name}.Start failed: {err}`)
+			end
+		end)
+	end
+end
+"#;
+
+const CLIENT_BOOTSTRAP: &str = r#"--!strict
+-- Client bootstrap. Loads every ModuleScript in the Controllers folder next to
+-- this script, calls Init on all of them, then Start on all of them.
+
+local controllersFolder = script.Parent:WaitForChild("Controllers")
+
+local controllers: { [string]: any } = {}
+for _, module in controllersFolder:GetChildren() do
+	if module:IsA("ModuleScript") then
+		controllers[module.Name] = require(module) :: any
+	end
+end
+
+for name, controller in controllers do
+	if type(controller.Init) == "function" then
+		local ok, err = pcall(controller.Init, controller, controllers)
+		if not ok then
+			warn(`{name}.Init failed: {err}`)
+		end
+	end
+end
+
+for name, controller in controllers do
+	if type(controller.Start) == "function" then
+		task.spawn(function()
+			local ok, err = pcall(controller.Start, controller)
+			if not ok then
+				warn(`{name}.Start failed: {err}`)
+			end
+		end)
+	end
+end
+"#;
+
+const POINTS_SERVICE: &str = r#"--!strict
+-- Example Service. Services live in src/Services (ServerStorage.Services) and
+-- are loaded by the server bootstrap. Keep one responsibility per service.
+
+local Players = game:GetService("Players")
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local PointsService = {}
+
+local points: { [Player]: number } = {}
+local pointsChanged: RemoteEvent
+
+function PointsService:Init(_services: { [string]: any })
+	pointsChanged = Instance.new("RemoteEvent")
+	pointsChanged.Name = "PointsChanged"
+	pointsChanged.Parent = ReplicatedStorage
+end
+
+function PointsService:Start()
+	Players.PlayerAdded:Connect(function(player)
+		points[player] = 0
+	end)
+	Players.PlayerRemoving:Connect(function(player)
+		points[player] = nil
+	end)
+end
+
+function PointsService:AddPoints(player: Player, amount: number)
+	points[player] = (points[player] or 0) + amount
+	pointsChanged:FireClient(player, points[player])
+end
+
+function PointsService:GetPoints(player: Player): number
+	return points[player] or 0
+end
+
+return PointsService
+"#;
+
+const POINTS_CONTROLLER: &str = r#"--!strict
+-- Example Controller. Controllers live in src/Controllers
+-- (StarterPlayerScripts.Controllers) and are loaded by the client bootstrap.
+
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local PointsController = {}
+
+PointsController.points = 0
+
+function PointsController:Init(_controllers: { [string]: any }) end
+
+function PointsController:Start()
+	local pointsChanged = ReplicatedStorage:WaitForChild("PointsChanged") :: RemoteEvent
+	pointsChanged.OnClientEvent:Connect(function(points: number)
+		PointsController.points = points
+		print(`Points: {points}`)
+	end)
+end
+
+return PointsController
+"#;
@@ -0,0 +1,225 @@
+//! Lesson templates: partially completed games with an `EXERCISES.md` for
+//! students to work through. Files are written over the standard starter project.
+
+/// A selectable lesson template.
+pub struct Lesson {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// `(path relative to the project root, content)` pairs.
+    pub files: &'static [(&'static str, &'static str)],
+    pub exercises: &'static str,
+    /// Context packs (file names in `.roxlit/context/`) the exercises rely on.
+    pub packs: &'static [&'static str],
+}
+
+/// All built-in lessons, in the order shown at project creation.
+pub const LESSONS: &[Lesson] = &[
+    Lesson {
+        id: "coin-collector",
+        title: "Coin Collector",
+        description: "Collectible coins and a leaderboard. Students add scoring, respawns and saving.",
+        files: &[
+            ("src/ServerScriptService/Coins.server.luau", COIN_SERVER),
+            ("src/ServerScriptService/Leaderstats.server.luau", COIN_LEADERSTATS),
+        ],
+        exercises: COIN_EXERCISES,
+        packs: &["player-lifecycle.md", "workspace-physics.md", "datastore.md"],
+    },
+    Lesson {
+        id: "obby-checkpoints",
+        title: "Obby Checkpoints",
+        description: "An obstacle course with checkpoints. Students add spawn logic, kill bricks and a timer UI.",
+        files: &[
+            ("src/ServerScriptService/Checkpoints.server.luau", OBBY_SERVER),
+            ("src/StarterPlayer/StarterPlayerScripts/Timer.client.luau", OBBY_CLIENT),
+        ],
+        exercises: OBBY_EXERCISES,
+        packs: &["player-lifecycle.md", "remote-events.md", "studio-ui.md"],
+    },
+];
+
+/// Looks up a lesson by id.
+pub fn find(id: &str) -> Option<&'static Lesson> {
+    LESSONS.iter().find(|l| l.id == id)
+}
+
+/// Returns the `.roxlit/context/lesson.md` pack that tells the AI it is
+/// tutoring, and which packs back the exercises.
+pub fn hints(lesson: &Lesson) -> String {
+    let packs: String = lesson
+        .packs
+        .iter()
+        .map(|p| format!("- `.roxlit/context/{p}`\n"))
+        .collect();
+    format!(
+        r#"# Lesson: {title}
+
+This project is a classroom exercise. The student is working through `EXERCISES.md`.
+
+## How to help
+
+- Explain concepts and point to the relevant file and line; don't write the full solution.
+- When the student is stuck, give the next small step, then let them try it.
+- Code marked `-- TODO (Exercise N)` is the student's work. Don't fill it in unasked.
+- Review their attempts: say what works, then what to fix.
+
+## Relevant context packs
+
+{packs}"#,
+        title = lesson.title,
+    )
+}
+
+const COIN_SERVER: &str = r#"--!strict
+-- Spawns coins around the map. Touching a coin collects it.
+
+local Workspace = game:GetService("Workspace")
+
+local COIN_COUNT = 10
+local SPAWN_RADIUS = 40
+
+local coinsFolder = Instance.new("Folder")
+coinsFolder.Name = "Coins"
+coinsFolder.Parent = Workspace
+
+local function spawnCoin()
+	local coin = Instance.new("Part")
+	coin.Name = "Coin"
+	coin.Shape = Enum.PartType.Cylinder
+	coin.Size = Vector3.new(0.5, 3, 3)
+	coin.BrickColor = BrickColor.new("Bright yellow")
+	coin.Anchored = true
+	coin.CanCollide = false
+	coin.Position = Vector3.new(
+		math.random(-SPAWN_RADIUS, SPAWN_RADIUS),
+		3,
+		math.random(-SPAWN_RADIUS, SPAWN_RADIUS)
+	)
+	coin.Parent = coinsFolder
+
+	coin.Touched:Connect(function(hit: BasePart)
+		local character = hit.Parent
+		if not character or not character:FindFirstChildOfClass("Humanoid") then
+			return
+		end
+		coin:Destroy()
+		-- TODO (Exercise 1): give the player a point
+		-- TODO (Exercise 2): spawn a new coin after a short delay
+	end)
+end
+
+for _ = 1, COIN_COUNT do
+	spawnCoin()
+end
+"#;
+
+const COIN_LEADERSTATS: &str = r#"--!strict
+-- Creates the leaderboard shown in the top-right corner.
+
+local Players = game:GetService("Players")
+
+Players.PlayerAdded:Connect(function(player: Player)
+	local leaderstats = Instance.new("Folder")
+	leaderstats.Name = "leaderstats"
+	leaderstats.Parent = player
+
+	local coins = Instance.new("IntValue")
+	coins.Name = "Coins"
+	coins.Value = 0
+	coins.Parent = leaderstats
+
+	-- TODO (Exercise 3): load the saved coin count
+end)
+
+-- TODO (Exercise 3): save the coin count when the player leaves
+"#;
+
+const COIN_EXERCISES: &str = r#"# Coin Collector — Exercises
+
+Coins already spawn and disappear when touched. Finish the game.
+
+## Exercise 1: Scoring
+In `Coins.server.luau`, add 1 to the player's `leaderstats.Coins` when they collect a coin.
+Hint: `Players:GetPlayerFromCharacter(character)`.
+
+## Exercise 2: Respawning coins
+After a coin is collected, spawn a new one 3 seconds later.
+Hint: `task.delay`.
+
+## Exercise 3: Saving progress
+Save each player's coins with DataStoreService and load them when they join.
+Wrap every DataStore call in `pcall`.
+
+## Bonus
+Make coins spin, or add a rare gold coin worth 5 points.
+"#;
+
+const OBBY_SERVER: &str = r#"--!strict
+-- Checkpoints: parts named "Checkpoint1", "Checkpoint2", ... in Workspace.Checkpoints.
+
+local Players = game:GetService("Players")
+local Workspace = game:GetService("Workspace")
+
+local checkpoints = Workspace:WaitForChild("Checkpoints")
+
+local function onCheckpointTouched(checkpoint: BasePart, hit: BasePart)
+	local player = Players:GetPlayerFromCharacter(hit.Parent)
+	if not player then
+		return
+	end
+	local stage = tonumber(string.match(checkpoint.Name, "%d+")) or 0
+	local current = player:GetAttribute("Stage") or 0
+	if stage > current then
+		player:SetAttribute("Stage", stage)
+		print(`{player.Name} reached stage {stage}`)
+	end
+end
+
+for _, checkpoint in checkpoints:GetChildren() do
+	if checkpoint:IsA("BasePart") then
+		checkpoint.Touched:Connect(function(hit: BasePart)
+			onCheckpointTouched(checkpoint, hit)
+		end)
+	end
+end
+
+Players.PlayerAdded:Connect(function(player: Player)
+	player:SetAttribute("Stage", 0)
+	-- TODO (Exercise 1): when the character spawns, move it to its checkpoint
+end)
+
+-- TODO (Exercise 2): parts tagged "KillBrick" reset the character on touch
+"#;
+
+const OBBY_CLIENT: &str = r#"--!strict
+-- Shows how long the player has been running the course.
+
+local Players = game:GetService("Players")
+
+local player = Players.LocalPlayer
+
+-- TODO (Exercise 3): create a ScreenGui with a TextLabel and update it every frame
+print(`Timer ready for {player.Name}`)
+"#;
+
+const OBBY_EXERCISES: &str = r#"# Obby Checkpoints — Exercises
+
+Build a few platforms in Studio, and put parts named `Checkpoint1`, `Checkpoint2`, ...
+in a Folder named `Checkpoints` in Workspace. Touching a checkpoint already saves the stage.
+
+## Exercise 1: Respawn at checkpoint
+In `Checkpoints.server.luau`, use `player.CharacterAdded` to move the character
+to the checkpoint for its `Stage` attribute.
+
+## Exercise 2: Kill bricks
+Tag some parts `KillBrick` with CollectionService. Touching one sets the
+character's Humanoid health to 0.
+
+## Exercise 3: Timer UI
+In `Timer.client.luau`, show the elapsed time on screen.
+Hint: `RunService.RenderStepped` and `string.format("%.1f", seconds)`.
+
+## Bonus
+Stop the timer on the last checkpoint and show the player's best time.
+"#;
@@ -1,65 +1,105 @@
 pub mod context_packs;
+pub mod frameworks;
+pub mod lessons;
+pub mod modules;
+pub mod scaffolds;
+
+use serde::{Deserialize, Serialize};
+
+/// Rojo CLI version pinned in new projects. The Rojo Studio plugin is
+/// downloaded from the same release so the sync protocol matches.
+pub const ROJO_VERSION: &str = "7.4.4";
+
+/// Returns the aftman.toml content pinning the Rojo version.
+pub fn aftman_toml() -> String {
+    format!("[tools]\nrojo = \"rojo-rbx/rojo@{ROJO_VERSION}\"\n")
+}
+
+/// Services mapped to `src/<Service>` by default. StarterPlayer is mapped
+/// through its StarterPlayerScripts and StarterCharacterScripts children.
+pub const STANDARD_SERVICES: &[&str] = &[
+    "ServerScriptService",
+    "StarterPlayer",
+    "ReplicatedStorage",
+    "ReplicatedFirst",
+    "ServerStorage",
+    "Workspace",
+    "StarterGui",
+    "StarterPack",
+];
+
+/// How default.project.json is generated. Saved in the project settings
+/// (`projectJson`), so a file Roxlit regenerates keeps the same shape.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ProjectJsonOptions {
+    /// Services from `STANDARD_SERVICES` to map (default: all of them).
+    pub services: Vec<String>,
+    /// Set `$ignoreUnknownInstances` on every mapped folder, so instances
+    /// created in Studio survive a sync (default on). Off lets Rojo remove
+    /// anything not on disk.
+    pub ignore_unknown_instances: bool,
+    /// Extra `globIgnorePaths` patterns (e.g. `**/*.spec.luau`).
+    pub glob_ignore_paths: Vec<String>,
+}
+
+impl Default for ProjectJsonOptions {
+    fn default() -> Self {
+        Self {
+            services: STANDARD_SERVICES.iter().map(|s| s.to_string()).collect(),
+            ignore_unknown_instances: true,
+            glob_ignore_paths: Vec::new(),
+        }
+    }
+}
+
+impl ProjectJsonOptions {
+    /// Adds `services` that aren't mapped yet, for templates that put files in them.
+    pub fn require_services(&mut self, services: &[&str]) {
+        for service in services {
+            if !self.services.iter().any(|s| s == service) {
+                self.services.push(service.to_string());
+            }
+        }
+    }
+}
 
 /// Returns the default.project.json content for Rojo.
-pub fn project_json(project_name: &str) -> String {
-    format!(
-        r#"{{
-  "name": "{project_name}",
-  "tree": {{
-    "$className": "DataModel",
-    "ServerScriptService": {{
-      "$className": "ServerScriptService",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/ServerScriptService"
-    }},
-    "StarterPlayer": {{
-      "$className": "StarterPlayer",
-      "$ignoreUnknownInstances": true,
-      "StarterPlayerScripts": {{
-        "$className": "StarterPlayerScripts",
-        "$ignoreUnknownInstances": true,
-        "$path": "src/StarterPlayer/StarterPlayerScripts"
-      }},
-      "StarterCharacterScripts": {{
-        "$className": "StarterCharacterScripts",
-        "$ignoreUnknownInstances": true,
-        "$path": "src/StarterPlayer/StarterCharacterScripts"
-      }}
-    }},
-    "ReplicatedStorage": {{
-      "$className": "ReplicatedStorage",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/ReplicatedStorage"
-    }},
-    "ReplicatedFirst": {{
-      "$className": "ReplicatedFirst",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/ReplicatedFirst"
-    }},
-    "ServerStorage": {{
-      "$className": "ServerStorage",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/ServerStorage"
-    }},
-    "Workspace": {{
-      "$className": "Workspace",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/Workspace"
-    }},
-    "StarterGui": {{
-      "$className": "StarterGui",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/StarterGui"
-    }},
-    "StarterPack": {{
-      "$className": "StarterPack",
-      "$ignoreUnknownInstances": true,
-      "$path": "src/StarterPack"
-    }}
-  }}
-}}
-"#
-    )
+pub fn project_json(project_name: &str, options: &ProjectJsonOptions) -> String {
+    let folder = |class: &str, path: Option<&str>| {
+        let mut node = serde_json::json!({ "$className": class });
+        if options.ignore_unknown_instances {
+            node["$ignoreUnknownInstances"] = true.into();
+        }
+        if let Some(path) = path {
+            node["$path"] = path.into();
+        }
+        node
+    };
+
+    let mut tree = serde_json::json!({ "$className": "DataModel" });
+    // Unknown names are skipped. serde_json (without `preserve_order`) writes
+    // the keys sorted, which Rojo doesn't mind
+    for &service in STANDARD_SERVICES {
+        if !options.services.iter().any(|s| s == service) {
+            continue;
+        }
+        tree[service] = if service == "StarterPlayer" {
+            let mut node = folder(service, None);
+            for child in ["StarterPlayerScripts", "StarterCharacterScripts"] {
+                node[child] = folder(child, Some(&format!("src/StarterPlayer/{child}")));
+            }
+            node
+        } else {
+            folder(service, Some(&format!("src/{service}")))
+        };
+    }
+
+    let mut project = serde_json::json!({ "name": project_name, "tree": tree });
+    if !options.glob_ignore_paths.is_empty() {
+        project["globIgnorePaths"] = options.glob_ignore_paths.clone().into();
+    }
+    format!("{project:#}\n")
 }
 
 /// Returns the .luaurc configuration for strict type checking.
@@ -70,6 +110,29 @@ pub fn luaurc() -> &'static str {
 "#
 }
 
+/// Returns the `<project>.code-workspace` content: the project plus its AI
+/// context packs as a second root, Luau/Rojo settings and extension
+/// recommendations. VS Code, Cursor and Windsurf all read this format.
+pub fn code_workspace(project_name: &str) -> String {
+    let workspace = serde_json::json!({
+        "folders": [
+            { "name": project_name, "path": "." },
+            { "name": "AI context", "path": ".roxlit/context" }
+        ],
+        "settings": {
+            "files.associations": { "*.luau": "luau" },
+            "files.exclude": { ".roxlit/logs": true, ".roxlit/backups": true },
+            "search.exclude": { ".roxlit/logs": true, ".roxlit/backups": true },
+            "luau-lsp.sourcemap.rojoProjectFile": "default.project.json",
+            "luau-lsp.sourcemap.autogenerate": true
+        },
+        "extensions": {
+            "recommendations": ["JohnnyMorganz.luau-lsp", "evaera.vscode-rojo"]
+        }
+    });
+    format!("{:#}\n", workspace)
+}
+
 /// Returns a minimal server-side starter script.
 pub fn server_script() -> &'static str {
     r#"--!strict
@@ -159,10 +222,37 @@ pub fn roxlit_mcp_json(project_name: &str) -> String {
     )
 }
 
+/// Returns the .roxlit-mcp-ignore content: folders roxlit-mcp never syncs.
+/// `Packages/` covers Wally dependencies in projects that use them.
+pub fn roxlit_mcp_ignore() -> &'static str {
+    ".git/\n.roxlit/\n.claude/\n.cursor/\n.vscode/\n.windsurf/\n.github/\nnode_modules/\nPackages/\nsrc/\n"
+}
+
+/// Returns the initial `.roxlit/memory/MEMORY.md`, the notes the AI keeps
+/// across chat sessions.
+pub fn memory_md() -> &'static str {
+    r#"# Project Memory
+
+> This file persists across AI chat sessions. The AI updates it with key decisions, patterns, and project state.
+
+## Architecture Decisions
+
+(AI will document key decisions here as the project evolves)
+
+## Known Patterns
+
+(AI will document coding patterns and conventions established in this project)
+
+## Current State
+
+(AI will update this with what's in progress, what's blocked, etc.)
+"#
+}
+
 /// Context version — bump this whenever ai_context() content changes significantly.
 /// ensure_ai_context() compares this against the marker in the existing file to decide
 /// whether to regenerate. Format: same as Cargo.toml version.
-pub const CONTEXT_VERSION: &str = "0.12.0";
+pub const CONTEXT_VERSION: &str = "0.12.1";
 
 /// Marker prefix used to embed the version in the generated context file.
 /// Must be a comment that AI tools will ignore but we can parse.
@@ -738,6 +828,8 @@ This project includes curated Roblox documentation in `.roxlit/context/`. Before
 
 Read `.roxlit/context/index.md` for an overview of all available packs.
 
+If `EXERCISES.md` exists at the project root, this is a classroom lesson: read `.roxlit/context/lesson.md` first and follow its tutoring rules.
+
 ## Roxlit Launcher
 
 This project was set up with Roxlit. The Roxlit launcher manages Rojo automatically.
@@ -778,3 +870,54 @@ Settings the AI will look for here:
 "#
     )
 }
+
+/// Marks a context file generated by `ai_context_compact`, so ensure_ai_context
+/// can tell which variant is on disk.
+pub const COMPACT_MARKER: &str = "<!-- roxlit-context-variant: compact -->";
+
+/// Trimmed AI context for tools that inject the context file into every
+/// message. Keeps the rules that prevent common mistakes and links to the
+/// full guide (`.roxlit/context/full-guide.md`) and packs for everything else.
+pub fn ai_context_compact(project_name: &str, mcp_available: bool) -> String {
+    let mcp_section = if mcp_available {
+        r#"
+## MCP Tools
+
+Roxlit MCP tools talk to Roblox Studio: `run_code`, `run_test`, `insert_model`, `get_logs`, `list_sessions`, `backup_create`/`backup_list`/`backup_restore`/`backup_diff`, `telemetry_*`. Use `get_logs` (with `tail`) instead of reading log files. Call `backup_create` before any multi-file change. Never create instances with MCP — write `.model.json` files.
+"#
+    } else {
+        ""
+    };
+
+    format!(
+        r#"{VERSION_MARKER} {CONTEXT_VERSION} -->
+{COMPACT_MARKER}
+# {project_name}
+
+Roblox game using Luau (`--!strict`) and Rojo. Everything in `src/` syncs to Studio live:
+`*.server.luau` = Script, `*.client.luau` = LocalScript, `*.luau` = ModuleScript, `*.model.json` = instances.
+Folders under `src/` map to services (ServerScriptService, ReplicatedStorage, StarterPlayer/StarterPlayerScripts, ...).
+
+## Key Rules
+
+- Respond in the user's language.
+- `task.wait`/`task.spawn`, never `wait`/`spawn`. `local` everywhere, type annotations, `game:GetService`.
+- Never trust the client; validate on the server. No secrets in ReplicatedStorage.
+- Wrap every DataStore call in `pcall`.
+- `string.gsub` with a replacement containing `%` errors — use the function form.
+- Before giving Studio UI directions, read `.roxlit/context/studio-ui.md`.
+- Search for community systems (vehicles, combat, inventory) before building from scratch.
+- Debug from `.roxlit/logs/output.log` (Studio output) and `.roxlit/logs/system.log` (Rojo).
+{mcp_section}
+## More Detail (read on demand)
+
+- `.roxlit/context/full-guide.md` — the complete guide: project structure, `.model.json` format, logs, backups, workflow
+- `.roxlit/context/index.md` — topic packs (DataStore, remotes, physics, replication, ...)
+- `.roxlit/memory/MEMORY.md` — project memory; read at the start of each chat
+
+{USER_NOTES_MARKER}
+
+Add your own project-specific notes, rules, or instructions below this line. Roxlit will preserve this section when updating the context above.
+"#
+    )
+}
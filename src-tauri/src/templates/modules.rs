@@ -0,0 +1,364 @@
+//! Optional starter modules, selectable at project creation. Each follows the
+//! patterns taught in the matching context pack.
+
+/// A starter module and where it goes in the project.
+pub struct StarterModule {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Path relative to the project root.
+    pub path: &'static str,
+    pub content: &'static str,
+}
+
+/// All starter modules, in the order shown at project creation.
+pub const MODULES: &[StarterModule] = &[
+    StarterModule {
+        id: "profile-store",
+        title: "DataStore profiles",
+        description: "Loads and saves per-player data with pcall, retries and session caching.",
+        path: "src/ServerStorage/ProfileStore.luau",
+        content: PROFILE_STORE,
+    },
+    StarterModule {
+        id: "net",
+        title: "Typed RemoteEvents",
+        description: "RemoteEvent wrapper with argument validation and per-player rate limiting.",
+        path: "src/ReplicatedStorage/Net.luau",
+        content: NET,
+    },
+    StarterModule {
+        id: "state-machine",
+        title: "State machine",
+        description: "Minimal finite state machine with enter/exit callbacks, for rounds and AI.",
+        path: "src/ReplicatedStorage/StateMachine.luau",
+        content: STATE_MACHINE,
+    },
+];
+
+/// Looks up a starter module by id.
+pub fn find(id: &str) -> Option<&'static StarterModule> {
+    MODULES.iter().find(|m| m.id == id)
+}
+
+const PROFILE_STORE: &str = r#"--!strict
+-- Per-player data backed by DataStoreService.
+-- Every DataStore call is wrapped in pcall and retried; data is cached for
+-- the session and saved on leave and on shutdown.
+--
+-- Usage (server):
+--   local ProfileStore = require(game:GetService("ServerStorage").ProfileStore)
+--   local store = ProfileStore.new("PlayerData", { coins = 0 })
+--   local data = store:get(player)
+--   data.coins += 1
+
+local DataStoreService = game:GetService("DataStoreService")
+local Players = game:GetService("Players")
+
+local MAX_RETRIES = 3
+local RETRY_DELAY = 1
+
+local ProfileStore = {}
+ProfileStore.__index = ProfileStore
+
+export type ProfileStore = typeof(setmetatable(
+	{} :: {
+		_store: DataStore,
+		_template: { [string]: any },
+		_profiles: { [Player]: { [string]: any } },
+	},
+	ProfileStore
+))
+
+local function withRetries<T>(callback: () -> T): (boolean, T | string)
+	local lastError = ""
+	for attempt = 1, MAX_RETRIES do
+		local ok, result = pcall(callback)
+		if ok then
+			return true, result
+		end
+		lastError = tostring(result)
+		task.wait(RETRY_DELAY * attempt)
+	end
+	return false, lastError
+end
+
+local function reconcile(data: { [string]: any }, template: { [string]: any })
+	for key, value in template do
+		if data[key] == nil then
+			data[key] = if type(value) == "table" then table.clone(value) else value
+		end
+	end
+end
+
+function ProfileStore.new(name: string, template: { [string]: any }): ProfileStore
+	local self = setmetatable({
+		_store = DataStoreService:GetDataStore(name),
+		_template = template,
+		_profiles = {},
+	}, ProfileStore)
+
+	Players.PlayerAdded:Connect(function(player)
+		self:_load(player)
+	end)
+	Players.PlayerRemoving:Connect(function(player)
+		self:_save(player)
+		self._profiles[player] = nil
+	end)
+	game:BindToClose(function()
+		for player in self._profiles do
+			self:_save(player)
+		end
+	end)
+	for _, player in Players:GetPlayers() do
+		task.spawn(self._load, self, player)
+	end
+
+	return self
+end
+
+function ProfileStore._load(self: ProfileStore, player: Player)
+	local ok, result = withRetries(function()
+		return self._store:GetAsync(tostring(player.UserId))
+	end)
+	if not ok then
+		-- Don't play with default data: saving it would overwrite the real profile
+		player:Kick("Could not load your data. Please rejoin.")
+		return
+	end
+	local data = if type(result) == "table" then result else {}
+	reconcile(data, self._template)
+	self._profiles[player] = data
+end
+
+function ProfileStore._save(self: ProfileStore, player: Player)
+	local data = self._profiles[player]
+	if not data then
+		return
+	end
+	local ok, err = withRetries(function()
+		return self._store:SetAsync(tostring(player.UserId), data)
+	end)
+	if not ok then
+		warn(`ProfileStore: failed to save {player.Name}: {err}`)
+	end
+end
+
+-- Returns the player's data, waiting for it to load. nil if the player left.
+function ProfileStore.get(self: ProfileStore, player: Player): { [string]: any }?
+	while not self._profiles[player] and player.Parent do
+		task.wait()
+	end
+	return self._profiles[player]
+end
+
+return ProfileStore
+"#;
+
+const NET: &str = r#"--!strict
+-- RemoteEvent wrapper: one place to create remotes, validate arguments on the
+-- server, and rate limit each player.
+--
+-- Usage:
+--   -- server
+--   local Net = require(game:GetService("ReplicatedStorage").Net)
+--   local buy = Net.event("BuyItem", { "string" }, 5) -- max 5 calls/second
+--   buy:onServer(function(player, itemId: string) ... end)
+--   -- client
+--   Net.event("BuyItem", { "string" }):fire("sword")
+
+local Players = game:GetService("Players")
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+local RunService = game:GetService("RunService")
+
+local IS_SERVER = RunService:IsServer()
+local FOLDER_NAME = "NetRemotes"
+
+local Event = {}
+Event.__index = Event
+
+export type Event = typeof(setmetatable(
+	{} :: {
+		_remote: RemoteEvent,
+		_types: { string },
+		_rate: number,
+		_calls: { [Player]: { count: number, window: number } },
+	},
+	Event
+))
+
+local function getFolder(): Folder
+	if IS_SERVER then
+		local folder = ReplicatedStorage:FindFirstChild(FOLDER_NAME)
+		if not folder then
+			folder = Instance.new("Folder")
+			folder.Name = FOLDER_NAME
+			folder.Parent = ReplicatedStorage
+		end
+		return folder :: Folder
+	end
+	return ReplicatedStorage:WaitForChild(FOLDER_NAME) :: Folder
+end
+
+local function getRemote(name: string): RemoteEvent
+	local folder = getFolder()
+	if IS_SERVER then
+		local remote = folder:FindFirstChild(name)
+		if not remote then
+			remote = Instance.new("RemoteEvent")
+			remote.Name = name
+			remote.Parent = folder
+		end
+		return remote :: RemoteEvent
+	end
+	return folder:WaitForChild(name) :: RemoteEvent
+end
+
+local Net = {}
+
+-- types: expected typeof() of each argument. rate: max calls per second per player.
+function Net.event(name: string, types: { string }, rate: number?): Event
+	local self = setmetatable({
+		_remote = getRemote(name),
+		_types = types,
+		_rate = rate or 10,
+		_calls = {},
+	}, Event)
+	if IS_SERVER then
+		Players.PlayerRemoving:Connect(function(player)
+			self._calls[player] = nil
+		end)
+	end
+	return self
+end
+
+function Event._allow(self: Event, player: Player): boolean
+	local now = os.clock()
+	local calls = self._calls[player]
+	if not calls or now - calls.window >= 1 then
+		self._calls[player] = { count = 1, window = now }
+		return true
+	end
+	calls.count += 1
+	return calls.count <= self._rate
+end
+
+function Event._valid(self: Event, args: { any }): boolean
+	if #args ~= #self._types then
+		return false
+	end
+	for i, expected in self._types do
+		if typeof(args[i]) ~= expected then
+			return false
+		end
+	end
+	return true
+end
+
+-- Server: handle calls from clients. Invalid or rate-limited calls are dropped.
+function Event.onServer(self: Event, handler: (Player, ...any) -> ())
+	self._remote.OnServerEvent:Connect(function(player, ...)
+		local args = { ... }
+		if not self:_allow(player) or not self:_valid(args) then
+			return
+		end
+		handler(player, table.unpack(args))
+	end)
+end
+
+-- Client: handle events from the server.
+function Event.onClient(self: Event, handler: (...any) -> ())
+	self._remote.OnClientEvent:Connect(handler)
+end
+
+-- Client: send to the server.
+function Event.fire(self: Event, ...: any)
+	self._remote:FireServer(...)
+end
+
+-- Server: send to one player, or to everyone when player is nil.
+function Event.fireClient(self: Event, player: Player?, ...: any)
+	if player then
+		self._remote:FireClient(player, ...)
+	else
+		self._remote:FireAllClients(...)
+	end
+end
+
+return Net
+"#;
+
+const STATE_MACHINE: &str = r#"--!strict
+-- Minimal finite state machine. Only one state is active; switching calls the
+-- old state's exit and the new state's enter.
+--
+-- Usage:
+--   local StateMachine = require(game:GetService("ReplicatedStorage").StateMachine)
+--   local round = StateMachine.new({
+--       Intermission = { enter = function() print("waiting") end },
+--       Playing = { enter = startRound, exit = endRound },
+--   }, "Intermission")
+--   round:transition("Playing")
+
+export type State = {
+	enter: ((from: string?) -> ())?,
+	exit: ((to: string) -> ())?,
+}
+
+local StateMachine = {}
+StateMachine.__index = StateMachine
+
+export type StateMachine = typeof(setmetatable(
+	{} :: {
+		current: string,
+		_states: { [string]: State },
+		_listeners: { (from: string, to: string) -> () },
+	},
+	StateMachine
+))
+
+function StateMachine.new(states: { [string]: State }, initial: string): StateMachine
+	assert(states[initial], `Unknown initial state {initial}`)
+	local self = setmetatable({
+		current = initial,
+		_states = states,
+		_listeners = {},
+	}, StateMachine)
+	local enter = states[initial].enter
+	if enter then
+		enter(nil)
+	end
+	return self
+end
+
+function StateMachine.transition(self: StateMachine, to: string)
+	local target = self._states[to]
+	assert(target, `Unknown state {to}`)
+	if to == self.current then
+		return
+	end
+	local from = self.current
+	local exit = self._states[from].exit
+	if exit then
+		exit(to)
+	end
+	self.current = to
+	if target.enter then
+		target.enter(from)
+	end
+	for _, listener in self._listeners do
+		task.spawn(listener, from, to)
+	end
+end
+
+function StateMachine.is(self: StateMachine, state: string): boolean
+	return self.current == state
+end
+
+-- Called after every transition.
+function StateMachine.onChanged(self: StateMachine, listener: (from: string, to: string) -> ())
+	table.insert(self._listeners, listener)
+end
+
+return StateMachine
+"#;
@@ -0,0 +1,280 @@
+//! System scaffolds: small sets of wired-up server/client/config files for
+//! common game systems. `__NAME__` in paths and content is replaced with the
+//! system name chosen by the user.
+
+/// A scaffoldable system.
+pub struct Scaffold {
+    pub kind: &'static str,
+    pub description: &'static str,
+    /// `(path relative to the project root, content)` pairs.
+    pub files: &'static [(&'static str, &'static str)],
+}
+
+/// Placeholder replaced with the system name.
+pub const NAME_PLACEHOLDER: &str = "__NAME__";
+
+/// All built-in scaffolds.
+pub const SCAFFOLDS: &[Scaffold] = &[
+    Scaffold {
+        kind: "shop",
+        description: "Item catalog, server-validated purchases, and a client purchase helper.",
+        files: &[
+            ("src/ReplicatedStorage/__NAME__Items.luau", SHOP_ITEMS),
+            ("src/ServerScriptService/__NAME__.server.luau", SHOP_SERVER),
+            ("src/StarterPlayer/StarterPlayerScripts/__NAME__.client.luau", SHOP_CLIENT),
+        ],
+    },
+    Scaffold {
+        kind: "round-manager",
+        description: "Intermission → playing → results loop with a replicated round state.",
+        files: &[
+            ("src/ServerScriptService/__NAME__.server.luau", ROUND_SERVER),
+            ("src/StarterPlayer/StarterPlayerScripts/__NAME__.client.luau", ROUND_CLIENT),
+        ],
+    },
+    Scaffold {
+        kind: "daily-rewards",
+        description: "Once-a-day reward with streaks, saved in a DataStore.",
+        files: &[
+            ("src/ReplicatedStorage/__NAME__Config.luau", DAILY_CONFIG),
+            ("src/ServerScriptService/__NAME__.server.luau", DAILY_SERVER),
+            ("src/StarterPlayer/StarterPlayerScripts/__NAME__.client.luau", DAILY_CLIENT),
+        ],
+    },
+];
+
+/// Looks up a scaffold by kind.
+pub fn find(kind: &str) -> Option<&'static Scaffold> {
+    SCAFFOLDS.iter().find(|s| s.kind == kind)
+}
+
+const SHOP_ITEMS: &str = r#"--!strict
+-- Items sold by the __NAME__ shop. Shared so the client can show prices.
+
+export type Item = {
+	name: string,
+	price: number,
+}
+
+local Items: { [string]: Item } = {
+	Sword = { name = "Sword", price = 100 },
+	Shield = { name = "Shield", price = 250 },
+}
+
+return Items
+"#;
+
+const SHOP_SERVER: &str = r#"--!strict
+-- __NAME__ shop: validates and applies purchases. Never trust the client's price.
+
+local Players = game:GetService("Players")
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local Items = require(ReplicatedStorage:WaitForChild("__NAME__Items"))
+
+local purchase = Instance.new("RemoteFunction")
+purchase.Name = "__NAME__Purchase"
+purchase.Parent = ReplicatedStorage
+
+local function getCoins(player: Player): IntValue?
+	local leaderstats = player:FindFirstChild("leaderstats")
+	return leaderstats and leaderstats:FindFirstChild("Coins") :: IntValue?
+end
+
+purchase.OnServerInvoke = function(player: Player, itemId: unknown): (boolean, string)
+	if typeof(itemId) ~= "string" then
+		return false, "Invalid item"
+	end
+	local item = Items[itemId]
+	if not item then
+		return false, "Unknown item"
+	end
+	local coins = getCoins(player)
+	if not coins or coins.Value < item.price then
+		return false, "Not enough coins"
+	end
+	coins.Value -= item.price
+	-- TODO: grant the item (e.g. clone a Tool from ServerStorage into player.Backpack)
+	return true, `Bought {item.name}`
+end
+
+Players.PlayerAdded:Connect(function(player: Player)
+	if not player:FindFirstChild("leaderstats") then
+		local leaderstats = Instance.new("Folder")
+		leaderstats.Name = "leaderstats"
+		leaderstats.Parent = player
+		local coins = Instance.new("IntValue")
+		coins.Name = "Coins"
+		coins.Parent = leaderstats
+	end
+end)
+"#;
+
+const SHOP_CLIENT: &str = r#"--!strict
+-- __NAME__ shop client: call buy(itemId) from your shop UI.
+
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local purchase = ReplicatedStorage:WaitForChild("__NAME__Purchase") :: RemoteFunction
+
+local function buy(itemId: string)
+	local ok, message = purchase:InvokeServer(itemId)
+	print(if ok then message else `Purchase failed: {message}`)
+end
+
+-- TODO: connect buy() to your shop buttons
+local _ = buy
+"#;
+
+const ROUND_SERVER: &str = r#"--!strict
+-- __NAME__: runs the round loop and replicates the state via attributes on
+-- ReplicatedStorage, so clients can react without extra remotes.
+
+local Players = game:GetService("Players")
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local INTERMISSION = 15
+local ROUND_LENGTH = 120
+local RESULTS = 5
+local MIN_PLAYERS = 1
+
+local function setState(state: string, duration: number)
+	ReplicatedStorage:SetAttribute("__NAME__State", state)
+	ReplicatedStorage:SetAttribute("__NAME__EndsAt", workspace:GetServerTimeNow() + duration)
+end
+
+local function startRound()
+	-- TODO: teleport players to the map, give tools, etc.
+end
+
+local function endRound()
+	-- TODO: award winners, reset the map
+end
+
+while true do
+	setState("Intermission", INTERMISSION)
+	task.wait(INTERMISSION)
+
+	if #Players:GetPlayers() < MIN_PLAYERS then
+		continue
+	end
+
+	setState("Playing", ROUND_LENGTH)
+	startRound()
+	task.wait(ROUND_LENGTH)
+	endRound()
+
+	setState("Results", RESULTS)
+	task.wait(RESULTS)
+end
+"#;
+
+const ROUND_CLIENT: &str = r#"--!strict
+-- __NAME__ client: follows the round state replicated by the server.
+
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local function onStateChanged()
+	local state = ReplicatedStorage:GetAttribute("__NAME__State")
+	local endsAt = ReplicatedStorage:GetAttribute("__NAME__EndsAt")
+	if typeof(state) ~= "string" or typeof(endsAt) ~= "number" then
+		return
+	end
+	local remaining = math.max(0, math.floor(endsAt - workspace:GetServerTimeNow()))
+	-- TODO: show the state and countdown in your UI
+	print(`{state} ({remaining}s)`)
+end
+
+ReplicatedStorage:GetAttributeChangedSignal("__NAME__State"):Connect(onStateChanged)
+onStateChanged()
+"#;
+
+const DAILY_CONFIG: &str = r#"--!strict
+-- __NAME__ rewards by streak day. The last entry repeats after the streak ends.
+
+local Config = {
+	COOLDOWN = 20 * 60 * 60, -- claimable again after 20h
+	STREAK_RESET = 48 * 60 * 60, -- streak lost after 48h
+	REWARDS = { 50, 75, 100, 150, 200, 300, 500 },
+}
+
+return Config
+"#;
+
+const DAILY_SERVER: &str = r#"--!strict
+-- __NAME__: daily reward claims with streaks, saved per player.
+-- DataStore calls are wrapped in pcall; a failed load disables claiming.
+
+local DataStoreService = game:GetService("DataStoreService")
+local Players = game:GetService("Players")
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local Config = require(ReplicatedStorage:WaitForChild("__NAME__Config"))
+
+local store = DataStoreService:GetDataStore("__NAME__")
+
+type Record = { lastClaim: number, streak: number }
+local records: { [Player]: Record } = {}
+
+local claim = Instance.new("RemoteFunction")
+claim.Name = "__NAME__Claim"
+claim.Parent = ReplicatedStorage
+
+Players.PlayerAdded:Connect(function(player: Player)
+	local ok, data = pcall(function()
+		return store:GetAsync(tostring(player.UserId))
+	end)
+	if ok then
+		records[player] = if type(data) == "table" then data else { lastClaim = 0, streak = 0 }
+	else
+		warn(`__NAME__: could not load {player.Name}: {data}`)
+	end
+end)
+
+Players.PlayerRemoving:Connect(function(player: Player)
+	local record = records[player]
+	records[player] = nil
+	if record then
+		pcall(function()
+			store:SetAsync(tostring(player.UserId), record)
+		end)
+	end
+end)
+
+claim.OnServerInvoke = function(player: Player): (boolean, number)
+	local record = records[player]
+	if not record then
+		return false, 0
+	end
+	local now = os.time()
+	local elapsed = now - record.lastClaim
+	if elapsed < Config.COOLDOWN then
+		return false, Config.COOLDOWN - elapsed
+	end
+	record.streak = if elapsed > Config.STREAK_RESET then 1 else record.streak + 1
+	record.lastClaim = now
+	local reward = Config.REWARDS[math.min(record.streak, #Config.REWARDS)]
+	-- TODO: give the reward (e.g. add to leaderstats)
+	return true, reward
+end
+"#;
+
+const DAILY_CLIENT: &str = r#"--!strict
+-- __NAME__ client: call claimReward() from your rewards UI.
+
+local ReplicatedStorage = game:GetService("ReplicatedStorage")
+
+local claim = ReplicatedStorage:WaitForChild("__NAME__Claim") :: RemoteFunction
+
+local function claimReward()
+	local ok, value = claim:InvokeServer()
+	if ok then
+		print(`Claimed {value} coins`)
+	else
+		print(`Next reward in {math.ceil(value / 60)} minutes`)
+	end
+end
+
+-- TODO: connect claimReward() to a button
+local _ = claimReward
+"#;
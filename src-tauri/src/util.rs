@@ -18,3 +18,70 @@ pub fn expand_tilde(path: &str) -> String {
         result
     }
 }
+
+/// Quotes one argument for a command line that cmd.exe parses itself
+/// (`cmd /c start ...`). Rust's quoting for Windows programs isn't enough
+/// there: `&`, `^` or parentheses in a project or folder name would split the
+/// command. `%` is escaped outside the quotes so it can't expand a variable.
+/// Windows paths can't contain `"`, so it isn't handled.
+pub fn cmd_quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.chars().any(|c| " \t&|<>()^%,;=".contains(c)) {
+        return arg.to_string();
+    }
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        if c == '%' {
+            quoted.push_str("\"^%\"");
+        } else {
+            quoted.push(c);
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A cmd.exe command line from separate arguments, each quoted with `cmd_quote`.
+/// Pass it with `CommandExt::raw_arg` so it reaches cmd.exe as is.
+pub fn cmd_line(args: &[&str]) -> String {
+    args.iter().map(|arg| cmd_quote(arg)).collect::<Vec<_>>().join(" ")
+}
+
+/// Quotes one argument for sh, e.g. a path in a generated script.
+pub fn sh_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// Image name of the process `pid` in `tasklist /FO CSV /NH` output. Matches
+/// the PID column exactly (a plain substring check finds 12 in 1234) and
+/// doesn't depend on the language of tasklist's messages.
+pub fn tasklist_image(output: &str, pid: u32) -> Option<String> {
+    let pid = pid.to_string();
+    output.lines().find_map(|line| {
+        let mut fields = line.trim().trim_matches('"').split("\",\"");
+        let image = fields.next()?;
+        (fields.next()? == pid).then(|| image.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmd_quote() {
+        assert_eq!(cmd_quote("claude"), "claude");
+        assert_eq!(cmd_quote(""), "\"\"");
+        assert_eq!(cmd_quote(r"C:\Users\Zoë\Roblox Projects\Tom & Jerry"), "\"C:\\Users\\Zoë\\Roblox Projects\\Tom & Jerry\"");
+        assert_eq!(cmd_quote("100%done"), "\"100\"^%\"done\"");
+        assert_eq!(cmd_line(&["/c", "start", "", "cmd"]), "/c start \"\" cmd");
+    }
+
+    #[test]
+    fn test_tasklist_image() {
+        let output = "\"rojo.exe\",\"1234\",\"Console\",\"1\",\"12,345 K\"\r\n\"code.exe\",\"12\",\"Console\",\"1\",\"99 K\"\r\n";
+        assert_eq!(tasklist_image(output, 12).as_deref(), Some("code.exe"));
+        assert_eq!(tasklist_image(output, 1234).as_deref(), Some("rojo.exe"));
+        assert_eq!(tasklist_image(output, 123), None);
+        assert_eq!(tasklist_image("INFO: No tasks are running which match the specified criteria.", 12), None);
+    }
+}
@@ -15,7 +15,7 @@ import { useLauncher } from "./hooks/useLauncher";
 import { useUpdateChecker } from "./hooks/useUpdateChecker";
 import { Recovery } from "./components/steps/Recovery";
 import { TOOL_OPTIONS } from "./lib/types";
-import type { AppMode, DiscoveredProject, ProjectEntry, RoxlitConfig } from "./lib/types";
+import type { AppMode, DiscoveredProject, ProjectEntry, RoxlitConfig, SettingsPatch } from "./lib/types";
 
 export default function App() {
   const [mode, setMode] = useState<AppMode>("loading");
@@ -121,7 +121,8 @@ export default function App() {
       prev ? { ...prev, updateDelayDays: days } : prev
     );
     try {
-      await invoke("save_settings", { updateDelayDays: days });
+      const patch: SettingsPatch = { updateDelayDays: days };
+      await invoke("save_settings", { patch });
     } catch {
       // Silent failure — settings save is non-critical
     }
@@ -156,11 +157,17 @@ export default function App() {
           rojoPort={launcher.rojoPort}
           logs={launcher.logs}
           error={launcher.error}
+          firstSync={launcher.firstSync}
+          editorError={launcher.editorError}
           update={update}
           updateDelayDays={updateDelayDays}
           onStartDevelopment={launcher.startDevelopment}
           onStopAll={launcher.stopAll}
           onOpenEditor={launcher.openEditor}
+          onOpenEditorWith={launcher.openEditorWith}
+          onDismissEditorError={launcher.dismissEditorError}
+          onPreviewFirstSync={launcher.previewFirstSync}
+          onConfirmFirstSync={launcher.confirmFirstSync}
           onNewProject={handleNewProject}
           onDismissUpdate={dismissUpdate}
           onUpdateDelayChange={handleUpdateDelayChange}
@@ -0,0 +1,83 @@
+import { useState } from "react";
+import { Check, Loader2, Search } from "lucide-react";
+import type { SyncPreview } from "@/lib/types";
+
+interface FirstSyncReviewProps {
+  message: string;
+  preview: SyncPreview | null;
+  onPreview: () => Promise<void>;
+  onConfirm: () => Promise<void>;
+}
+
+function PathList({ title, paths, count }: { title: string; paths: string[]; count: number }) {
+  if (count === 0) return null;
+  return (
+    <div className="mt-2">
+      <p className="text-[11px] text-zinc-400">
+        {title} ({count})
+      </p>
+      <ul className="mt-1 max-h-24 overflow-y-auto font-mono text-[11px] text-zinc-500">
+        {paths.map((path) => (
+          <li key={path} className="truncate">
+            {path}
+          </li>
+        ))}
+        {paths.length < count && <li>…</li>}
+      </ul>
+    </div>
+  );
+}
+
+/** Review of an adopted project's first sync before Rojo is allowed to run. */
+export function FirstSyncReview({ message, preview, onPreview, onConfirm }: FirstSyncReviewProps) {
+  const [busy, setBusy] = useState(false);
+
+  async function run(action: () => Promise<void>) {
+    if (busy) return;
+    setBusy(true);
+    try {
+      await action();
+    } finally {
+      setBusy(false);
+    }
+  }
+
+  return (
+    <div className="rounded-md border border-yellow-500/20 bg-yellow-500/[0.05] px-3 py-2">
+      <p className="text-xs text-yellow-400">{message}</p>
+      {preview && (
+        <div className="mt-1">
+          {preview.addedCount === 0 && preview.removedCount === 0 ? (
+            <p className="text-[11px] text-zinc-400">The first sync won't change anything in Studio.</p>
+          ) : (
+            <>
+              <PathList title="Created in Studio" paths={preview.added} count={preview.addedCount} />
+              <PathList title="Deleted from Studio" paths={preview.removed} count={preview.removedCount} />
+            </>
+          )}
+        </div>
+      )}
+      <div className="mt-2 flex items-center gap-1">
+        <button
+          onClick={() => run(onPreview)}
+          disabled={busy}
+          className="flex items-center gap-1 rounded px-2 py-1 text-xs text-zinc-400 transition-colors hover:bg-white/5 hover:text-zinc-200 disabled:opacity-50"
+          title="Open the project's place in Studio first"
+        >
+          {busy ? <Loader2 className="h-3 w-3 animate-spin" /> : <Search className="h-3 w-3" />}
+          {preview ? "Compare again" : "Compare with Studio"}
+        </button>
+        {preview && (
+          <button
+            onClick={() => run(onConfirm)}
+            disabled={busy}
+            className="flex items-center gap-1 rounded px-2 py-1 text-xs font-medium text-emerald-400 transition-colors hover:bg-emerald-500/10 disabled:opacity-50"
+          >
+            <Check className="h-3 w-3" />
+            Start syncing
+          </button>
+        )}
+      </div>
+    </div>
+  );
+}
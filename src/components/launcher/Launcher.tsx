@@ -10,6 +10,7 @@ import {
   Loader2,
   ChevronDown,
   Check,
+  X,
 } from "lucide-react";
 import { openUrl } from "@tauri-apps/plugin-opener";
 import { invoke } from "@tauri-apps/api/core";
@@ -17,8 +18,9 @@ import { getVersion } from "@tauri-apps/api/app";
 import { LogTerminal } from "./LogTerminal";
 import { UpdateBanner } from "./UpdateBanner";
 import { SettingsPopover } from "./SettingsPopover";
+import { FirstSyncReview } from "./FirstSyncReview";
 import { TOOL_OPTIONS } from "@/lib/types";
-import type { ProjectEntry, RojoStatus, UpdateInfo } from "@/lib/types";
+import type { EditorError, ProjectEntry, RojoStatus, SyncPreview, UpdateInfo } from "@/lib/types";
 
 async function openExternal(url: string) {
   try {
@@ -40,11 +42,17 @@ interface LauncherProps {
   rojoPort: number | null;
   logs: string[];
   error: string | null;
+  firstSync: { message: string; preview: SyncPreview | null } | null;
+  editorError: EditorError | null;
   update: UpdateInfo | null;
   updateDelayDays: number;
   onStartDevelopment: () => void;
   onStopAll: () => void;
   onOpenEditor: () => void;
+  onOpenEditorWith: (editor: string) => void;
+  onDismissEditorError: () => void;
+  onPreviewFirstSync: () => Promise<void>;
+  onConfirmFirstSync: () => Promise<void>;
   onNewProject: () => void;
   onDismissUpdate: () => void;
   onUpdateDelayChange: (days: number) => void;
@@ -57,6 +65,7 @@ function StatusDot({ status }: { status: RojoStatus }) {
     stopped: "bg-zinc-500",
     starting: "bg-yellow-400 animate-pulse",
     running: "bg-emerald-400",
+    reviewing: "bg-yellow-400",
     error: "bg-red-400",
   };
   return <div className={`h-2 w-2 rounded-full ${colors[status] ?? "bg-zinc-500"}`} />;
@@ -91,6 +100,8 @@ function RojoStatusText({
           ) : ""}
         </span>
       );
+    case "reviewing":
+      return <span className="text-yellow-400">Rojo waiting for first-sync review</span>;
     case "error":
       return <span className="text-red-400">Rojo error</span>;
   }
@@ -104,11 +115,17 @@ export function Launcher({
   rojoPort,
   logs,
   error,
+  firstSync,
+  editorError,
   update,
   updateDelayDays,
   onStartDevelopment,
   onStopAll,
   onOpenEditor,
+  onOpenEditorWith,
+  onDismissEditorError,
+  onPreviewFirstSync,
+  onConfirmFirstSync,
   onNewProject,
   onDismissUpdate,
   onUpdateDelayChange,
@@ -141,7 +158,8 @@ export function Launcher({
   }, [projectDropdownOpen]);
   const toolName =
     TOOL_OPTIONS.find((t) => t.id === aiTool)?.name ?? "your AI tool";
-  const isRunning = rojoStatus === "running" || rojoStatus === "starting";
+  const isRunning =
+    rojoStatus === "running" || rojoStatus === "starting" || rojoStatus === "reviewing";
 
   async function handleOpenEditor() {
     if (editorLoading) return;
@@ -284,6 +302,18 @@ export function Launcher({
         </div>
       </div>
 
+      {/* Adopted project: first sync held back until reviewed */}
+      {firstSync && (
+        <div className="mt-2">
+          <FirstSyncReview
+            message={firstSync.message}
+            preview={firstSync.preview}
+            onPreview={onPreviewFirstSync}
+            onConfirm={onConfirmFirstSync}
+          />
+        </div>
+      )}
+
       {/* Error display */}
       {error && (
         <div className="mt-2 rounded-md border border-red-500/20 bg-red-500/[0.05] px-3 py-2 text-xs text-red-400">
@@ -291,6 +321,36 @@ export function Launcher({
         </div>
       )}
 
+      {/* Editor couldn't be opened: offer the ones that were found */}
+      {editorError && (
+        <div className="mt-2 flex items-start gap-2 rounded-md border border-yellow-500/20 bg-yellow-500/[0.05] px-3 py-2">
+          <div className="flex-1">
+            <p className="text-xs text-yellow-400">{editorError.message}</p>
+            {editorError.available.length > 0 && (
+              <div className="mt-1.5 flex flex-wrap gap-1">
+                {editorError.available.map((editor) => (
+                  <button
+                    key={editor.id}
+                    onClick={() => onOpenEditorWith(editor.id)}
+                    className="flex items-center gap-1 rounded px-2 py-1 text-xs text-zinc-300 transition-colors hover:bg-white/5"
+                  >
+                    <Code2 className="h-3 w-3" />
+                    Open in {editor.name}
+                  </button>
+                ))}
+              </div>
+            )}
+          </div>
+          <button
+            onClick={onDismissEditorError}
+            className="text-zinc-500 transition-colors hover:text-zinc-300"
+            title="Dismiss"
+          >
+            <X className="h-3.5 w-3.5" />
+          </button>
+        </div>
+      )}
+
       {/* Terminal */}
       <div className="mt-4 flex min-h-0 flex-1 flex-col">
         <LogTerminal logs={logs} />
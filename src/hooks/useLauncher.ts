@@ -1,6 +1,6 @@
 import { useReducer, useCallback, useRef } from "react";
 import { invoke, Channel } from "@tauri-apps/api/core";
-import type { ProjectEntry, RojoEvent, RojoStatus } from "@/lib/types";
+import type { EditorError, ProjectEntry, RojoEvent, RojoStatus, SyncPreview } from "@/lib/types";
 
 const MAX_LOGS = 500;
 const MAX_AUTO_RESTARTS = 3;
@@ -13,6 +13,10 @@ interface LauncherState {
   rojoPort: number | null;
   logs: string[];
   error: string | null;
+  /** Set while an adopted project's first sync waits for review. */
+  firstSync: { message: string; preview: SyncPreview | null } | null;
+  /** Why the project couldn't be opened in an editor, with the ones found instead. */
+  editorError: EditorError | null;
 }
 
 type Action =
@@ -22,6 +26,9 @@ type Action =
   | { type: "ROJO_OUTPUT"; line: string; stream: string }
   | { type: "ROJO_STOPPED"; code: number | null }
   | { type: "ROJO_ERROR"; message: string }
+  | { type: "FIRST_SYNC_PENDING"; message: string }
+  | { type: "FIRST_SYNC_PREVIEW"; preview: SyncPreview }
+  | { type: "EDITOR_ERROR"; error: EditorError | null }
   | { type: "CLEAR_LOGS" };
 
 const initialState: LauncherState = {
@@ -30,8 +37,18 @@ const initialState: LauncherState = {
   rojoPort: null,
   logs: [],
   error: null,
+  firstSync: null,
+  editorError: null,
 };
 
+// open_in_editor rejects with an EditorError; anything else becomes one with no alternatives
+function toEditorError(err: unknown): EditorError {
+  if (err && typeof err === "object" && "message" in err && "available" in err) {
+    return err as EditorError;
+  }
+  return { message: err instanceof Error ? err.message : String(err), available: [] };
+}
+
 function reducer(state: LauncherState, action: Action): LauncherState {
   switch (action.type) {
     case "SET_PROJECT":
@@ -43,6 +60,7 @@ function reducer(state: LauncherState, action: Action): LauncherState {
         rojoPort: null,
         logs: action.keepLogs ? state.logs : [],
         error: null,
+        firstSync: null,
       };
     case "ROJO_STARTED":
       return { ...state, rojoStatus: "running", rojoPort: action.port };
@@ -56,9 +74,21 @@ function reducer(state: LauncherState, action: Action): LauncherState {
       };
     }
     case "ROJO_STOPPED":
-      return { ...state, rojoStatus: "stopped", rojoPort: null };
+      return { ...state, rojoStatus: "stopped", rojoPort: null, firstSync: null };
     case "ROJO_ERROR":
       return { ...state, rojoStatus: "error", error: action.message };
+    case "FIRST_SYNC_PENDING":
+      return {
+        ...state,
+        rojoStatus: "reviewing",
+        firstSync: { message: action.message, preview: null },
+      };
+    case "FIRST_SYNC_PREVIEW":
+      return state.firstSync
+        ? { ...state, error: null, firstSync: { ...state.firstSync, preview: action.preview } }
+        : state;
+    case "EDITOR_ERROR":
+      return { ...state, editorError: action.error };
     case "CLEAR_LOGS":
       return { ...state, logs: [] };
     default:
@@ -150,6 +180,26 @@ export function useLauncher() {
           dispatch({ type: "ROJO_ERROR", message: event.data.message });
           rojoChannelRef.current = null;
           break;
+        case "diskLow":
+          dispatch({
+            type: "ROJO_OUTPUT",
+            line: event.data.message,
+            stream: "stderr",
+          });
+          break;
+        case "firstSyncPending":
+          dispatch({ type: "FIRST_SYNC_PENDING", message: event.data.message });
+          break;
+        case "studioLost":
+          dispatch({
+            type: "ROJO_OUTPUT",
+            line: event.data.message,
+            stream: "stderr",
+          });
+          if (event.data.stopSession) {
+            void stopAll();
+          }
+          break;
       }
     };
   }
@@ -216,6 +266,18 @@ export function useLauncher() {
     rojoChannelRef.current = null;
   }, []);
 
+  // Opens the project in `editor` (an AI tool or editor id), keeping any failure for the UI
+  const openIn = useCallback(async (editor: string) => {
+    const project = projectRef.current;
+    if (!project) return;
+    try {
+      await invoke("open_in_editor", { editor, path: project.path });
+      dispatch({ type: "EDITOR_ERROR", error: null });
+    } catch (err) {
+      dispatch({ type: "EDITOR_ERROR", error: toEditorError(err) });
+    }
+  }, []);
+
   const startDevelopment = useCallback(async () => {
     const project = projectRef.current;
     if (!project) return;
@@ -227,31 +289,54 @@ export function useLauncher() {
     await startRojo();
 
     // Open editor after a short delay so the window has settled
-    setTimeout(async () => {
-      try {
-        await invoke("open_in_editor", {
-          editor: project.aiTool,
-          path: project.path,
-        });
-      } catch {
-        // Editor open failure is non-critical
-      }
-    }, 2000);
-  }, [startRojo, stopAll]);
+    setTimeout(() => openIn(project.aiTool), 2000);
+  }, [startRojo, stopAll, openIn]);
 
   const openEditor = useCallback(async () => {
+    const project = projectRef.current;
+    if (!project) return;
+    await openIn(project.aiTool);
+  }, [openIn]);
+
+  const dismissEditorError = useCallback(() => {
+    dispatch({ type: "EDITOR_ERROR", error: null });
+  }, []);
+
+  // Compares the project with the place open in Studio
+  const previewFirstSync = useCallback(async () => {
     const project = projectRef.current;
     if (!project) return;
     try {
-      await invoke("open_in_editor", {
-        editor: project.aiTool,
-        path: project.path,
+      const preview = await invoke<SyncPreview>("preview_first_sync", {
+        projectPath: project.path,
+      });
+      dispatch({ type: "FIRST_SYNC_PREVIEW", preview });
+    } catch (err) {
+      dispatch({
+        type: "ROJO_OUTPUT",
+        line: err instanceof Error ? err.message : String(err),
+        stream: "stderr",
       });
-    } catch {
-      // Non-critical
     }
   }, []);
 
+  // Lets Rojo sync from now on; the session restarts to start Rojo
+  const confirmFirstSync = useCallback(async () => {
+    const project = projectRef.current;
+    if (!project) return;
+    try {
+      await invoke("confirm_first_sync", { projectPath: project.path });
+    } catch (err) {
+      dispatch({
+        type: "ROJO_ERROR",
+        message: err instanceof Error ? err.message : String(err),
+      });
+      return;
+    }
+    await stopAll();
+    await startRojo();
+  }, [startRojo, stopAll]);
+
   const clearLogs = useCallback(() => {
     dispatch({ type: "CLEAR_LOGS" });
   }, []);
@@ -263,6 +348,10 @@ export function useLauncher() {
     stopAll,
     startDevelopment,
     openEditor,
+    openEditorWith: openIn,
+    dismissEditorError,
+    previewFirstSync,
+    confirmFirstSync,
     clearLogs,
   };
 }
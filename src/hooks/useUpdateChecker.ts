@@ -4,6 +4,8 @@ import type { RoxlitConfig, UpdateInfo } from "@/lib/types";
 
 export function useUpdateChecker(config: RoxlitConfig | null) {
   const [update, setUpdate] = useState<UpdateInfo | null>(null);
+  // Unix time the GitHub rate limit resets at, when a check ran into it
+  const [retryAt, setRetryAt] = useState<number | null>(null);
 
   const checkForUpdate = useCallback(async () => {
     if (!config) return;
@@ -23,6 +25,9 @@ export function useUpdateChecker(config: RoxlitConfig | null) {
       });
 
       setUpdate(result);
+
+      const saved = await invoke<RoxlitConfig | null>("load_config");
+      setRetryAt(saved?.updateRetryAt ?? null);
     } catch {
       // Silent failure — update check is non-critical
     }
@@ -32,6 +37,14 @@ export function useUpdateChecker(config: RoxlitConfig | null) {
     checkForUpdate();
   }, [checkForUpdate]);
 
+  // Check again once the rate limit resets instead of waiting for a relaunch
+  useEffect(() => {
+    if (retryAt === null) return;
+    const delay = Math.max(retryAt * 1000 - Date.now(), 0) + 5000;
+    const timer = setTimeout(checkForUpdate, delay);
+    return () => clearTimeout(timer);
+  }, [retryAt, checkForUpdate]);
+
   const dismissUpdate = useCallback(async () => {
     if (!update) return;
     try {
@@ -67,6 +67,9 @@ export interface DetectionResult {
   aftmanVersion: string | null;
   roxlitMcpInstalled: boolean;
   roxlitMcpVersion: string | null;
+  rojoManaged: boolean;
+  aftmanManaged: boolean;
+  installedTools: Record<string, string>;
 }
 
 export type SetupEvent =
@@ -91,10 +94,26 @@ export type SetupEvent =
       event: "stepWarning";
       data: { step: string; message: string };
     }
+  | {
+      event: "stepSkipped";
+      data: { step: string; reason: string };
+    }
   | {
       event: "error";
       data: { step: string; message: string };
     }
+  | {
+      event: "rolledBack";
+      data: { removed: string[]; restored: string[]; reason: string };
+    }
+  | {
+      event: "verified";
+      data: { checks: string[] };
+    }
+  | {
+      event: "diskLow";
+      data: { path: string; message: string; freeBytes: number };
+    }
   | { event: "finished" };
 
 export interface InstallConfig {
@@ -105,6 +124,19 @@ export interface InstallConfig {
   skipRojo: boolean;
   skipRoxlitMcp: boolean;
   pluginsPath: string | null;
+  toolDir?: string | null;
+  template?: string | null;
+  starterModules?: string[];
+  systemWide?: boolean;
+  rollbackOnFailure?: boolean;
+  projectJson?: ProjectJsonOptions | null;
+}
+
+/** How default.project.json is generated (matches Rust ProjectJsonOptions). */
+export interface ProjectJsonOptions {
+  services: string[];
+  ignoreUnknownInstances: boolean;
+  globIgnorePaths: string[];
 }
 
 // --- App Mode ---
@@ -120,6 +152,46 @@ export interface ProjectEntry {
   createdAt: string;
   placeId?: number | null;
   universeId?: number | null;
+  settings?: ProjectSettings;
+  places?: ProjectPlace[];
+  activePlace?: string | null;
+  firstSyncPending?: boolean;
+  missing?: boolean;
+}
+
+/** A project pruned because its folder was gone (matches Rust ArchivedProject). */
+export interface ArchivedProject {
+  name: string;
+  path: string;
+  placeId?: number | null;
+  universeId?: number | null;
+  places?: ProjectPlace[];
+  archivedAt: string;
+}
+
+/** One place of a multi-place project (e.g. lobby and game). */
+export interface ProjectPlace {
+  name: string;
+  placeId?: number | null;
+  universeId?: number | null;
+  projectFile?: string | null;
+}
+
+export interface ProjectSettings {
+  compactContext?: boolean;
+  contextExtra?: string | null;
+  autoOpenStudio?: boolean | null;
+  captureLogs?: boolean | null;
+  mcpConfig?: boolean | null;
+  openLocalPlace?: boolean | null;
+  stopOnStudioLost?: boolean | null;
+  sharedLog?: string | null;
+  backupIntervalMins?: number | null;
+  backupLimitMb?: number | null;
+  errorLog?: boolean | null;
+  projectJson?: ProjectJsonOptions | null;
+  env?: Record<string, string> | null;
+  playtestSchedule?: PlaytestSchedule | null;
 }
 
 export interface RoxlitConfig {
@@ -129,6 +201,76 @@ export interface RoxlitConfig {
   lastUpdateCheck?: string | null;
   dismissedVersion?: string | null;
   updateDelayDays?: number | null;
+  githubToken?: string | null;
+  updateRetryAt?: number | null;
+  toolDir?: string | null;
+  pluginsDir?: string | null;
+  terminal?: string | null;
+  defaultEditor?: "vscode" | "cursor" | "windsurf" | null;
+  redactLogs?: boolean | null;
+  logLevels?: Record<string, "info" | "warn" | "error" | "off"> | null;
+  pluginGraceSecs?: number | null;
+  installedTools?: Record<string, string> | null;
+  prefetchUpdates?: boolean | null;
+  analyticsEnabled?: boolean | null;
+  analyticsId?: string | null;
+  appVersion?: string | null;
+  contextHashes?: Record<string, string> | null;
+  pluginConfig?: PluginConfig | null;
+  scriptFilter?: ScriptFilter | null;
+  archivedProjects?: ArchivedProject[] | null;
+  maintenance?: boolean | null;
+  lastMaintenance?: number | null;
+  notifications?: Record<string, boolean> | null;
+}
+
+/** Settings changed from the launcher; unset fields are kept (matches Rust SettingsPatch). */
+export interface SettingsPatch {
+  updateDelayDays?: number;
+  /** Empty string clears the token. */
+  githubToken?: string;
+  toolDir?: string;
+  pluginsDir?: string;
+  terminal?: string;
+  defaultEditor?: string;
+  redactLogs?: boolean;
+  logLevels?: Record<string, string>;
+  pluginGraceSecs?: number;
+  prefetchUpdates?: boolean;
+  analyticsEnabled?: boolean;
+  maintenance?: boolean;
+  notifications?: Record<string, boolean>;
+}
+
+/** Studio output `[ScriptName]` prefixes muted or soloed (matches Rust ScriptFilter). */
+export interface ScriptFilter {
+  muted?: string[];
+  solo?: string[];
+}
+
+/** Capture settings the Studio plugin polls from the launcher (matches Rust PluginConfig). */
+export interface PluginConfig {
+  batchIntervalMs?: number;
+  levels?: ("output" | "info" | "warning" | "error")[];
+  paused: boolean;
+  metrics: boolean;
+}
+
+export interface ConfigBackup {
+  version: string;
+  path: string;
+  projects: number;
+}
+
+export interface ResumeStep {
+  step: "project" | "rojo" | "backups" | "studio";
+  ok: boolean;
+  detail: string;
+}
+
+export interface ResumeReport {
+  projectPath: string | null;
+  steps: ResumeStep[];
 }
 
 export interface DiscoveredProject {
@@ -137,6 +279,45 @@ export interface DiscoveredProject {
   aiTool: string;
 }
 
+export interface ProjectTemplate {
+  id: string;
+  title: string;
+  description: string;
+  kind: "lesson" | "framework";
+}
+
+export interface StarterModuleInfo {
+  id: string;
+  title: string;
+  description: string;
+  path: string;
+}
+
+export interface ScaffoldInfo {
+  kind: string;
+  description: string;
+}
+
+export interface RosterEntry {
+  name: string;
+  placeId?: number | null;
+  universeId?: number | null;
+}
+
+export interface ProvisionReport {
+  created: ProjectEntry[];
+  skipped: string[];
+  failed: [string, string][];
+}
+
+/** Result of adopt_project (matches Rust AdoptReport). */
+export interface AdoptReport {
+  project: ProjectEntry | null;
+  detected: string[];
+  added: string[];
+  kept: string[];
+}
+
 export interface UpdateInfo {
   version: string;
   publishedAt: string;
@@ -151,7 +332,291 @@ export type RojoEvent =
   | { event: "output"; data: { line: string; stream: string } }
   | { event: "started"; data: { port: number } }
   | { event: "stopped"; data: { code: number | null } }
-  | { event: "error"; data: { message: string } };
+  | { event: "error"; data: { message: string } }
+  | { event: "pluginNotConnected"; data: { waitedSecs: number; message: string } }
+  | {
+      event: "portConflict";
+      data: { service: string; port: number; owner: string | null; fallback: number | null };
+    }
+  | { event: "studioSessionStarted"; data: { placeName: string | null } }
+  | { event: "studioSessionEnded"; data: { placeName: string | null } }
+  | { event: "studioLost"; data: { message: string; stopSession: boolean } }
+  | {
+      event: "projectJsonInvalid";
+      data: { message: string; line: number | null; column: number | null; canRestore: boolean };
+    }
+  | { event: "diskLow"; data: { message: string; freeBytes: number } }
+  | { event: "firstSyncPending"; data: { message: string } }
+  | { event: "playtestRegression"; data: { errors: number; scripts: string[]; message: string } };
+
+/** A code editor found on this machine (matches Rust EditorInfo). */
+export interface EditorInfo {
+  id: "vscode" | "cursor" | "windsurf";
+  name: string;
+  command: string;
+}
+
+/** open_in_editor's error: what failed and the editors that could be used instead. */
+export interface EditorError {
+  message: string;
+  available: EditorInfo[];
+}
+
+/** Outcome of a bulk action on one project (matches Rust BulkResult). */
+export interface BulkResult {
+  name: string;
+  path: string;
+  status: "ok" | "skipped" | "failed";
+  detail: string;
+}
+
+export type BulkAction = "regenerate-context" | "refresh-mcp" | "doctor" | "update-aftman";
+
+/** What an adopted project's first sync would change (matches Rust SyncPreview). */
+export interface SyncPreview {
+  added: string[];
+  addedCount: number;
+  removed: string[];
+  removedCount: number;
+}
+
+/** Who holds a project's session lock (matches Rust SessionLock). */
+export interface SessionLock {
+  host: string;
+  pid: number;
+  startedAt: string;
+  heartbeat: number;
+}
+
+/** A project file changed by a Rojo sync (matches Rust SyncedFile). */
+export interface SyncedFile {
+  file: string;
+  instance: string;
+  className: string;
+  change: "added" | "changed" | "removed";
+  sha256?: string;
+  previousSha256?: string;
+}
+
+/** One batch of changes synced by Rojo, from sync-history.jsonl (matches Rust SyncEntry). */
+export interface SyncEntry {
+  timestamp: number;
+  time: string;
+  files: SyncedFile[];
+}
 
-export type RojoStatus = "stopped" | "starting" | "running" | "error";
+// --- Disk space (matches Rust DiskUsage) ---
 
+export interface DiskUsage {
+  label: string;
+  path: string;
+  freeBytes: number | null;
+  totalBytes: number | null;
+  low: boolean;
+}
+
+export type RojoStatus = "stopped" | "starting" | "running" | "reviewing" | "error";
+
+
+// --- Session log buffer (matches Rust LogEntry / LogSlice) ---
+
+export interface LogEntry {
+  seq: number;
+  timestamp: number;
+  source: "system" | "output";
+  line: string;
+}
+
+export interface LogSlice {
+  entries: LogEntry[];
+  nextSeq: number;
+  missed: boolean;
+}
+
+// --- Health metrics (matches Rust MetricSummary) ---
+
+export interface MetricSummary {
+  name: "backupDuration" | "batchLag";
+  count: number;
+  lastMs: number;
+  avgMs: number;
+  p95Ms: number;
+  maxMs: number;
+}
+
+// --- Launcher playtests (matches Rust PlaytestResult) ---
+
+export interface PlaytestLine {
+  level: "error" | "warn" | "info";
+  script?: string;
+  message: string;
+}
+
+export interface ScriptCounts {
+  script: string;
+  errors: number;
+  warnings: number;
+  prints: number;
+}
+
+/** When a project runs playtests by itself; `at` is "HH:MM" UTC. */
+export interface PlaytestSchedule {
+  at?: string | null;
+  afterSyncs?: number | null;
+  durationSecs?: number | null;
+}
+
+export interface PlaytestResult {
+  startedAt: string;
+  trigger: "manual" | "scheduled" | "syncs";
+  durationSecs: number;
+  errors: number;
+  warnings: number;
+  prints: number;
+  scripts: ScriptCounts[];
+  lines: PlaytestLine[];
+}
+
+// --- Smoke test (matches Rust SmokeCheck) ---
+
+export interface SmokeCheck {
+  name: string;
+  passed: boolean;
+  detail: string;
+}
+
+// --- Crash reports (matches Rust CrashReport) ---
+
+export interface CrashReport {
+  path: string;
+  title: string;
+  panic: string;
+}
+
+// --- Aftman toolchain (matches Rust AftmanTool) ---
+
+export interface AftmanTool {
+  name: string;
+  repo: string;
+  version: string;
+}
+
+// --- Instance tree (matches Rust InstanceTree / InstanceNode) ---
+
+export interface InstanceNode {
+  name: string;
+  className: string;
+  path: string;
+  file?: string;
+  properties?: Record<string, unknown>;
+  children: InstanceNode[];
+  hasMore: boolean;
+}
+
+export interface InstanceTree {
+  source: "files" | "studio";
+  root: InstanceNode;
+}
+
+export interface InstanceMatch {
+  name: string;
+  className: string;
+  path: string;
+  file?: string;
+  properties?: Record<string, unknown>;
+}
+
+// --- Backup verification (matches Rust BackupCheck) ---
+
+export interface BackupCheck {
+  id: string;
+  name: string | null;
+  timestamp: string | null;
+  problem: string | null;
+  pruned: boolean;
+}
+
+// --- Baselines (matches Rust Baseline / BaselineRevert) ---
+
+export interface Baseline {
+  label: string;
+  createdAt: string;
+  backupId?: string;
+  commit: string;
+  tag?: string;
+  place?: string;
+}
+
+export interface BaselineRevert {
+  label: string;
+  restoredFiles: number;
+  removedFiles: string[];
+  preRevertBackup: string | null;
+  place: string | null;
+}
+
+// --- Script search (matches Rust ScriptMatch) ---
+
+export interface ScriptMatch {
+  file: string;
+  line: number;
+  column: number;
+  snippet: string;
+}
+
+// --- Require graph (matches Rust RequireGraph) ---
+
+export interface ScriptNode {
+  file: string;
+  instancePath: string;
+  className: string;
+}
+
+export interface RequireEdge {
+  from: string;
+  to: string;
+  line: number;
+}
+
+export interface UnresolvedRequire {
+  from: string;
+  line: number;
+  expression: string;
+}
+
+export interface RequireGraph {
+  scripts: ScriptNode[];
+  edges: RequireEdge[];
+  unresolved: UnresolvedRequire[];
+  cycles: string[][];
+}
+
+// --- Moving scripts (matches Rust MoveReport) ---
+
+export interface RequireUpdate {
+  file: string;
+  line: number;
+  old: string;
+  new: string;
+}
+
+export interface MoveReport {
+  from: string;
+  to: string;
+  updated: RequireUpdate[];
+  ambiguous: UnresolvedRequire[];
+}
+
+// --- Unused code (matches Rust UnusedReport) ---
+
+export interface RemoteUsage {
+  name: string;
+  server: string[];
+  client: string[];
+}
+
+export interface UnusedReport {
+  unusedModules: ScriptNode[];
+  oneSidedRemotes: RemoteUsage[];
+  unresolvedRequires: number;
+}